@@ -0,0 +1,597 @@
+use std::fs;
+use std::path::PathBuf;
+
+use crate::{
+    enums::error::CommandError,
+    structs::{
+        availability::{PortAvailability, UnavailableReason},
+        port::PortInfo,
+        snapshot::SnapshotDiff,
+    },
+    traits::command::OSCommandExecutor,
+    utils::{
+        availability::{check_port, find_free_port, parse_addresses},
+        port::scan_open_ports,
+        snapshot::{create_snapshot, diff_snapshots, load_snapshot, save_snapshot},
+    },
+};
+
+const USAGE: &str = "\
+usage:
+  open_port_check                       start the GUI
+  open_port_check list [--json] [--output <file>]
+                                        print the current listening ports
+  open_port_check snapshot <file>       save the current listening ports to <file>
+  open_port_check diff <file> [--json]  compare the current listening ports with <file>
+  open_port_check check <port> [--addr <ip>]...
+                                        check whether <port> can be bound (default: 0.0.0.0 and ::)
+  open_port_check free <start>-<end> [--addr <ip>]...
+                                        print the first port in the range that can be bound";
+
+#[derive(Debug, PartialEq)]
+pub enum CliCommand {
+    List {
+        json: bool,
+        output: Option<PathBuf>,
+    },
+    Snapshot {
+        path: PathBuf,
+    },
+    Diff {
+        path: PathBuf,
+        json: bool,
+    },
+    Check {
+        port: u16,
+        addresses: Vec<String>,
+    },
+    Free {
+        start: u16,
+        end: u16,
+        addresses: Vec<String>,
+    },
+    Help,
+}
+
+/**
+ * `--addr <ip>` 옵션을 모두 모으고, 나머지 인자를 돌려준다.
+ */
+fn split_addresses(rest: &[String]) -> Result<(Vec<String>, Vec<&String>), String> {
+    let mut addresses = Vec::new();
+    let mut positional = Vec::new();
+    let mut iter = rest.iter();
+
+    while let Some(arg) = iter.next() {
+        if arg == "--addr" {
+            let address = iter
+                .next()
+                .ok_or_else(|| format!("missing value for --addr\n{}", USAGE))?;
+            addresses.push(address.clone());
+        } else {
+            positional.push(arg);
+        }
+    }
+
+    Ok((addresses, positional))
+}
+
+/**
+ * 하나뿐인 위치 인자를 꺼낸다. 알 수 없는 옵션이나 남는 인자는 사용법 오류로 본다.
+ */
+fn single_positional<'a>(args: &[&'a String], name: &str) -> Result<&'a String, String> {
+    if let Some(arg) = args.iter().find(|arg| arg.starts_with('-')) {
+        return Err(format!("unknown argument: {}\n{}", arg, USAGE));
+    }
+
+    match args {
+        [] => Err(format!("missing {}\n{}", name, USAGE)),
+        [value] => Ok(value),
+        [_, extra, ..] => Err(format!("unexpected argument: {}\n{}", extra, USAGE)),
+    }
+}
+
+// 0은 OS가 임의로 고르는 포트라 확인할 수 없다.
+fn parse_port(value: &str) -> Result<u16, String> {
+    value
+        .parse::<u16>()
+        .ok()
+        .filter(|port| *port != 0)
+        .ok_or_else(|| format!("invalid port: {}", value))
+}
+
+fn parse_check(rest: &[String]) -> Result<CliCommand, String> {
+    let (addresses, positional) = split_addresses(rest)?;
+    let port = single_positional(&positional, "<port>")?;
+
+    Ok(CliCommand::Check {
+        port: parse_port(port)?,
+        addresses,
+    })
+}
+
+/**
+ * `--output <file>`은 표준 출력을 넘겨받을 수 없는 관리자 권한 실행(윈도우)에서 사용한다.
+ */
+fn parse_list(rest: &[String]) -> Result<CliCommand, String> {
+    let mut json = false;
+    let mut output = None;
+    let mut iter = rest.iter();
+
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--json" => json = true,
+            "--output" => {
+                let path = iter
+                    .next()
+                    .ok_or_else(|| format!("missing value for --output\n{}", USAGE))?;
+                output = Some(PathBuf::from(path));
+            }
+            _ => return Err(format!("unknown argument: {}\n{}", arg, USAGE)),
+        }
+    }
+
+    Ok(CliCommand::List { json, output })
+}
+
+fn parse_free(rest: &[String]) -> Result<CliCommand, String> {
+    let (addresses, positional) = split_addresses(rest)?;
+    let range = single_positional(&positional, "<start>-<end>")?;
+    let (start, end) = range
+        .split_once('-')
+        .ok_or_else(|| format!("invalid range: {}", range))?;
+
+    Ok(CliCommand::Free {
+        start: parse_port(start)?,
+        end: parse_port(end)?,
+        addresses,
+    })
+}
+
+/**
+ * 인자를 CLI 명령어로 해석한다.
+ * 알 수 없는 첫 인자는 GUI 실행으로 보고 None을 돌려준다.
+ */
+pub fn parse_args(args: &[String]) -> Option<Result<CliCommand, String>> {
+    let (command, rest) = args.split_first()?;
+
+    // flags 외의 옵션은 받지 않는다.
+    let path = |rest: &[String], flags: &[&str]| -> Result<PathBuf, String> {
+        let positional: Vec<&String> = rest
+            .iter()
+            .filter(|arg| !flags.contains(&arg.as_str()))
+            .collect();
+        single_positional(&positional, "<file>").map(PathBuf::from)
+    };
+
+    let command = match command.as_str() {
+        "list" => parse_list(rest),
+        "snapshot" => path(rest, &[]).map(|path| CliCommand::Snapshot { path }),
+        "diff" => path(rest, &["--json"]).map(|path| CliCommand::Diff {
+            path,
+            json: rest.iter().any(|arg| arg == "--json"),
+        }),
+        "check" => parse_check(rest),
+        "free" => parse_free(rest),
+        "help" | "--help" | "-h" => Ok(CliCommand::Help),
+        _ => return None,
+    };
+
+    Some(command)
+}
+
+fn format_port(port: &PortInfo) -> String {
+    format!(
+        "{} {}:{} {} ({})",
+        port.protocol, port.address, port.port, port.process_name, port.pid
+    )
+}
+
+/**
+ * 비교 결과를 사람이 읽을 수 있는 형태로 나타낸다.
+ *
+ * ex)
+ * `+ TCP 127.0.0.1:5173 node (400)`
+ * `- TCP 127.0.0.1:8080 java (300)`
+ * `~ TCP 127.0.0.1:3000 node (200) -> node (250)`
+ */
+pub fn format_diff(diff: &SnapshotDiff) -> String {
+    let mut lines = Vec::new();
+
+    for port in diff.added.iter() {
+        lines.push(format!("+ {}", format_port(port)));
+    }
+    for port in diff.removed.iter() {
+        lines.push(format!("- {}", format_port(port)));
+    }
+    for changed in diff.changed.iter() {
+        let owners = |ports: &[PortInfo]| {
+            ports
+                .iter()
+                .map(|port| format!("{} ({})", port.process_name, port.pid))
+                .collect::<Vec<String>>()
+                .join(", ")
+        };
+
+        if let Some(first) = changed.after.first().or(changed.before.first()) {
+            lines.push(format!(
+                "~ {} {}:{} {} -> {}",
+                first.protocol,
+                first.address,
+                first.port,
+                owners(&changed.before),
+                owners(&changed.after)
+            ));
+        }
+    }
+
+    lines.join("\n")
+}
+
+fn format_reason(reason: &UnavailableReason) -> String {
+    match reason {
+        UnavailableReason::InUse {
+            pid: Some(pid),
+            process_name,
+        } => format!(
+            "in use by {} ({})",
+            process_name.as_deref().unwrap_or("unknown"),
+            pid
+        ),
+        UnavailableReason::InUse { pid: None, .. } => "in use".to_string(),
+        UnavailableReason::PermissionDenied => {
+            "permission denied (ports below 1024 need elevated privileges)".to_string()
+        }
+        UnavailableReason::Excluded { start, end } => {
+            format!("excluded by the OS ({}-{})", start, end)
+        }
+        UnavailableReason::AddressNotAvailable => "address not available".to_string(),
+        UnavailableReason::Other { message } => message.clone(),
+    }
+}
+
+/**
+ * 포트 확인 결과를 주소마다 한 줄로 나타낸다.
+ *
+ * ex)
+ * `8080 unavailable`
+ * `  0.0.0.0  in use by node (1234)`
+ * `  ::       ok`
+ */
+pub fn format_availability(availability: &PortAvailability) -> String {
+    let mut lines = vec![format!(
+        "{} {}",
+        availability.port,
+        if availability.available {
+            "available"
+        } else {
+            "unavailable"
+        }
+    )];
+
+    for address in availability.addresses.iter() {
+        lines.push(format!(
+            "  {:<8} {}",
+            address.address,
+            address
+                .reason
+                .as_ref()
+                .map(format_reason)
+                .unwrap_or("ok".to_string())
+        ));
+    }
+
+    lines.join("\n")
+}
+
+fn execute(command: CliCommand) -> Result<i32, CommandError> {
+    match command {
+        CliCommand::List { json, output } => {
+            let ports = scan_open_ports(&OSCommandExecutor)?;
+            let text = if json {
+                serde_json::to_string(&ports).map_err(|e| CommandError::Failed(e.to_string()))?
+            } else {
+                ports
+                    .iter()
+                    .map(format_port)
+                    .collect::<Vec<String>>()
+                    .join("\n")
+            };
+
+            match output {
+                Some(path) => fs::write(&path, text).map_err(|e| {
+                    CommandError::Failed(format!("Failed to write {}: {}", path.display(), e))
+                })?,
+                None => println!("{}", text),
+            }
+            Ok(0)
+        }
+        CliCommand::Snapshot { path } => {
+            let snapshot = create_snapshot(scan_open_ports(&OSCommandExecutor)?);
+            save_snapshot(&path, &snapshot)?;
+            println!("saved {} ports to {}", snapshot.ports.len(), path.display());
+            Ok(0)
+        }
+        CliCommand::Diff { path, json } => {
+            let snapshot = load_snapshot(&path)?;
+            let current = scan_open_ports(&OSCommandExecutor)?;
+            let diff = diff_snapshots(&snapshot.ports, &current);
+
+            if json {
+                let output = serde_json::to_string_pretty(&diff)
+                    .map_err(|e| CommandError::Failed(e.to_string()))?;
+                println!("{}", output);
+            } else if !diff.is_empty() {
+                println!("{}", format_diff(&diff));
+            }
+
+            // 변화가 있으면 1, 스크립트에서 남은 포트를 검사할 때 사용
+            Ok(if diff.is_empty() { 0 } else { 1 })
+        }
+        CliCommand::Check { port, addresses } => {
+            let addresses = parse_addresses(&addresses)?;
+            let availability = check_port(&OSCommandExecutor, port, &addresses)?;
+            println!("{}", format_availability(&availability));
+
+            Ok(if availability.available { 0 } else { 1 })
+        }
+        CliCommand::Free {
+            start,
+            end,
+            addresses,
+        } => {
+            let addresses = parse_addresses(&addresses)?;
+
+            // 스크립트에서 바로 쓸 수 있도록 포트 번호만 출력한다.
+            match find_free_port(&OSCommandExecutor, start, end, &addresses)? {
+                Some(availability) => {
+                    println!("{}", availability.port);
+                    Ok(0)
+                }
+                None => {
+                    eprintln!("no free port in {}-{}", start, end);
+                    Ok(1)
+                }
+            }
+        }
+        CliCommand::Help => {
+            println!("{}", USAGE);
+            Ok(0)
+        }
+    }
+}
+
+/**
+ * 릴리즈 빌드는 윈도우 GUI 프로그램이라 콘솔이 없으므로, 실행한 터미널의 콘솔에 붙어 출력한다.
+ * 관리자 권한 실행처럼 붙을 콘솔이 없다면 실패하고 --output으로 결과를 넘긴다.
+ */
+#[cfg(windows)]
+fn attach_parent_console() {
+    const ATTACH_PARENT_PROCESS: u32 = u32::MAX;
+
+    #[link(name = "kernel32")]
+    extern "system" {
+        fn AttachConsole(process_id: u32) -> i32;
+    }
+
+    unsafe {
+        AttachConsole(ATTACH_PARENT_PROCESS);
+    }
+}
+
+/**
+ * CLI 명령어라면 실행하고 종료 코드를 돌려준다. GUI를 실행해야 하면 None
+ */
+pub fn run(args: &[String]) -> Option<i32> {
+    let command = parse_args(args)?;
+
+    #[cfg(windows)]
+    attach_parent_console();
+
+    let code = match command {
+        Ok(command) => execute(command).unwrap_or_else(|e| {
+            eprintln!("error: {}", e);
+            2
+        }),
+        Err(message) => {
+            eprintln!("error: {}", message);
+            2
+        }
+    };
+
+    Some(code)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::structs::{availability::AddressAvailability, snapshot::ChangedPort};
+
+    fn args(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    fn port_info(process: &str, port: &str, pid: &str) -> PortInfo {
+        PortInfo {
+            process_name: process.to_string(),
+            port: port.to_string(),
+            pid: pid.to_string(),
+            protocol: "TCP".to_string(),
+            address: "127.0.0.1".to_string(),
+            ..PortInfo::default()
+        }
+    }
+
+    #[test]
+    fn test_parse_args() {
+        assert_eq!(parse_args(&args(&[])), None);
+        assert_eq!(
+            parse_args(&args(&["snapshot", "before.json"])),
+            Some(Ok(CliCommand::Snapshot {
+                path: PathBuf::from("before.json")
+            }))
+        );
+        assert_eq!(
+            parse_args(&args(&["diff", "--json", "before.json"])),
+            Some(Ok(CliCommand::Diff {
+                path: PathBuf::from("before.json"),
+                json: true
+            }))
+        );
+        assert!(matches!(parse_args(&args(&["diff"])), Some(Err(_))));
+    }
+
+    #[test]
+    fn test_parse_args_rejects_unknown_flags() {
+        let error = |values: &[&str]| match parse_args(&args(values)) {
+            Some(Err(message)) => message.lines().next().unwrap_or_default().to_string(),
+            other => panic!("expected an error: {:?}", other),
+        };
+
+        assert_eq!(
+            error(&["diff", "--jsn", "before.json"]),
+            "unknown argument: --jsn"
+        );
+        assert_eq!(
+            error(&["snapshot", "--json", "before.json"]),
+            "unknown argument: --json"
+        );
+        assert_eq!(
+            error(&["snapshot", "before.json", "after.json"]),
+            "unexpected argument: after.json"
+        );
+        assert_eq!(
+            error(&["check", "3000", "--address", "127.0.0.1"]),
+            "unknown argument: --address"
+        );
+        assert_eq!(
+            error(&["free", "3000-3100", "--verbose"]),
+            "unknown argument: --verbose"
+        );
+        assert_eq!(run(&args(&["diff", "--jsn", "before.json"])), Some(2));
+    }
+
+    #[test]
+    fn test_parse_args_list() {
+        assert_eq!(
+            parse_args(&args(&["list"])),
+            Some(Ok(CliCommand::List {
+                json: false,
+                output: None
+            }))
+        );
+        assert_eq!(
+            parse_args(&args(&["list", "--json", "--output", "ports.json"])),
+            Some(Ok(CliCommand::List {
+                json: true,
+                output: Some(PathBuf::from("ports.json"))
+            }))
+        );
+        assert!(matches!(
+            parse_args(&args(&["list", "--output"])),
+            Some(Err(_))
+        ));
+        assert!(matches!(
+            parse_args(&args(&["list", "--csv"])),
+            Some(Err(_))
+        ));
+    }
+
+    #[test]
+    fn test_parse_args_check() {
+        assert_eq!(
+            parse_args(&args(&[
+                "check",
+                "8080",
+                "--addr",
+                "127.0.0.1",
+                "--addr",
+                "::1"
+            ])),
+            Some(Ok(CliCommand::Check {
+                port: 8080,
+                addresses: vec!["127.0.0.1".to_string(), "::1".to_string()]
+            }))
+        );
+        assert!(matches!(
+            parse_args(&args(&["check", "http"])),
+            Some(Err(_))
+        ));
+        assert!(matches!(parse_args(&args(&["check", "0"])), Some(Err(_))));
+        assert!(matches!(
+            parse_args(&args(&["check", "8080", "--addr"])),
+            Some(Err(_))
+        ));
+    }
+
+    #[test]
+    fn test_parse_args_free() {
+        assert_eq!(
+            parse_args(&args(&["free", "3000-3100"])),
+            Some(Ok(CliCommand::Free {
+                start: 3000,
+                end: 3100,
+                addresses: vec![]
+            }))
+        );
+        assert!(matches!(parse_args(&args(&["free", "3000"])), Some(Err(_))));
+        assert!(matches!(
+            parse_args(&args(&["free", "0-100"])),
+            Some(Err(_))
+        ));
+    }
+
+    #[test]
+    fn test_format_availability() {
+        let availability = PortAvailability {
+            port: 8080,
+            available: false,
+            addresses: vec![
+                AddressAvailability {
+                    address: "0.0.0.0".to_string(),
+                    reason: Some(UnavailableReason::InUse {
+                        pid: Some("1234".to_string()),
+                        process_name: Some("node".to_string()),
+                    }),
+                },
+                AddressAvailability {
+                    address: "::".to_string(),
+                    reason: None,
+                },
+            ],
+        };
+
+        assert_eq!(
+            format_availability(&availability),
+            "\
+8080 unavailable
+  0.0.0.0  in use by node (1234)
+  ::       ok"
+        );
+    }
+
+    // 알 수 없는 인자는 GUI 실행으로 본다
+    #[test]
+    fn test_parse_args_unknown() {
+        assert_eq!(parse_args(&args(&["-psn_0_12345"])), None);
+    }
+
+    #[test]
+    fn test_format_diff() {
+        let diff = SnapshotDiff {
+            added: vec![port_info("vite", "5173", "400")],
+            removed: vec![port_info("java", "8080", "300")],
+            changed: vec![ChangedPort {
+                before: vec![port_info("node", "3000", "200")],
+                after: vec![port_info("node", "3000", "250")],
+            }],
+        };
+
+        assert_eq!(
+            format_diff(&diff),
+            "\
++ TCP 127.0.0.1:5173 vite (400)
+- TCP 127.0.0.1:8080 java (300)
+~ TCP 127.0.0.1:3000 node (200) -> node (250)"
+        );
+    }
+}
@@ -0,0 +1,27 @@
+use serde::Serialize;
+
+/**
+ * Tauri 명령어가 프론트엔드에 돌려주는 에러
+ * kind 필드로 에러 종류를 구분한다.
+ */
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "kind", content = "message")]
+pub enum CommandError {
+    // 제한 시간(ms) 안에 명령어가 끝나지 않음
+    Timeout(u64),
+    // 새로운 요청이 들어와 이전 요청이 취소됨
+    Cancelled,
+    UnsupportedOS,
+    Failed(String),
+}
+
+impl std::fmt::Display for CommandError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CommandError::Timeout(ms) => write!(f, "command timed out after {}ms", ms),
+            CommandError::Cancelled => write!(f, "command cancelled"),
+            CommandError::UnsupportedOS => write!(f, "unsupported OS"),
+            CommandError::Failed(message) => write!(f, "{}", message),
+        }
+    }
+}
@@ -1,46 +1,631 @@
-use enums::os::OS;
-use traits::command::OSCommandExecutor;
-use utils::{kill, port::{self, parsing_mac_lsof, parsing_window_netstat}, remove_duplicate::remove_duplicates};
+use enums::error::CommandError;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use structs::{
+    availability::PortAvailability,
+    bulk::{BulkAction, BulkOutcome},
+    coverage::ElevatedScan,
+    detail::ProcessDetail,
+    fingerprint::FingerprintResult,
+    history::{HistoryQuery, PortLifetime},
+    kill::{KillSignal, ProcessContext},
+    port::{PortInfo, ScanEvent},
+    probe::ProbeResult,
+    reservation::Reservation,
+    settings::Settings,
+    snapshot::{Snapshot, SnapshotDiff},
+    watch::WatchStatus,
+};
+use tauri::{ipc::Channel, Emitter, Manager, State};
+use traits::{command::OSCommandExecutor, elevator::OSElevator};
+use utils::{
+    availability, bulk, cgroup, detail,
+    docker::DockerClient,
+    elevate, fingerprint,
+    history::{self, HistoryStore},
+    kill, port, probe, process,
+    reservation::ReservationStore,
+    settings::SettingsStore,
+    snapshot,
+    task::{run_blocking, CancelRegistry, DEFAULT_TIMEOUT_MS},
+    time::now_millis,
+    usage::UsageSampler,
+    watch::WatchState,
+};
+
+pub mod cli;
 
 pub mod utils {
     pub mod port;
     pub mod kill;
+    pub mod kill_permission;
+    pub mod process;
+    pub mod bulk;
+    pub mod detail;
     pub mod remove_duplicate;
+    pub mod task;
+    pub mod watch;
+    pub mod snapshot;
+    pub mod time;
+    pub mod settings;
+    pub mod history;
+    pub mod docker;
+    pub mod cgroup;
+    pub mod service;
+    pub mod availability;
+    pub mod reservation;
+    pub mod probe;
+    pub mod http_probe;
+    pub mod protocol_probe;
+    pub mod tls_probe;
+    pub mod fingerprint;
+    pub mod procfs;
+    pub mod coverage;
+    pub mod elevate;
+    pub mod activity;
+    pub mod usage;
 }
 
 pub mod traits {
     pub mod command;
+    pub mod elevator;
+}
+
+pub mod structs {
+    pub mod port;
+    pub mod watch;
+    pub mod snapshot;
+    pub mod settings;
+    pub mod history;
+    pub mod container;
+    pub mod cgroup;
+    pub mod availability;
+    pub mod reservation;
+    pub mod probe;
+    pub mod fingerprint;
+    pub mod coverage;
+    pub mod kill;
+    pub mod bulk;
+    pub mod detail;
+    pub mod usage;
 }
 
 pub mod enums {
     pub mod os;
+    pub mod error;
+}
+
+/**
+ * 포트 조회 요청의 취소 상태
+ * 새로고침이 다시 눌리면 진행 중인 조회는 취소된다.
+ */
+#[derive(Default)]
+struct ScanState(CancelRegistry);
+
+#[tauri::command]
+async fn get_open_ports(
+    state: State<'_, ScanState>,
+    timeout_ms: Option<u64>,
+) -> Result<Vec<PortInfo>, CommandError> {
+    let cancel = state.0.begin();
+
+    run_blocking(
+        || port::scan_open_ports(&OSCommandExecutor),
+        timeout_ms.unwrap_or(DEFAULT_TIMEOUT_MS),
+        Some(cancel),
+    )
+    .await
+}
+
+/**
+ * get_open_ports와 같은 조회를 하지만 단계가 끝날 때마다 on_event로 결과를 흘려보낸다.
+ * 감시를 꺼 두어도 CPU, 메모리 열이 채워지도록 조회가 끝나면 사용량을 보낸다.
+ */
+#[tauri::command]
+async fn stream_open_ports(
+    app: tauri::AppHandle,
+    state: State<'_, ScanState>,
+    on_event: Channel<ScanEvent>,
+    timeout_ms: Option<u64>,
+) -> Result<(), CommandError> {
+    let cancel = state.0.begin();
+
+    run_blocking(
+        move || {
+            let mut rows = Vec::new();
+            port::stream_open_ports(&OSCommandExecutor, &mut |event| {
+                if let ScanEvent::Rows(new_rows) = &event {
+                    rows = new_rows.clone();
+                }
+                // 프론트엔드가 채널을 닫았다면 남은 이벤트는 버린다.
+                let _ = on_event.send(event);
+            })?;
+
+            let usage = app
+                .state::<UsageSampler>()
+                .sample(&OSCommandExecutor, &rows);
+            let _ = app.emit("process-usage", &usage);
+            Ok(())
+        },
+        timeout_ms.unwrap_or(DEFAULT_TIMEOUT_MS),
+        Some(cancel),
+    )
+    .await
+}
+
+#[tauri::command]
+fn cancel_get_open_ports(state: State<'_, ScanState>) -> bool {
+    state.0.cancel()
+}
+
+// 관리자 인증 창에서 사용자가 입력할 시간
+const ELEVATION_TIMEOUT_MS: u64 = 120_000;
+
+/**
+ * 권한이 없어 일부만 보이는 경우 관리자 권한으로 다시 조회한다.
+ * pkexec(리눅스), 관리자 인증 창(맥), UAC(윈도우)를 거친다.
+ */
+#[tauri::command]
+async fn scan_open_ports_elevated() -> Result<ElevatedScan, CommandError> {
+    run_blocking(
+        || elevate::elevated_scan(&OSCommandExecutor, &OSElevator),
+        ELEVATION_TIMEOUT_MS,
+        None,
+    )
+    .await
+}
+
+#[tauri::command]
+async fn kill_process(
+    pid: String,
+    signal: Option<KillSignal>,
+    timeout_ms: Option<u64>,
+) -> Result<bool, CommandError> {
+    // kill은 서로 독립적이므로 이전 요청을 취소하지 않는다.
+    run_blocking(
+        move || {
+            Ok(kill::send_signal(
+                &OSCommandExecutor,
+                pid,
+                signal.unwrap_or_default(),
+            ))
+        },
+        timeout_ms.unwrap_or(DEFAULT_TIMEOUT_MS),
+        None,
+    )
+    .await
+}
+
+/**
+ * 선택한 여러 행을 한 번에 종료, 내보내기, 복사하고 pid마다 결과를 돌려준다.
+ */
+#[tauri::command]
+async fn run_bulk_action(
+    app: tauri::AppHandle,
+    action: BulkAction,
+    ports: Vec<PortInfo>,
+) -> Result<BulkOutcome, CommandError> {
+    let export_dir = app
+        .path()
+        .download_dir()
+        .or_else(|_| app.path().home_dir())
+        .map_err(|e| CommandError::Failed(e.to_string()))?;
+
+    run_blocking(
+        move || {
+            bulk::run_bulk_action(
+                &OSCommandExecutor,
+                &DockerClient::from_env(),
+                action,
+                &ports,
+                &export_dir,
+            )
+        },
+        DEFAULT_TIMEOUT_MS,
+        None,
+    )
+    .await
+}
+
+/**
+ * 종료 확인 창에 보여줄 전체 명령어와 자식 프로세스를 조회한다.
+ */
+#[tauri::command]
+async fn get_process_context(pid: String) -> Result<ProcessContext, CommandError> {
+    run_blocking(
+        move || process::process_context(&OSCommandExecutor, &pid),
+        DEFAULT_TIMEOUT_MS,
+        None,
+    )
+    .await
+}
+
+/**
+ * 상세 패널에 보여줄 명령어, 환경 변수, 소켓, 사용량을 조회한다.
+ */
+#[tauri::command]
+async fn get_process_detail(pid: String) -> Result<ProcessDetail, CommandError> {
+    run_blocking(
+        move || detail::process_detail(&OSCommandExecutor, &pid),
+        DEFAULT_TIMEOUT_MS,
+        None,
+    )
+    .await
+}
+
+/**
+ * 문자열을 클립보드에 복사한다. (ex. curl 명령어)
+ */
+#[tauri::command]
+async fn copy_text(text: String) -> Result<(), CommandError> {
+    run_blocking(
+        move || bulk::copy_to_clipboard(&OSCommandExecutor, &text),
+        DEFAULT_TIMEOUT_MS,
+        None,
+    )
+    .await
+}
+
+/**
+ * 실행 파일을 파일 관리자에서 보여준다.
+ */
+#[tauri::command]
+async fn reveal_path(path: String) -> Result<(), CommandError> {
+    run_blocking(
+        move || detail::reveal_in_file_manager(&OSCommandExecutor, &path),
+        DEFAULT_TIMEOUT_MS,
+        None,
+    )
+    .await
+}
+
+/**
+ * 포트를 점유한 docker-proxy 대신 컨테이너를 멈춘다.
+ */
+#[tauri::command]
+async fn stop_container(id: String) -> Result<(), CommandError> {
+    run_blocking(
+        move || DockerClient::from_env().stop_container(&id),
+        DEFAULT_TIMEOUT_MS,
+        None,
+    )
+    .await
+}
+
+/**
+ * 프로세스를 다시 띄우는 systemd 서비스를 멈춘다.
+ * user가 true면 사용자 서비스 (systemctl --user)
+ */
+#[tauri::command]
+async fn stop_unit(unit: String, user: bool) -> Result<(), CommandError> {
+    run_blocking(
+        move || cgroup::stop_unit(&OSCommandExecutor, &unit, user),
+        DEFAULT_TIMEOUT_MS,
+        None,
+    )
+    .await
 }
 
+/**
+ * 실제로 bind를 시도해 포트를 열 수 있는지 확인한다.
+ * addresses가 비어 있으면 IPv4, IPv6 모든 주소에서 확인한다.
+ */
 #[tauri::command]
-fn get_open_ports() -> Vec<(String, String, String)> {
-    let executor = OSCommandExecutor;
-    let os = port::get_open_ports(&executor);
+async fn check_port(
+    port: u16,
+    addresses: Option<Vec<String>>,
+) -> Result<PortAvailability, CommandError> {
+    run_blocking(
+        move || {
+            let addresses = availability::parse_addresses(&addresses.unwrap_or_default())?;
+            availability::check_port(&OSCommandExecutor, port, &addresses)
+        },
+        DEFAULT_TIMEOUT_MS,
+        None,
+    )
+    .await
+}
+
+/**
+ * start..=end 구간에서 열 수 있는 첫 포트를 찾는다. 없으면 None
+ */
+#[tauri::command]
+async fn find_free_port(
+    start: u16,
+    end: u16,
+    addresses: Option<Vec<String>>,
+) -> Result<Option<PortAvailability>, CommandError> {
+    run_blocking(
+        move || {
+            let addresses = availability::parse_addresses(&addresses.unwrap_or_default())?;
+            availability::find_free_port(&OSCommandExecutor, start, end, &addresses)
+        },
+        DEFAULT_TIMEOUT_MS,
+        None,
+    )
+    .await
+}
+
+/**
+ * 목록의 소켓마다 연결을 시도해 실제로 연결을 받는지 확인한다.
+ * 모든 주소에 열린 소켓은 루프백으로 연결한다.
+ */
+#[tauri::command]
+async fn probe_ports(
+    ports: Vec<PortInfo>,
+    timeout_ms: Option<u64>,
+) -> Result<Vec<ProbeResult>, CommandError> {
+    let probe_timeout =
+        Duration::from_millis(timeout_ms.unwrap_or(probe::DEFAULT_PROBE_TIMEOUT_MS));
+
+    run_blocking(
+        move || Ok(probe::probe_rows(&ports, probe_timeout)),
+        DEFAULT_TIMEOUT_MS,
+        None,
+    )
+    .await
+}
+
+/**
+ * 루프백 소켓 너머의 프로토콜을 확인한다.
+ * HTTP(상태 코드, 제목, 프레임워크), TLS 인증서, Redis, Postgres, MySQL, SSH
+ */
+#[tauri::command]
+async fn fingerprint_ports(
+    ports: Vec<PortInfo>,
+    timeout_ms: Option<u64>,
+) -> Result<Vec<FingerprintResult>, CommandError> {
+    let fingerprint_timeout = Duration::from_millis(
+        timeout_ms.unwrap_or(fingerprint::DEFAULT_FINGERPRINT_TIMEOUT_MS),
+    );
+
+    run_blocking(
+        move || Ok(fingerprint::fingerprint_rows(&ports, fingerprint_timeout)),
+        DEFAULT_TIMEOUT_MS,
+        None,
+    )
+    .await
+}
+
+/**
+ * 포트에 자리만 차지하는 listener를 열어 해제할 때까지 붙잡는다.
+ * 예약한 포트는 목록에서 "reserved by open_port_check"로 표시된다.
+ */
+#[tauri::command]
+fn reserve_port(
+    reservations: State<'_, ReservationStore>,
+    port: u16,
+    addresses: Option<Vec<String>>,
+) -> Result<Vec<Reservation>, CommandError> {
+    let addresses = availability::parse_addresses(&addresses.unwrap_or_default())?;
+    reservations.reserve(port, &addresses)
+}
+
+#[tauri::command]
+fn release_port(reservations: State<'_, ReservationStore>, port: u16) -> usize {
+    reservations.release(port)
+}
+
+#[tauri::command]
+fn list_reservations(reservations: State<'_, ReservationStore>) -> Vec<Reservation> {
+    reservations.list()
+}
+
+/**
+ * 현재 포트 목록을 path에 저장한다.
+ */
+#[tauri::command]
+async fn save_snapshot(path: PathBuf) -> Result<Snapshot, CommandError> {
+    run_blocking(
+        move || {
+            let snapshot = snapshot::create_snapshot(port::scan_open_ports(&OSCommandExecutor)?);
+            snapshot::save_snapshot(&path, &snapshot)?;
+            Ok(snapshot)
+        },
+        DEFAULT_TIMEOUT_MS,
+        None,
+    )
+    .await
+}
 
-    let result = match os {
-        OS::MacOS(output) => parsing_mac_lsof(&output),
-        OS::Windows(output) => parsing_window_netstat(&output),
-        _ => panic!("Unsupported OS"),
-    };
+/**
+ * path에 저장된 스냅샷과 현재 포트 목록을 비교한다.
+ */
+#[tauri::command]
+async fn diff_with_snapshot(path: PathBuf) -> Result<SnapshotDiff, CommandError> {
+    run_blocking(
+        move || {
+            let before = snapshot::load_snapshot(&path)?;
+            let after = port::scan_open_ports(&OSCommandExecutor)?;
+            Ok(snapshot::diff_snapshots(&before.ports, &after))
+        },
+        DEFAULT_TIMEOUT_MS,
+        None,
+    )
+    .await
+}
+
+#[tauri::command]
+fn get_watch_status(state: State<'_, WatchState>) -> WatchStatus {
+    state.status()
+}
+
+#[tauri::command]
+fn set_watch_interval(state: State<'_, WatchState>, interval_ms: u64) -> WatchStatus {
+    state.set_interval(interval_ms);
+    state.status()
+}
+
+#[tauri::command]
+fn pause_watch(state: State<'_, WatchState>) -> WatchStatus {
+    state.pause();
+    state.status()
+}
+
+#[tauri::command]
+fn resume_watch(state: State<'_, WatchState>) -> WatchStatus {
+    state.resume();
+    state.status()
+}
+
+#[tauri::command]
+fn get_settings(settings: State<'_, SettingsStore>) -> Settings {
+    settings.get()
+}
+
+#[tauri::command]
+fn update_settings(
+    store: State<'_, SettingsStore>,
+    history: State<'_, HistoryStore>,
+    watch: State<'_, WatchState>,
+    settings: Settings,
+) -> Result<Settings, CommandError> {
+    let updated = store.update(settings)?;
+
+    // REC는 LIVE 감시와 별개로 기록을 켜고 끈다. 끄면 감시 루프가 열린 소켓을 닫힘으로 쓴다.
+    watch.set_recording(updated.history_enabled);
 
-    remove_duplicates(result)
+    // 보관 기간이 줄었다면 바로 정리한다.
+    history.prune(updated.history_retention_days, now_millis())?;
+    Ok(updated)
 }
 
+/**
+ * 포트, 프로세스 이름, 시간 구간으로 포트 점유 이력을 조회한다.
+ * 이력 파일이 커지면 오래 걸리므로 블로킹 스레드에서 읽는다.
+ */
 #[tauri::command]
-fn kill_process(pid: &str) -> bool {
-    let executor = OSCommandExecutor;
-    kill::kill_process(&executor, pid.to_string())
+async fn query_history(
+    app: tauri::AppHandle,
+    query: HistoryQuery,
+) -> Result<Vec<PortLifetime>, CommandError> {
+    run_blocking(
+        move || app.state::<HistoryStore>().query(&query),
+        DEFAULT_TIMEOUT_MS,
+        None,
+    )
+    .await
 }
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     tauri::Builder::default()
         .plugin(tauri_plugin_shell::init())
-        .invoke_handler(tauri::generate_handler![get_open_ports, kill_process])
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+        .manage(ScanState::default())
+        .manage(WatchState::default())
+        .manage(UsageSampler::default())
+        .manage(ReservationStore::default())
+        .setup(|app| {
+            let settings = SettingsStore::load(app.path().app_config_dir()?.join("settings.json"));
+            let history = HistoryStore::new(app.path().app_data_dir()?.join("history.jsonl"));
+            let _ = history.prune(settings.get().history_retention_days, now_millis());
+            app.state::<WatchState>()
+                .set_recording(settings.get().history_enabled);
+            app.manage(settings);
+            app.manage(history);
+
+            // 앱이 오래 켜져 있어도 보관 기간이 지난 이력을 지운다.
+            let handle = app.handle().clone();
+            tauri::async_runtime::spawn(async move {
+                loop {
+                    tokio::time::sleep(Duration::from_millis(history::PRUNE_INTERVAL_MS)).await;
+                    let handle = handle.clone();
+                    let _ = run_blocking(
+                        move || {
+                            let retention_days =
+                                handle.state::<SettingsStore>().get().history_retention_days;
+                            handle
+                                .state::<HistoryStore>()
+                                .prune(retention_days, now_millis())
+                        },
+                        DEFAULT_TIMEOUT_MS,
+                        None,
+                    )
+                    .await;
+                }
+            });
+
+            // 감시 루프는 앱 수명 동안 유지되며, 변화가 있을 때만 이벤트를 보낸다.
+            let handle = app.handle().clone();
+            tauri::async_runtime::spawn(async move {
+                let state = handle.state::<WatchState>();
+                let history = handle.state::<HistoryStore>();
+
+                // 조회할 때마다 소켓을 가진 프로세스의 사용량도 함께 보낸다. (스파크라인)
+                let scan = {
+                    let handle = handle.clone();
+                    move || {
+                        let rows = port::scan_open_ports(&OSCommandExecutor)?;
+                        let usage = handle
+                            .state::<UsageSampler>()
+                            .sample(&OSCommandExecutor, &rows);
+                        let _ = handle.emit("process-usage", &usage);
+                        Ok(rows)
+                    }
+                };
+
+                state
+                    .run(
+                        scan,
+                        |diff| {
+                            if !diff.opened.is_empty() {
+                                let _ = handle.emit("port-opened", &diff.opened);
+                            }
+                            if !diff.closed.is_empty() {
+                                let _ = handle.emit("port-closed", &diff.closed);
+                            }
+                        },
+                        // 기록을 시작하면 열려 있던 소켓을, 멈추면 남은 소켓의 닫힘을 쓴다.
+                        |current| {
+                            let _ = match current {
+                                Some(current) => history.record(current, now_millis()),
+                                None => history.stop_recording(now_millis()),
+                            };
+                        },
+                    )
+                    .await;
+            });
+            Ok(())
+        })
+        .invoke_handler(tauri::generate_handler![
+            get_open_ports,
+            stream_open_ports,
+            cancel_get_open_ports,
+            scan_open_ports_elevated,
+            kill_process,
+            get_process_context,
+            get_process_detail,
+            copy_text,
+            reveal_path,
+            run_bulk_action,
+            stop_container,
+            stop_unit,
+            check_port,
+            find_free_port,
+            reserve_port,
+            release_port,
+            list_reservations,
+            probe_ports,
+            fingerprint_ports,
+            save_snapshot,
+            diff_with_snapshot,
+            get_watch_status,
+            set_watch_interval,
+            pause_watch,
+            resume_watch,
+            get_settings,
+            update_settings,
+            query_history
+        ])
+        .build(tauri::generate_context!())
+        .expect("error while running tauri application")
+        .run(|app, event| {
+            // 종료할 때 열려 있던 소켓은 이 시각에 닫힌 것으로 기록한다.
+            if let tauri::RunEvent::Exit = event {
+                let _ = app
+                    .state::<HistoryStore>()
+                    .stop_recording(now_millis());
+            }
+        });
 }
@@ -2,5 +2,11 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
 fn main() {
+    // snapshot, diff 등의 하위 명령어가 있으면 GUI 없이 실행하고 종료
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if let Some(code) = open_port_check_lib::cli::run(&args) {
+        std::process::exit(code);
+    }
+
     open_port_check_lib::run()
 }
@@ -0,0 +1,50 @@
+use serde::{Deserialize, Serialize};
+
+/**
+ * 포트를 사용할 수 없는 이유
+ */
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum UnavailableReason {
+    // 다른 프로세스가 사용 중, 조회에 실패하면 pid는 None
+    #[serde(rename_all = "camelCase")]
+    InUse {
+        pid: Option<String>,
+        process_name: Option<String>,
+    },
+    // 1024 미만 포트를 관리자 권한 없이 열려고 함
+    PermissionDenied,
+    // 윈도우의 excludedportrange 등 OS가 예약한 구간
+    #[serde(rename_all = "camelCase")]
+    Excluded {
+        start: u16,
+        end: u16,
+    },
+    // 이 시스템에 없는 주소 (ex. IPv6 비활성화)
+    AddressNotAvailable,
+    #[serde(rename_all = "camelCase")]
+    Other {
+        message: String,
+    },
+}
+
+/**
+ * 주소 하나에 대한 bind 결과
+ */
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressAvailability {
+    pub address: String,
+    pub reason: Option<UnavailableReason>,
+}
+
+/**
+ * 요청한 모든 주소에서 포트를 열 수 있는지
+ */
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct PortAvailability {
+    pub port: u16,
+    pub available: bool,
+    pub addresses: Vec<AddressAvailability>,
+}
@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+
+use crate::structs::kill::KillSignal;
+
+/**
+ * 선택한 여러 행에 한 번에 실행할 작업
+ */
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum BulkAction {
+    // TERM이면 정상 종료 요청, KILL이면 강제 종료
+    Kill { signal: KillSignal },
+    // 다운로드 폴더에 CSV로 저장
+    Export,
+    // 클립보드에 복사
+    Copy,
+}
+
+/**
+ * pid 하나에 대한 결과
+ */
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct BulkResult {
+    pub pid: String,
+    pub success: bool,
+    // 실패한 이유
+    pub message: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct BulkOutcome {
+    pub results: Vec<BulkResult>,
+    // 내보낸 파일 경로
+    pub output: Option<String>,
+}
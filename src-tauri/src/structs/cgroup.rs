@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+
+/**
+ * /proc/<pid>/cgroup 으로 알아낸 프로세스의 소속
+ */
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum CgroupInfo {
+    // systemd 서비스, user가 true면 사용자 서비스 (systemctl --user)
+    // main이 false면 서비스의 주 프로세스가 아니다. (ex. 터미널 서비스 안에서 실행한 개발 서버)
+    #[serde(rename_all = "camelCase")]
+    SystemdUnit {
+        unit: String,
+        user: bool,
+        #[serde(default)]
+        main: bool,
+    },
+    // docker, podman, containerd, cri-o 컨테이너
+    #[serde(rename_all = "camelCase")]
+    Container { runtime: String, id: String },
+    // 로그인 세션 혹은 사용자 slice에서 직접 실행한 프로세스
+    #[serde(rename_all = "camelCase")]
+    UserSession { slice: String },
+    #[serde(rename_all = "camelCase")]
+    Other { path: String },
+}
@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+
+/**
+ * 포트를 점유한 컨테이너 정보
+ */
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[serde(rename_all = "camelCase")]
+pub struct ContainerSummary {
+    pub id: String,
+    pub name: String,
+    pub image: String,
+    pub compose_project: Option<String>,
+}
+
+/**
+ * 컨테이너와 외부에 공개된 포트
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContainerInfo {
+    pub summary: ContainerSummary,
+    // (protocol, public port)
+    pub public_ports: Vec<(String, u16)>,
+}
+
+/**
+ * key(PortInfo::key)에 해당하는 행에 컨테이너 정보를 채운다.
+ */
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ContainerPatch {
+    pub key: String,
+    pub container: ContainerSummary,
+}
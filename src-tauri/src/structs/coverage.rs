@@ -0,0 +1,39 @@
+use serde::{Deserialize, Serialize};
+
+use crate::structs::port::PortInfo;
+
+/**
+ * 조회 결과가 모든 소켓을 담고 있는지 나타낸다.
+ * 권한이 없으면 다른 사용자의 프로세스가 가진 소켓은 소유자를 알 수 없거나 아예 보이지 않는다.
+ */
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ScanCoverage {
+    // 관리자(root) 권한으로 조회했는지
+    pub elevated: bool,
+    // 소유 프로세스를 알 수 없는 소켓 수
+    pub unknown_owners: usize,
+    // 권한이 없어 fd 목록을 읽지 못한 프로세스 수 (리눅스 /proc/<pid>/fd)
+    pub unreadable_processes: usize,
+    // lsof가 출력한 권한 경고
+    pub warnings: Vec<String>,
+}
+
+impl ScanCoverage {
+    /**
+     * 일부 소켓이 빠졌거나 소유자를 알 수 없는 결과인지
+     */
+    pub fn is_partial(&self) -> bool {
+        self.unknown_owners > 0 || self.unreadable_processes > 0 || !self.warnings.is_empty()
+    }
+}
+
+/**
+ * 관리자 권한으로 다시 조회한 결과
+ */
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ElevatedScan {
+    pub rows: Vec<PortInfo>,
+    pub coverage: ScanCoverage,
+}
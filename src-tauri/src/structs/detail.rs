@@ -0,0 +1,51 @@
+use serde::{Deserialize, Serialize};
+
+use crate::structs::kill::ChildProcess;
+
+/**
+ * 프로세스가 가진 소켓 하나 (LISTEN 외의 상태 포함)
+ */
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SocketEntry {
+    pub protocol: String,
+    pub local_address: String,
+    // 대기 중인 소켓은 상대 주소가 없다.
+    pub remote_address: Option<String>,
+    pub state: String,
+}
+
+/**
+ * 환경 변수 하나, 값은 프론트엔드에서 기본으로 가린다.
+ */
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct EnvVar {
+    pub name: String,
+    pub value: String,
+}
+
+/**
+ * 상세 패널에 보여줄 프로세스 정보
+ * 운영체제나 권한에 따라 알 수 없는 항목은 None
+ */
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ProcessDetail {
+    pub pid: String,
+    pub process_name: Option<String>,
+    pub command_line: Option<String>,
+    pub executable: Option<String>,
+    pub cwd: Option<String>,
+    // 리눅스(/proc)와 맥(ps -E)에서만 읽는다. 다른 사용자의 프로세스라면 None
+    pub environment: Option<Vec<EnvVar>>,
+    // 부모부터 최상위 프로세스까지
+    pub ancestors: Vec<ChildProcess>,
+    pub sockets: Vec<SocketEntry>,
+    // 시작 이후 평균 CPU 사용률 (%)
+    pub cpu_percent: Option<f64>,
+    // 상주 메모리 (bytes)
+    pub memory_bytes: Option<u64>,
+    // 시작 시각 (unix 초)
+    pub started_at: Option<u64>,
+}
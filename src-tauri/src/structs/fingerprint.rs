@@ -0,0 +1,66 @@
+use serde::{Deserialize, Serialize};
+
+/**
+ * HTTP 응답으로 알아낸 서버 정보
+ */
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct HttpFingerprint {
+    pub url: String,
+    pub status: u16,
+    pub server: Option<String>,
+    pub title: Option<String>,
+    // 응답으로 추측한 프레임워크 (ex. Vite, Next.js)
+    pub framework: Option<String>,
+}
+
+/**
+ * TLS 핸드셰이크에서 받은 서버 인증서 정보
+ * TLS 1.3만 지원하는 서버는 인증서가 암호화되어 비어 있다.
+ */
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct TlsFingerprint {
+    pub subject: Option<String>,
+    pub issuer: Option<String>,
+    // 인증서 만료 시각 (unix time ms)
+    pub not_after: Option<u64>,
+    pub self_signed: bool,
+}
+
+/**
+ * 소켓 너머에서 동작하는 프로토콜
+ */
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(tag = "kind", content = "data", rename_all = "camelCase")]
+pub enum Fingerprint {
+    Http(HttpFingerprint),
+    // 인증이 필요하면 버전을 알 수 없다.
+    #[serde(rename_all = "camelCase")]
+    Redis {
+        version: Option<String>,
+        auth_required: bool,
+    },
+    // ssl은 SSLRequest 응답, 접속하지 않으므로 버전은 알 수 없다.
+    #[serde(rename_all = "camelCase")]
+    Postgres {
+        ssl: bool,
+    },
+    #[serde(rename_all = "camelCase")]
+    Mysql {
+        version: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    Ssh {
+        banner: String,
+    },
+    Tls(TlsFingerprint),
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct FingerprintResult {
+    // PortInfo::socket_key
+    pub socket_key: String,
+    pub fingerprint: Option<Fingerprint>,
+}
@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+
+use crate::structs::port::PortInfo;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum HistoryEventKind {
+    Opened,
+    Closed,
+}
+
+/**
+ * 감시자가 기록한 열림/닫힘 이벤트, history.jsonl에 한 줄씩 저장된다.
+ */
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct HistoryEvent {
+    // unix time (ms)
+    pub timestamp: u64,
+    pub kind: HistoryEventKind,
+    pub port: PortInfo,
+}
+
+/**
+ * 이력 조회 조건, 지정하지 않은 조건은 무시한다.
+ * from, to는 unix time (ms)이며 이 구간과 겹치는 점유 기간을 찾는다.
+ */
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct HistoryQuery {
+    pub port: Option<String>,
+    pub process_name: Option<String>,
+    pub from: Option<u64>,
+    pub to: Option<u64>,
+}
+
+/**
+ * 한 프로세스가 한 소켓을 점유한 기간
+ * 기록 시작 전부터 열려 있었다면 opened_at이, 아직 열려 있다면 closed_at이 None
+ */
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PortLifetime {
+    pub port: PortInfo,
+    pub opened_at: Option<u64>,
+    pub closed_at: Option<u64>,
+}
@@ -0,0 +1,70 @@
+use serde::{Deserialize, Serialize};
+
+/**
+ * 현재 사용자가 프로세스에 종료 신호를 보낼 수 있는지
+ */
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum KillPermission {
+    Allowed,
+    // 종료할 수 없는 이유 (ex. owned by root — requires administrator)
+    #[serde(rename_all = "camelCase")]
+    Denied {
+        reason: String,
+    },
+    // 미리 알 수 없어 시도해봐야 하는 경우 (ex. 윈도우에서 관리자 권한으로 실행된 같은 사용자의 프로세스)
+    #[serde(rename_all = "camelCase")]
+    Unknown {
+        reason: String,
+    },
+}
+
+/**
+ * 프로세스에 보낼 신호
+ * 윈도우는 KILL이면 강제 종료(taskkill /F), 나머지는 창 닫기 요청(taskkill)
+ */
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum KillSignal {
+    Term,
+    #[default]
+    Kill,
+    Int,
+    Hup,
+}
+
+impl KillSignal {
+    // kill -s에 넘길 이름
+    pub fn name(&self) -> &'static str {
+        match self {
+            KillSignal::Term => "TERM",
+            KillSignal::Kill => "KILL",
+            KillSignal::Int => "INT",
+            KillSignal::Hup => "HUP",
+        }
+    }
+}
+
+/**
+ * 종료하면 함께 영향을 받는 자식 프로세스
+ */
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ChildProcess {
+    pub pid: String,
+    pub process_name: String,
+}
+
+/**
+ * 종료 확인 창에 보여줄 프로세스 정보
+ */
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ProcessContext {
+    pub pid: String,
+    pub process_name: Option<String>,
+    // 실행 인자를 포함한 전체 명령어, 권한이 없으면 알 수 없다.
+    pub command_line: Option<String>,
+    // 손자 프로세스까지 포함한다.
+    pub children: Vec<ChildProcess>,
+}
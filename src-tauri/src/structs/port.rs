@@ -0,0 +1,111 @@
+use serde::{Deserialize, Serialize};
+
+use crate::structs::{
+    cgroup::CgroupInfo,
+    container::{ContainerPatch, ContainerSummary},
+    coverage::ScanCoverage,
+    kill::KillPermission,
+};
+
+/**
+ * LISTEN 상태인 소켓 하나와 그 소켓을 가진 프로세스 정보
+ */
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct PortInfo {
+    pub process_name: String,
+    pub port: String,
+    pub pid: String,
+    pub protocol: String,
+    pub address: String,
+    pub user: Option<String>,
+    // docker-proxy 등이 점유한 포트라면 실제 컨테이너
+    #[serde(default)]
+    pub container: Option<ContainerSummary>,
+    // 리눅스에서 소속된 systemd 서비스나 컨테이너
+    #[serde(default)]
+    pub cgroup: Option<CgroupInfo>,
+    // 잘 알려진 포트라면 서비스 이름 (ex. Vite HMR, postgresql)
+    #[serde(default)]
+    pub service: Option<String>,
+    // 이 앱이 예약해 둔 포트
+    #[serde(default)]
+    pub reserved: bool,
+    // 현재 사용자가 종료할 수 있는지
+    #[serde(default)]
+    pub kill_permission: Option<KillPermission>,
+    // 프로세스 시작 시각 (unix 초)
+    #[serde(default)]
+    pub started_at: Option<u64>,
+    // 이 포트로 맺어진 TCP 연결 수, UDP는 None
+    #[serde(default)]
+    pub connections: Option<usize>,
+}
+
+impl PortInfo {
+    /**
+     * 프론트엔드에서 행을 구분하는 키 (protocol, address, port, pid)
+     */
+    pub fn key(&self) -> String {
+        format!(
+            "{}_{}_{}_{}",
+            self.protocol, self.address, self.port, self.pid
+        )
+    }
+
+    /**
+     * 소켓 하나를 구분하는 키 (protocol, address, port)
+     * SO_REUSEPORT, 상속된 소켓처럼 여러 프로세스가 같은 소켓을 가질 수 있다.
+     */
+    pub fn socket_key(&self) -> String {
+        format!("{}_{}_{}", self.protocol, self.address, self.port)
+    }
+}
+
+/**
+ * 이름, 사용자, cgroup, 종료 권한, 시작 시각 조회 단계에서 나중에 채워지는 프로세스 정보
+ * 같은 pid를 가진 모든 행에 적용된다.
+ */
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ProcessPatch {
+    pub pid: String,
+    pub process_name: Option<String>,
+    pub user: Option<String>,
+    #[serde(default)]
+    pub cgroup: Option<CgroupInfo>,
+    #[serde(default)]
+    pub kill_permission: Option<KillPermission>,
+    #[serde(default)]
+    pub started_at: Option<u64>,
+}
+
+/**
+ * 로컬 주소와 포트 하나에 맺어진 TCP 연결 수
+ */
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ConnectionCount {
+    pub address: String,
+    pub port: u16,
+    pub count: usize,
+}
+
+/**
+ * 포트 조회 중 단계별로 프론트엔드에 전달되는 이벤트
+ */
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(tag = "event", content = "data", rename_all = "camelCase")]
+pub enum ScanEvent {
+    // 소켓 목록 (중복 제거 후)
+    Rows(Vec<PortInfo>),
+    // 프로세스 이름, 사용자, cgroup, 종료 권한, 시작 시각 보강
+    Patch(Vec<ProcessPatch>),
+    // 포트마다 맺어진 연결 수
+    Connections(Vec<ConnectionCount>),
+    // Docker 컨테이너 보강
+    Containers(Vec<ContainerPatch>),
+    // 권한 부족으로 일부만 보이는 결과인지
+    Coverage(ScanCoverage),
+    Finished,
+}
@@ -0,0 +1,30 @@
+use serde::{Deserialize, Serialize};
+
+/**
+ * 소켓에 연결을 시도한 결과
+ */
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum ProbeStatus {
+    // 연결에 걸린 시간 (ms)
+    #[serde(rename_all = "camelCase")]
+    Healthy {
+        latency_ms: u64,
+    },
+    #[serde(rename_all = "camelCase")]
+    Failed {
+        message: String,
+    },
+    // UDP 등 연결로 확인할 수 없는 소켓
+    Skipped,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ProbeResult {
+    // PortInfo::socket_key
+    pub socket_key: String,
+    pub target: Option<String>,
+    pub status: ProbeStatus,
+    pub checked_at: u64,
+}
@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+/**
+ * 앱이 붙잡고 있는 포트
+ */
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Reservation {
+    pub port: u16,
+    pub address: String,
+    pub reserved_at: u64,
+}
@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+
+use crate::structs::kill::KillSignal;
+
+pub const DEFAULT_HISTORY_RETENTION_DAYS: u32 = 7;
+
+/**
+ * 앱 설정, app config dir의 settings.json에 저장된다.
+ * 새 필드가 추가되어도 이전 파일을 읽을 수 있도록 기본값을 사용한다.
+ */
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Settings {
+    // 감시 이벤트를 기록할지 여부 (opt-in)
+    pub history_enabled: bool,
+    pub history_retention_days: u32,
+    // 종료 확인 창을 건너뛸 프로세스 이름
+    pub skip_kill_confirm: Vec<String>,
+    // 마지막으로 고른 신호, 확인 창을 건너뛸 때도 사용한다.
+    pub kill_signal: KillSignal,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Settings {
+            history_enabled: false,
+            history_retention_days: DEFAULT_HISTORY_RETENTION_DAYS,
+            skip_kill_confirm: Vec::new(),
+            kill_signal: KillSignal::default(),
+        }
+    }
+}
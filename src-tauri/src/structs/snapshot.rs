@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+
+use crate::structs::port::PortInfo;
+
+/**
+ * 특정 시점의 포트 목록, 파일로 저장해 나중에 비교한다.
+ */
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Snapshot {
+    // unix time (ms)
+    pub taken_at: u64,
+    pub ports: Vec<PortInfo>,
+}
+
+/**
+ * 같은 소켓(protocol, address, port)을 가진 프로세스가 바뀐 경우
+ */
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangedPort {
+    pub before: Vec<PortInfo>,
+    pub after: Vec<PortInfo>,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct SnapshotDiff {
+    pub added: Vec<PortInfo>,
+    pub removed: Vec<PortInfo>,
+    pub changed: Vec<ChangedPort>,
+}
+
+impl SnapshotDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
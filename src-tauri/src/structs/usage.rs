@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+/**
+ * 프로세스 하나의 CPU, 메모리 사용량 (감시 간격마다)
+ */
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ProcessUsage {
+    pub pid: String,
+    // 직전 측정 이후 CPU 사용률 (%, 코어 하나가 100), 첫 측정에서는 알 수 없다.
+    pub cpu_percent: Option<f64>,
+    // 상주 메모리 (bytes)
+    pub memory_bytes: Option<u64>,
+    // 측정 시각 (unix time, ms)
+    pub sampled_at: u64,
+}
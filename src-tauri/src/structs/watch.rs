@@ -0,0 +1,26 @@
+use serde::Serialize;
+
+use crate::structs::port::PortInfo;
+
+/**
+ * 이전 스냅샷과 비교해 새로 열리거나 닫힌 소켓
+ */
+#[derive(Serialize, Debug, Clone, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct WatchDiff {
+    pub opened: Vec<PortInfo>,
+    pub closed: Vec<PortInfo>,
+}
+
+impl WatchDiff {
+    pub fn is_empty(&self) -> bool {
+        self.opened.is_empty() && self.closed.is_empty()
+    }
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct WatchStatus {
+    pub paused: bool,
+    pub interval_ms: u64,
+}
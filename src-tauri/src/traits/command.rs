@@ -1,31 +1,112 @@
+use std::io::Write;
 use std::process::{Command, Stdio};
 pub struct OSCommandExecutor;
 
 pub trait CommandExecutor {
     fn execute_command(&self, command: &str, args: &[&str]) -> Result<String, String>;
+
+    /**
+     * input을 표준 입력으로 넘기고 종료 코드만 확인한다.
+     * 클립보드 명령어(xclip, wl-copy)는 백그라운드 프로세스를 남기므로 출력은 받지 않는다.
+     */
+    fn execute_command_with_input(
+        &self,
+        command: &str,
+        _args: &[&str],
+        _input: &str,
+    ) -> Result<(), String> {
+        Err(format!("Unsupported command: {}", command))
+    }
+}
+
+/**
+ * 표준 입출력을 파이프로 연결한 명령어를 만든다.
+ */
+pub fn new_command(command: &str, args: &[&str]) -> Command {
+    let mut cmd = Command::new(command);
+    cmd.args(args)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+
+    // 윈도우 환경에서 특정 플래그를 설정하여 창이 안나오게 설정
+    // FLAG => CREATE_NO_WINDOW
+    #[cfg(windows)]
+    {
+        use std::os::windows::process::CommandExt;
+        cmd.creation_flags(0x08000000);
+    }
+
+    cmd
 }
 
 impl CommandExecutor for OSCommandExecutor {
     fn execute_command(&self, command: &str, args: &[&str]) -> Result<String, String> {
-        let mut cmd = Command::new(command);
-        cmd.args(args)
-            .stdin(Stdio::null())
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped());
-
-        // 윈도우 환경에서 특정 플래그를 설정하여 창이 안나오게 설정
-        // FLAG => CREATE_NO_WINDOW
-        #[cfg(windows)]
-        {
-            use std::os::windows::process::CommandExt;
-            cmd.creation_flags(0x08000000);
-        }
-
-        let output = cmd
+        let output = new_command(command, args)
             .output()
             .map_err(|e| format!("Failed to execute command: {}", e))?;
 
         let result = String::from_utf8_lossy(&output.stdout).to_string();
         Ok(result)
     }
+
+    fn execute_command_with_input(
+        &self,
+        command: &str,
+        args: &[&str],
+        input: &str,
+    ) -> Result<(), String> {
+        // 남은 프로세스가 파이프를 물고 있으면 wait가 끝나지 않으므로 출력을 버린다.
+        let mut child = new_command(command, args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+            .map_err(|e| format!("Failed to execute command: {}", e))?;
+
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin
+                .write_all(input.as_bytes())
+                .map_err(|e| format!("Failed to write to command: {}", e))?;
+        }
+
+        let status = child
+            .wait()
+            .map_err(|e| format!("Failed to execute command: {}", e))?;
+
+        if status.success() {
+            Ok(())
+        } else {
+            Err(format!("{} exited with {}", command, status))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{Duration, Instant};
+
+    #[test]
+    #[cfg(unix)]
+    fn test_execute_command_with_input_detached_child() {
+        // xclip처럼 표준 출력을 물고 있는 자식 프로세스를 남기고 끝나는 명령어
+        let started = Instant::now();
+        let result = OSCommandExecutor.execute_command_with_input(
+            "sh",
+            &["-c", "cat > /dev/null; (sleep 5; echo done) & exit 0"],
+            "text",
+        );
+
+        assert_eq!(result, Ok(()));
+        assert!(started.elapsed() < Duration::from_secs(3));
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_execute_command_with_input_status() {
+        assert!(OSCommandExecutor
+            .execute_command_with_input("sh", &["-c", "exit 1"], "text")
+            .is_err());
+    }
 }
@@ -0,0 +1,198 @@
+use std::fs;
+use std::path::Path;
+use std::process::Output;
+
+use crate::{enums::error::CommandError, traits::command::new_command};
+
+pub struct OSElevator;
+
+/**
+ * 프로그램을 관리자(root) 권한으로 실행하고 표준 출력을 돌려준다.
+ * 사용자가 인증 창을 닫으면 Cancelled, 인증에 실패하면 표준 에러를 담은 Failed
+ */
+pub trait Elevator {
+    fn run_elevated(&self, program: &Path, args: &[&str]) -> Result<String, CommandError>;
+}
+
+/**
+ * sh에서 그대로 읽히도록 작은따옴표로 감싼다.
+ */
+pub fn shell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', r"'\''"))
+}
+
+/**
+ * AppleScript 문자열 리터럴
+ */
+pub fn applescript_string(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', r"\\").replace('"', "\\\""))
+}
+
+/**
+ * PowerShell의 작은따옴표 문자열 리터럴
+ */
+pub fn powershell_string(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
+
+/**
+ * 실패로 끝난 권한 상승을 에러로 바꾼다.
+ * pkexec은 인증 창을 닫으면 126, osascript는 -128, UAC는 "canceled by the user"로 끝난다.
+ */
+pub fn elevation_error(code: Option<i32>, stderr: &str) -> CommandError {
+    let stderr = stderr.trim();
+
+    if code == Some(126) || stderr.contains("(-128)") || stderr.contains("canceled by the user") {
+        return CommandError::Cancelled;
+    }
+
+    if stderr.is_empty() {
+        match code {
+            Some(code) => {
+                CommandError::Failed(format!("Elevated command exited with status {}", code))
+            }
+            None => CommandError::Failed("Elevated command was terminated".to_string()),
+        }
+    } else {
+        CommandError::Failed(stderr.to_string())
+    }
+}
+
+/**
+ * 종료 코드를 확인해 성공했을 때만 표준 출력을 돌려준다.
+ */
+fn checked_output(output: Output) -> Result<String, CommandError> {
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).to_string())
+    } else {
+        Err(elevation_error(
+            output.status.code(),
+            &String::from_utf8_lossy(&output.stderr),
+        ))
+    }
+}
+
+fn run_checked(program: &str, args: &[&str]) -> Result<String, CommandError> {
+    new_command(program, args)
+        .output()
+        .map_err(|e| CommandError::Failed(format!("Failed to execute command: {}", e)))
+        .and_then(checked_output)
+}
+
+impl Elevator for OSElevator {
+    fn run_elevated(&self, program: &Path, args: &[&str]) -> Result<String, CommandError> {
+        let program = program.to_string_lossy().to_string();
+
+        if cfg!(target_os = "windows") {
+            // 관리자 프로세스의 표준 출력은 넘겨받을 수 없으므로 --output 파일로 받는다.
+            let output = std::env::temp_dir().join(format!(
+                "open_port_check_elevated_{}.json",
+                std::process::id()
+            ));
+            let _ = fs::remove_file(&output);
+
+            let output_path = output.to_string_lossy().to_string();
+            let argument_list = args
+                .iter()
+                .copied()
+                .chain(["--output", output_path.as_str()])
+                .map(powershell_string)
+                .collect::<Vec<String>>()
+                .join(",");
+            let script = format!(
+                "Start-Process -FilePath {} -ArgumentList {} -Verb RunAs -Wait -WindowStyle Hidden",
+                powershell_string(&program),
+                argument_list
+            );
+
+            run_checked(
+                "powershell",
+                &["-NoProfile", "-NonInteractive", "-Command", &script],
+            )?;
+
+            // 관리자 프로세스가 실패하면 파일이 만들어지지 않는다.
+            let result = fs::read_to_string(&output).map_err(|_| {
+                CommandError::Failed("Elevated scan did not write its output".to_string())
+            });
+            let _ = fs::remove_file(&output);
+            result
+        } else if cfg!(target_os = "macos") {
+            let command = std::iter::once(program.as_str())
+                .chain(args.iter().copied())
+                .map(shell_quote)
+                .collect::<Vec<String>>()
+                .join(" ");
+            let script = format!(
+                "do shell script {} with administrator privileges",
+                applescript_string(&command)
+            );
+
+            run_checked("osascript", &["-e", &script])
+        } else {
+            let mut command = vec![program.as_str()];
+            command.extend_from_slice(args);
+
+            // pkexec은 polkit 인증 창을 띄운다. 설치되어 있지 않다면 비밀번호를 묻지 않는 sudo -n
+            match new_command("pkexec", &command).output() {
+                Ok(output) => checked_output(output),
+                Err(_) => {
+                    let mut sudo = vec!["-n"];
+                    sudo.extend_from_slice(&command);
+                    run_checked("sudo", &sudo)
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quote() {
+        assert_eq!(
+            shell_quote("/Applications/My App"),
+            "'/Applications/My App'"
+        );
+        assert_eq!(shell_quote("it's"), r"'it'\''s'");
+        assert_eq!(applescript_string(r#"say "hi" \"#), r#""say \"hi\" \\""#);
+        assert_eq!(powershell_string(r"C:\it's"), r"'C:\it''s'");
+    }
+
+    #[test]
+    fn test_elevation_error() {
+        // 인증 창을 닫음
+        assert_eq!(elevation_error(Some(126), ""), CommandError::Cancelled);
+        assert_eq!(
+            elevation_error(Some(1), "execution error: User canceled. (-128)\n"),
+            CommandError::Cancelled
+        );
+        assert_eq!(
+            elevation_error(
+                Some(1),
+                "Start-Process : The operation was canceled by the user."
+            ),
+            CommandError::Cancelled
+        );
+
+        // 인증 실패
+        assert_eq!(
+            elevation_error(
+                Some(127),
+                "Error executing command as another user: Not authorized\n"
+            ),
+            CommandError::Failed(
+                "Error executing command as another user: Not authorized".to_string()
+            )
+        );
+        assert_eq!(
+            elevation_error(Some(1), "sudo: a password is required\n"),
+            CommandError::Failed("sudo: a password is required".to_string())
+        );
+        assert_eq!(
+            elevation_error(Some(2), ""),
+            CommandError::Failed("Elevated command exited with status 2".to_string())
+        );
+    }
+}
@@ -0,0 +1,383 @@
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::sync::OnceLock;
+
+use crate::{
+    structs::port::{ConnectionCount, PortInfo, ProcessPatch},
+    traits::command::CommandExecutor,
+    utils::{
+        procfs::{parse_proc_net_state, TCP_ESTABLISHED},
+        time::now_millis,
+    },
+};
+
+// getconf를 실행할 수 없을 때의 USER_HZ, 대부분의 리눅스 아키텍처에서 100이다.
+const DEFAULT_CLOCK_TICKS_PER_SECOND: u64 = 100;
+
+/**
+ * /proc/<pid>/stat의 시각 단위(USER_HZ)
+ * 실행 중에는 바뀌지 않으므로 getconf CLK_TCK를 한 번만 읽는다.
+ */
+pub fn clock_ticks_per_second(executor: &dyn CommandExecutor) -> u64 {
+    static TICKS: OnceLock<u64> = OnceLock::new();
+    *TICKS.get_or_init(|| {
+        executor
+            .execute_command("getconf", &["CLK_TCK"])
+            .ok()
+            .and_then(|output| output.trim().parse::<u64>().ok())
+            .filter(|ticks| *ticks > 0)
+            .unwrap_or(DEFAULT_CLOCK_TICKS_PER_SECOND)
+    })
+}
+
+/**
+ * ps의 etime(실행된 시간)을 초로 바꾼다.
+ *
+ * ex) 05:09 => 309, 01:02:03 => 3723, 2-01:02:03 => 176523
+ */
+pub fn parse_etime(value: &str) -> Option<u64> {
+    let (days, clock) = match value.trim().split_once('-') {
+        Some((days, clock)) => (days.parse::<u64>().ok()?, clock),
+        None => (0, value.trim()),
+    };
+
+    let parts = clock
+        .split(':')
+        .map(|part| part.parse::<u64>().ok())
+        .collect::<Option<Vec<u64>>>()?;
+    let seconds = match parts.as_slice() {
+        [minutes, seconds] => minutes * 60 + seconds,
+        [hours, minutes, seconds] => hours * 3600 + minutes * 60 + seconds,
+        _ => return None,
+    };
+
+    Some(days * 86400 + seconds)
+}
+
+/**
+ * ps -A -o pid=,etime= 결과로 pid => 시작 시각(unix 초)
+ *
+ * ex)
+ *  1234    01:02:03
+ */
+pub fn parse_ps_etime(output: &str, now: u64) -> HashMap<String, u64> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let (pid, etime) = line.trim().split_once(char::is_whitespace)?;
+            let elapsed = parse_etime(etime)?;
+            Some((pid.to_string(), now.saturating_sub(elapsed)))
+        })
+        .collect()
+}
+
+/**
+ * /proc/<pid>/stat의 22번째 값, 부팅 후 시작될 때까지의 tick
+ * 이름에 공백이 있을 수 있으므로 마지막 ')' 뒤(3번째 값)부터 센다.
+ */
+pub fn parse_proc_start_ticks(stat: &str) -> Option<u64> {
+    stat.get(stat.rfind(')')? + 1..)?
+        .split_whitespace()
+        .nth(19)?
+        .parse()
+        .ok()
+}
+
+/**
+ * /proc/stat의 btime, 부팅 시각(unix 초)
+ */
+pub fn parse_boot_time(stat: &str) -> Option<u64> {
+    stat.lines()
+        .find_map(|line| line.strip_prefix("btime "))
+        .and_then(|value| value.trim().parse().ok())
+}
+
+/**
+ * "<pid>,<unix 초>" 형식의 줄 (윈도우 PowerShell 결과)
+ */
+pub fn parse_start_csv(output: &str) -> HashMap<String, u64> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let (pid, started_at) = line.trim().split_once(',')?;
+            Some((pid.to_string(), started_at.parse().ok()?))
+        })
+        .collect()
+}
+
+fn start_times(executor: &dyn CommandExecutor, pids: &HashSet<&str>) -> HashMap<String, u64> {
+    if cfg!(target_os = "windows") {
+        let script = "Get-CimInstance Win32_Process | Where-Object { $_.CreationDate } | ForEach-Object { '{0},{1}' -f $_.ProcessId, ([DateTimeOffset]$_.CreationDate).ToUnixTimeSeconds() }";
+        executor
+            .execute_command(
+                "powershell",
+                &["-NoProfile", "-NonInteractive", "-Command", script],
+            )
+            .map(|output| parse_start_csv(&output))
+            .unwrap_or_default()
+    } else if cfg!(target_os = "linux") {
+        let Some(boot_time) = fs::read_to_string("/proc/stat")
+            .ok()
+            .and_then(|stat| parse_boot_time(&stat))
+        else {
+            return HashMap::new();
+        };
+
+        let ticks_per_second = clock_ticks_per_second(executor);
+        pids.iter()
+            .filter_map(|pid| {
+                let stat = fs::read_to_string(format!("/proc/{}/stat", pid)).ok()?;
+                let ticks = parse_proc_start_ticks(&stat)?;
+                Some((pid.to_string(), boot_time + ticks / ticks_per_second))
+            })
+            .collect()
+    } else {
+        executor
+            .execute_command("ps", &["-A", "-o", "pid=,etime="])
+            .map(|output| parse_ps_etime(&output, now_millis() / 1000))
+            .unwrap_or_default()
+    }
+}
+
+/**
+ * 프로세스 하나의 시작 시각 (상세 패널)
+ */
+pub fn process_start_time(executor: &dyn CommandExecutor, pid: &str) -> Option<u64> {
+    start_times(executor, &HashSet::from([pid])).remove(pid)
+}
+
+/**
+ * 소켓 목록에 있는 pid들의 시작 시각
+ */
+pub fn resolve_start_times(executor: &dyn CommandExecutor, rows: &[PortInfo]) -> Vec<ProcessPatch> {
+    let pids: HashSet<&str> = rows.iter().map(|row| row.pid.as_str()).collect();
+    if pids.is_empty() {
+        return Vec::new();
+    }
+
+    let mut patches: Vec<ProcessPatch> = start_times(executor, &pids)
+        .into_iter()
+        .filter(|(pid, _)| pids.contains(pid.as_str()))
+        .map(|(pid, started_at)| ProcessPatch {
+            pid,
+            started_at: Some(started_at),
+            ..ProcessPatch::default()
+        })
+        .collect();
+    patches.sort_by(|a, b| a.pid.cmp(&b.pid));
+
+    patches
+}
+
+/**
+ * netstat 결과에서 ESTABLISHED인 연결의 로컬 주소와 포트
+ * 맥은 "주소.포트", 윈도우는 "주소:포트" 형식이다.
+ *
+ * ex)
+ * tcp4       0      0  127.0.0.1.3000         127.0.0.1.54321        ESTABLISHED
+ *   TCP    127.0.0.1:3000         127.0.0.1:54321        ESTABLISHED     1234
+ */
+pub fn parse_netstat_established(output: &str) -> Vec<(String, u16)> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let columns: Vec<&str> = line.split_whitespace().collect();
+            let local = match columns.as_slice() {
+                [proto, local, _, "ESTABLISHED", ..] if proto.eq_ignore_ascii_case("TCP") => local,
+                [proto, _, _, local, _, "ESTABLISHED", ..] if proto.starts_with("tcp") => local,
+                _ => return None,
+            };
+            let (address, port) = local.rsplit_once([':', '.'])?;
+            let address = address.trim_start_matches('[').trim_end_matches(']');
+            Some((address.to_string(), port.parse().ok()?))
+        })
+        .collect()
+}
+
+/**
+ * 로컬 주소와 포트마다 맺어진 TCP 연결 수
+ * 다른 주소에 같은 포트로 맺어진 연결(ex. 나가는 연결의 임시 포트)을 섞지 않기 위해 주소까지 본다.
+ */
+pub fn count_connections(executor: &dyn CommandExecutor) -> Vec<ConnectionCount> {
+    let sockets = if cfg!(target_os = "windows") {
+        executor
+            .execute_command("netstat", &["-ano", "-p", "TCP"])
+            .map(|output| parse_netstat_established(&output))
+            .unwrap_or_default()
+    } else if cfg!(target_os = "linux") {
+        ["/proc/net/tcp", "/proc/net/tcp6"]
+            .iter()
+            .filter_map(|file| fs::read_to_string(file).ok())
+            .flat_map(|content| parse_proc_net_state(&content, TCP_ESTABLISHED))
+            .map(|socket| (socket.address.to_string(), socket.port))
+            .collect()
+    } else {
+        executor
+            .execute_command("netstat", &["-an", "-p", "tcp"])
+            .map(|output| parse_netstat_established(&output))
+            .unwrap_or_default()
+    };
+
+    let mut counts: HashMap<(String, u16), usize> = HashMap::new();
+    for socket in sockets {
+        *counts.entry(socket).or_insert(0) += 1;
+    }
+
+    let mut connections: Vec<ConnectionCount> = counts
+        .into_iter()
+        .map(|((address, port), count)| ConnectionCount {
+            address,
+            port,
+            count,
+        })
+        .collect();
+    connections.sort_by(|a, b| (a.port, &a.address).cmp(&(b.port, &b.address)));
+    connections
+}
+
+/**
+ * 행의 주소에 맺어진 연결인지
+ * 모든 주소(0.0.0.0, ::, *)에서 기다리는 행은 그 포트의 모든 연결을 받는다.
+ */
+pub fn is_connection_of(row_address: &str, address: &str) -> bool {
+    matches!(row_address, "" | "*" | "0.0.0.0" | "::") || row_address.eq_ignore_ascii_case(address)
+}
+
+/**
+ * TCP 행마다 연결 수를 채운다. 연결이 없다면 0, UDP는 연결이 없으므로 비워 둔다.
+ */
+pub fn apply_connections(rows: &mut [PortInfo], connections: &[ConnectionCount]) {
+    for row in rows.iter_mut() {
+        if !row.protocol.eq_ignore_ascii_case("TCP") {
+            continue;
+        }
+        if let Ok(port) = row.port.parse::<u16>() {
+            row.connections = Some(
+                connections
+                    .iter()
+                    .filter(|connection| {
+                        connection.port == port
+                            && is_connection_of(&row.address, &connection.address)
+                    })
+                    .map(|connection| connection.count)
+                    .sum(),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_etime() {
+        assert_eq!(parse_etime("05:09"), Some(309));
+        assert_eq!(parse_etime(" 01:02:03"), Some(3723));
+        assert_eq!(parse_etime("2-01:02:03"), Some(176523));
+        assert_eq!(parse_etime("abc"), None);
+        assert_eq!(parse_etime("1:2:3:4"), None);
+    }
+
+    #[test]
+    fn test_parse_ps_etime() {
+        let started = parse_ps_etime("    1 10-00:00:00\n 1234       01:40\n", 1_000_000);
+
+        assert_eq!(started.get("1"), Some(&136_000));
+        assert_eq!(started.get("1234"), Some(&999_900));
+    }
+
+    #[test]
+    fn test_parse_proc_start() {
+        let stat = "1234 (tmux: server) S 1 1234 1234 0 -1 4194560 100 0 0 0 5 3 0 0 20 0 1 0 98765 1000 200";
+
+        assert_eq!(parse_proc_start_ticks(stat), Some(98765));
+        assert_eq!(parse_proc_start_ticks("garbage"), None);
+        assert_eq!(
+            parse_boot_time("cpu  1 2 3\nbtime 1700000000\nprocesses 10\n"),
+            Some(1_700_000_000)
+        );
+    }
+
+    #[test]
+    fn test_parse_start_csv() {
+        let started = parse_start_csv("1234,1700000000\r\n4,\n");
+
+        assert_eq!(started.get("1234"), Some(&1_700_000_000));
+        assert_eq!(started.get("4"), None);
+    }
+
+    #[test]
+    fn test_parse_netstat_established() {
+        let mac = "\
+Active Internet connections (including servers)
+Proto Recv-Q Send-Q  Local Address          Foreign Address        (state)
+tcp4       0      0  127.0.0.1.3000         127.0.0.1.54321        ESTABLISHED
+tcp6       0      0  ::1.5432               ::1.60000              ESTABLISHED
+tcp4       0      0  *.3000                 *.*                    LISTEN
+";
+        let windows = "
+  Proto  Local Address          Foreign Address        State           PID
+  TCP    127.0.0.1:3000         127.0.0.1:54321        ESTABLISHED     1234
+  TCP    [::1]:5432             [::1]:60000            ESTABLISHED     2200
+  TCP    0.0.0.0:3000           0.0.0.0:0              LISTENING       1234
+";
+
+        assert_eq!(
+            parse_netstat_established(mac),
+            vec![("127.0.0.1".to_string(), 3000), ("::1".to_string(), 5432)]
+        );
+        assert_eq!(
+            parse_netstat_established(windows),
+            vec![("127.0.0.1".to_string(), 3000), ("::1".to_string(), 5432)]
+        );
+    }
+
+    fn tcp(address: &str, port: &str) -> PortInfo {
+        PortInfo {
+            port: port.to_string(),
+            protocol: "TCP".to_string(),
+            address: address.to_string(),
+            ..PortInfo::default()
+        }
+    }
+
+    fn connection(address: &str, port: u16, count: usize) -> ConnectionCount {
+        ConnectionCount {
+            address: address.to_string(),
+            port,
+            count,
+        }
+    }
+
+    #[test]
+    fn test_apply_connections() {
+        let mut rows = vec![
+            tcp("0.0.0.0", "3000"),
+            tcp("127.0.0.1", "8080"),
+            tcp("127.0.0.1", "9000"),
+            PortInfo {
+                port: "5353".to_string(),
+                protocol: "UDP".to_string(),
+                ..PortInfo::default()
+            },
+        ];
+
+        apply_connections(
+            &mut rows,
+            &[
+                connection("127.0.0.1", 3000, 2),
+                connection("192.168.0.2", 3000, 1),
+                // 같은 포트 번호지만 다른 주소의 연결
+                connection("192.168.0.2", 8080, 4),
+                connection("127.0.0.1", 9000, 1),
+                connection("0.0.0.0", 5353, 1),
+            ],
+        );
+
+        assert_eq!(rows[0].connections, Some(3));
+        assert_eq!(rows[1].connections, Some(0));
+        assert_eq!(rows[2].connections, Some(1));
+        assert_eq!(rows[3].connections, None);
+    }
+}
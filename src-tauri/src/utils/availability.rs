@@ -0,0 +1,357 @@
+use std::io::ErrorKind;
+use std::net::{IpAddr, SocketAddr, TcpListener};
+
+use crate::{
+    enums::error::CommandError,
+    structs::{
+        availability::{AddressAvailability, PortAvailability, UnavailableReason},
+        port::PortInfo,
+    },
+    traits::command::CommandExecutor,
+    utils::port::scan_open_ports,
+};
+
+// 주소를 지정하지 않으면 IPv4, IPv6 모든 주소에서 확인한다.
+pub const DEFAULT_ADDRESSES: [&str; 2] = ["0.0.0.0", "::"];
+
+pub fn parse_addresses(addresses: &[String]) -> Result<Vec<IpAddr>, CommandError> {
+    if addresses.is_empty() {
+        return Ok(DEFAULT_ADDRESSES
+            .iter()
+            .filter_map(|address| address.parse().ok())
+            .collect());
+    }
+
+    addresses
+        .iter()
+        .map(|address| {
+            address
+                .trim_matches(|c| c == '[' || c == ']')
+                .parse::<IpAddr>()
+                .map_err(|_| CommandError::Failed(format!("Invalid address: {}", address)))
+        })
+        .collect()
+}
+
+/**
+ * 윈도우의 `netsh interface ipv4 show excludedportrange protocol=tcp` 결과를 파싱한다.
+ *
+ * ex)
+ * `Start Port    End Port`
+ * `----------    --------`
+ * `      5357        5357`
+ * `     50000       50059     *`
+ */
+pub fn parse_excluded_ranges(output: &str) -> Vec<(u16, u16)> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let mut columns = line.split_whitespace();
+            let start = columns.next()?.parse::<u16>().ok()?;
+            let end = columns.next()?.parse::<u16>().ok()?;
+            Some((start, end))
+        })
+        .collect()
+}
+
+/**
+ * OS가 예약해 bind할 수 없는 포트 구간, 윈도우(Hyper-V, WSL)에서만 존재한다.
+ */
+pub fn excluded_port_ranges(executor: &dyn CommandExecutor) -> Vec<(u16, u16)> {
+    if !cfg!(target_os = "windows") {
+        return Vec::new();
+    }
+
+    executor
+        .execute_command(
+            "netsh",
+            &[
+                "interface",
+                "ipv4",
+                "show",
+                "excludedportrange",
+                "protocol=tcp",
+            ],
+        )
+        .map(|output| parse_excluded_ranges(&output))
+        .unwrap_or_default()
+}
+
+/**
+ * 주소마다 실제로 bind를 시도해 포트를 열 수 있는지 확인한다.
+ * 목록 조회만으로는 권한, 예약 구간, IPv6 지원 여부를 알 수 없다.
+ */
+pub fn probe_port(port: u16, addresses: &[IpAddr], excluded: &[(u16, u16)]) -> PortAvailability {
+    let excluded = excluded
+        .iter()
+        .find(|(start, end)| (*start..=*end).contains(&port));
+
+    let addresses: Vec<AddressAvailability> = addresses
+        .iter()
+        .map(|address| {
+            let reason = match excluded {
+                Some((start, end)) => Some(UnavailableReason::Excluded {
+                    start: *start,
+                    end: *end,
+                }),
+                // 확인만 하고 바로 닫는다.
+                None => TcpListener::bind(SocketAddr::new(*address, port))
+                    .err()
+                    .map(|e| match e.kind() {
+                        ErrorKind::AddrInUse => UnavailableReason::InUse {
+                            pid: None,
+                            process_name: None,
+                        },
+                        ErrorKind::PermissionDenied => UnavailableReason::PermissionDenied,
+                        ErrorKind::AddrNotAvailable => UnavailableReason::AddressNotAvailable,
+                        _ => UnavailableReason::Other {
+                            message: e.to_string(),
+                        },
+                    }),
+            };
+
+            AddressAvailability {
+                address: address.to_string(),
+                reason,
+            }
+        })
+        .collect();
+
+    PortAvailability {
+        port,
+        available: addresses.iter().all(|address| address.reason.is_none()),
+        addresses,
+    }
+}
+
+/**
+ * 사용 중인 포트에 점유한 프로세스를 채운다.
+ */
+pub fn fill_owners(availability: &mut PortAvailability, rows: &[PortInfo]) {
+    let port = availability.port.to_string();
+    let owner = rows
+        .iter()
+        .find(|row| row.port == port && row.protocol.eq_ignore_ascii_case("TCP"));
+
+    let Some(owner) = owner else {
+        return;
+    };
+
+    for address in availability.addresses.iter_mut() {
+        if let Some(UnavailableReason::InUse { pid, process_name }) = &mut address.reason {
+            *pid = Some(owner.pid.clone());
+            *process_name = Some(owner.process_name.clone());
+        }
+    }
+}
+
+fn is_in_use(availability: &PortAvailability) -> bool {
+    availability
+        .addresses
+        .iter()
+        .any(|address| matches!(address.reason, Some(UnavailableReason::InUse { .. })))
+}
+
+/**
+ * 0은 OS가 임의의 포트를 고르라는 뜻이라 bind는 항상 성공한다.
+ */
+fn validate_port(port: u16) -> Result<(), CommandError> {
+    if port == 0 {
+        return Err(CommandError::Failed("Invalid port: 0".to_string()));
+    }
+    Ok(())
+}
+
+/**
+ * 포트 하나를 확인하고, 사용 중이라면 점유한 프로세스를 조회한다.
+ */
+pub fn check_port(
+    executor: &dyn CommandExecutor,
+    port: u16,
+    addresses: &[IpAddr],
+) -> Result<PortAvailability, CommandError> {
+    validate_port(port)?;
+
+    let mut availability = probe_port(port, addresses, &excluded_port_ranges(executor));
+
+    if is_in_use(&availability) {
+        if let Ok(rows) = scan_open_ports(executor) {
+            fill_owners(&mut availability, &rows);
+        }
+    }
+
+    Ok(availability)
+}
+
+/**
+ * start..=end 구간에서 모든 주소에 열 수 있는 첫 포트를 찾는다.
+ */
+pub fn find_free_port(
+    executor: &dyn CommandExecutor,
+    start: u16,
+    end: u16,
+    addresses: &[IpAddr],
+) -> Result<Option<PortAvailability>, CommandError> {
+    validate_port(start)?;
+    if start > end {
+        return Err(CommandError::Failed(format!(
+            "Invalid range: {}-{}",
+            start, end
+        )));
+    }
+
+    let excluded = excluded_port_ranges(executor);
+
+    Ok((start..=end)
+        .map(|port| probe_port(port, addresses, &excluded))
+        .find(|availability| availability.available))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LOCALHOST: [&str; 1] = ["127.0.0.1"];
+
+    fn localhost() -> Vec<IpAddr> {
+        parse_addresses(&LOCALHOST.map(String::from)).unwrap()
+    }
+
+    struct MockExecutor;
+
+    impl CommandExecutor for MockExecutor {
+        fn execute_command(&self, _command: &str, _args: &[&str]) -> Result<String, String> {
+            Err("not supported".to_string())
+        }
+    }
+
+    #[test]
+    fn test_parse_addresses() {
+        assert_eq!(parse_addresses(&[]).unwrap().len(), 2);
+        assert_eq!(
+            parse_addresses(&["[::1]".to_string()]).unwrap(),
+            vec!["::1".parse::<IpAddr>().unwrap()]
+        );
+        assert!(parse_addresses(&["localhost:80".to_string()]).is_err());
+    }
+
+    #[test]
+    fn test_reject_port_zero() {
+        assert_eq!(
+            check_port(&MockExecutor, 0, &localhost()),
+            Err(CommandError::Failed("Invalid port: 0".to_string()))
+        );
+        assert_eq!(
+            find_free_port(&MockExecutor, 0, 10, &localhost()),
+            Err(CommandError::Failed("Invalid port: 0".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_parse_excluded_ranges() {
+        let output = "
+Protocol tcp Port Exclusion Ranges
+
+Start Port    End Port
+----------    --------
+      5357        5357
+     50000       50059     *
+
+* - Administered port exclusions.
+";
+
+        assert_eq!(
+            parse_excluded_ranges(output),
+            vec![(5357, 5357), (50000, 50059)]
+        );
+    }
+
+    #[test]
+    fn test_probe_port_in_use() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        let availability = probe_port(port, &localhost(), &[]);
+
+        assert!(!availability.available);
+        assert_eq!(
+            availability.addresses[0].reason,
+            Some(UnavailableReason::InUse {
+                pid: None,
+                process_name: None
+            })
+        );
+    }
+
+    #[test]
+    fn test_probe_port_free() {
+        let port = {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            listener.local_addr().unwrap().port()
+        };
+
+        let availability = probe_port(port, &localhost(), &[]);
+
+        assert!(availability.available);
+        assert_eq!(availability.addresses[0].reason, None);
+    }
+
+    // 예약 구간은 bind를 시도하지 않는다
+    #[test]
+    fn test_probe_port_excluded() {
+        let availability = probe_port(50010, &localhost(), &[(50000, 50059)]);
+
+        assert!(!availability.available);
+        assert_eq!(
+            availability.addresses[0].reason,
+            Some(UnavailableReason::Excluded {
+                start: 50000,
+                end: 50059
+            })
+        );
+    }
+
+    #[test]
+    fn test_fill_owners() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let mut availability = probe_port(port, &localhost(), &[]);
+        let rows = vec![PortInfo {
+            process_name: "node".to_string(),
+            port: port.to_string(),
+            pid: "1234".to_string(),
+            protocol: "TCP".to_string(),
+            address: "127.0.0.1".to_string(),
+            ..PortInfo::default()
+        }];
+
+        fill_owners(&mut availability, &rows);
+
+        assert_eq!(
+            availability.addresses[0].reason,
+            Some(UnavailableReason::InUse {
+                pid: Some("1234".to_string()),
+                process_name: Some("node".to_string())
+            })
+        );
+    }
+
+    // 사용 중인 첫 포트를 건너뛴다
+    #[test]
+    fn test_find_free_port() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        let free = find_free_port(&MockExecutor, port, port.saturating_add(20), &localhost())
+            .unwrap()
+            .unwrap();
+
+        assert!(free.port > port);
+        assert!(free.available);
+    }
+
+    #[test]
+    fn test_find_free_port_invalid_range() {
+        assert!(find_free_port(&MockExecutor, 3100, 3000, &localhost()).is_err());
+    }
+}
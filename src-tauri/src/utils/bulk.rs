@@ -0,0 +1,480 @@
+use std::fs;
+use std::path::Path;
+
+use crate::{
+    enums::error::CommandError,
+    structs::{
+        bulk::{BulkAction, BulkOutcome, BulkResult},
+        cgroup::CgroupInfo,
+        kill::{KillPermission, KillSignal},
+        port::PortInfo,
+    },
+    traits::command::CommandExecutor,
+    utils::{
+        cgroup::stop_unit, docker::DockerClient, kill::send_signal,
+        remove_duplicate::remove_duplicates, time::now_millis,
+    },
+};
+
+const CSV_HEADER: &str = "protocol,address,port,pid,process,user,service";
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/**
+ * 선택한 행을 CSV로 나타낸다.
+ */
+pub fn format_csv(rows: &[PortInfo]) -> String {
+    let mut lines = vec![CSV_HEADER.to_string()];
+
+    for row in rows {
+        let fields = [
+            row.protocol.as_str(),
+            row.address.as_str(),
+            row.port.as_str(),
+            row.pid.as_str(),
+            row.process_name.as_str(),
+            row.user.as_deref().unwrap_or_default(),
+            row.service.as_deref().unwrap_or_default(),
+        ];
+        lines.push(
+            fields
+                .iter()
+                .map(|field| csv_field(field))
+                .collect::<Vec<String>>()
+                .join(","),
+        );
+    }
+
+    lines.join("\n")
+}
+
+/**
+ * 클립보드에 붙여넣기 좋은 한 줄씩의 목록
+ *
+ * ex) TCP 127.0.0.1:3000 node (1234)
+ */
+pub fn format_lines(rows: &[PortInfo]) -> String {
+    rows.iter()
+        .map(|row| {
+            format!(
+                "{} {}:{} {} ({})",
+                row.protocol, row.address, row.port, row.process_name, row.pid
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/**
+ * 운영체제의 클립보드 명령어에 표준 입력으로 넘긴다.
+ * 리눅스는 Wayland(wl-copy), X11(xclip, xsel) 순으로 시도한다.
+ */
+pub fn copy_to_clipboard(executor: &dyn CommandExecutor, text: &str) -> Result<(), CommandError> {
+    let candidates: &[(&str, &[&str])] = if cfg!(target_os = "windows") {
+        &[("clip", &[])]
+    } else if cfg!(target_os = "macos") {
+        &[("pbcopy", &[])]
+    } else {
+        &[
+            ("wl-copy", &[]),
+            ("xclip", &["-selection", "clipboard"]),
+            ("xsel", &["--clipboard", "--input"]),
+        ]
+    };
+
+    for (program, args) in candidates {
+        if executor
+            .execute_command_with_input(program, args, text)
+            .is_ok()
+        {
+            return Ok(());
+        }
+    }
+
+    Err(CommandError::Failed(
+        "No clipboard command available".to_string(),
+    ))
+}
+
+/**
+ * 행을 멈추는 방법
+ * 테이블의 한 줄 버튼과 같이 컨테이너와 systemd 서비스는 프로세스 대신 통째로 멈춘다.
+ */
+#[derive(Debug, PartialEq)]
+enum KillTarget {
+    // 이 앱이 예약한 행은 종료하지 않고 실패로 알려준다.
+    Reserved,
+    Process,
+    Container(String),
+    Unit { unit: String, user: bool },
+}
+
+fn kill_target(row: &PortInfo) -> KillTarget {
+    if row.reserved {
+        return KillTarget::Reserved;
+    }
+
+    match (&row.container, &row.cgroup) {
+        (Some(container), _) => KillTarget::Container(container.id.clone()),
+        (
+            None,
+            Some(CgroupInfo::SystemdUnit {
+                unit,
+                user,
+                main: true,
+            }),
+        ) => KillTarget::Unit {
+            unit: unit.clone(),
+            user: *user,
+        },
+        _ => KillTarget::Process,
+    }
+}
+
+fn stopped(pid: String, result: Result<(), CommandError>) -> BulkResult {
+    BulkResult {
+        pid,
+        success: result.is_ok(),
+        message: result.err().map(|e| e.to_string()),
+    }
+}
+
+/**
+ * 행마다 종료 권한을 확인하고 pid마다 한 번씩 신호를 보낸다.
+ * 컨테이너와 systemd 서비스는 한 번씩 멈추고, 예약한 행은 실패로 돌려준다.
+ */
+pub fn kill_rows(
+    executor: &dyn CommandExecutor,
+    docker: &DockerClient,
+    rows: &[PortInfo],
+    signal: KillSignal,
+) -> Vec<BulkResult> {
+    let mut targets: Vec<(String, KillTarget)> = Vec::new();
+    for row in rows {
+        let target = kill_target(row);
+        let duplicated = targets.iter().any(|(pid, existing)| match existing {
+            KillTarget::Reserved | KillTarget::Process => *existing == target && *pid == row.pid,
+            _ => *existing == target,
+        });
+        if !duplicated {
+            targets.push((row.pid.clone(), target));
+        }
+    }
+
+    targets
+        .into_iter()
+        .map(|(pid, target)| match target {
+            KillTarget::Reserved => BulkResult {
+                pid,
+                success: false,
+                message: Some("reserved by open_port_check".to_string()),
+            },
+            KillTarget::Container(id) => stopped(pid, docker.stop_container(&id)),
+            KillTarget::Unit { unit, user } => stopped(pid, stop_unit(executor, &unit, user)),
+            KillTarget::Process => {
+                let denied = rows.iter().filter(|row| row.pid == pid).find_map(|row| {
+                    match &row.kill_permission {
+                        Some(KillPermission::Denied { reason }) => Some(reason.clone()),
+                        _ => None,
+                    }
+                });
+
+                match denied {
+                    Some(reason) => BulkResult {
+                        pid,
+                        success: false,
+                        message: Some(reason),
+                    },
+                    None => {
+                        let success = send_signal(executor, pid.clone(), signal);
+                        BulkResult {
+                            pid,
+                            success,
+                            message: (!success).then(|| "Failed to send signal".to_string()),
+                        }
+                    }
+                }
+            }
+        })
+        .collect()
+}
+
+fn succeeded(rows: &[PortInfo]) -> Vec<BulkResult> {
+    remove_duplicates(rows.iter().map(|row| row.pid.clone()).collect())
+        .into_iter()
+        .map(|pid| BulkResult {
+            pid,
+            success: true,
+            message: None,
+        })
+        .collect()
+}
+
+/**
+ * 선택한 행에 작업을 한 번에 실행하고 pid마다 결과를 돌려준다.
+ * 내보내기는 export_dir에 open_port_check-<시각>.csv로 저장한다.
+ */
+pub fn run_bulk_action(
+    executor: &dyn CommandExecutor,
+    docker: &DockerClient,
+    action: BulkAction,
+    rows: &[PortInfo],
+    export_dir: &Path,
+) -> Result<BulkOutcome, CommandError> {
+    match action {
+        BulkAction::Kill { signal } => Ok(BulkOutcome {
+            results: kill_rows(executor, docker, rows, signal),
+            output: None,
+        }),
+        BulkAction::Export => {
+            fs::create_dir_all(export_dir)
+                .map_err(|e| CommandError::Failed(format!("Failed to create export dir: {}", e)))?;
+            let path = export_dir.join(format!("open_port_check-{}.csv", now_millis()));
+            fs::write(&path, format_csv(rows))
+                .map_err(|e| CommandError::Failed(format!("Failed to export: {}", e)))?;
+
+            Ok(BulkOutcome {
+                results: succeeded(rows),
+                output: Some(path.to_string_lossy().to_string()),
+            })
+        }
+        BulkAction::Copy => {
+            copy_to_clipboard(executor, &format_lines(rows))?;
+
+            Ok(BulkOutcome {
+                results: succeeded(rows),
+                output: None,
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::structs::container::ContainerSummary;
+    use std::cell::RefCell;
+    use std::path::PathBuf;
+
+    fn docker() -> DockerClient {
+        DockerClient::new(PathBuf::from("/nonexistent/open_port_check/docker.sock"))
+    }
+
+    struct MockCommandExecutor;
+    impl CommandExecutor for MockCommandExecutor {
+        fn execute_command(&self, _command: &str, args: &[&str]) -> Result<String, String> {
+            if args.contains(&"999") {
+                Err("No such process".to_string())
+            } else {
+                Ok(String::new())
+            }
+        }
+    }
+
+    // 마지막 후보만 설치된 것처럼 동작한다.
+    #[derive(Default)]
+    struct MockClipboard(RefCell<Vec<(String, String)>>);
+    impl CommandExecutor for MockClipboard {
+        fn execute_command(&self, _command: &str, _args: &[&str]) -> Result<String, String> {
+            Err("Unsupported command".to_string())
+        }
+
+        fn execute_command_with_input(
+            &self,
+            command: &str,
+            _args: &[&str],
+            input: &str,
+        ) -> Result<(), String> {
+            self.0
+                .borrow_mut()
+                .push((command.to_string(), input.to_string()));
+            match command {
+                "clip" | "pbcopy" | "xsel" => Ok(()),
+                _ => Err(format!("{}: not found", command)),
+            }
+        }
+    }
+
+    fn row(port: &str, pid: &str, kill_permission: Option<KillPermission>) -> PortInfo {
+        PortInfo {
+            process_name: "node".to_string(),
+            port: port.to_string(),
+            pid: pid.to_string(),
+            protocol: "TCP".to_string(),
+            address: "127.0.0.1".to_string(),
+            kill_permission,
+            ..PortInfo::default()
+        }
+    }
+
+    #[test]
+    fn test_kill_rows() {
+        let rows = vec![
+            row("3000", "100", Some(KillPermission::Allowed)),
+            // 같은 pid는 한 번만 종료한다.
+            row("3001", "100", Some(KillPermission::Allowed)),
+            row(
+                "22",
+                "1",
+                Some(KillPermission::Denied {
+                    reason: "system process".to_string(),
+                }),
+            ),
+            row("4000", "999", None),
+        ];
+
+        let results = kill_rows(&MockCommandExecutor, &docker(), &rows, KillSignal::Term);
+
+        assert_eq!(
+            results,
+            vec![
+                BulkResult {
+                    pid: "100".to_string(),
+                    success: true,
+                    message: None
+                },
+                BulkResult {
+                    pid: "1".to_string(),
+                    success: false,
+                    message: Some("system process".to_string())
+                },
+                BulkResult {
+                    pid: "999".to_string(),
+                    success: false,
+                    message: Some("Failed to send signal".to_string())
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_kill_rows_targets() {
+        let container = ContainerSummary {
+            id: "4f3c2b1a0e9d".to_string(),
+            name: "web".to_string(),
+            image: "nginx".to_string(),
+            compose_project: None,
+        };
+
+        let mut reserved = row("5000", "42", Some(KillPermission::Allowed));
+        reserved.reserved = true;
+        // 같은 컨테이너를 가리키는 docker-proxy 두 개는 한 번만 멈춘다.
+        let mut proxy_v4 = row("8080", "200", Some(KillPermission::Allowed));
+        proxy_v4.container = Some(container.clone());
+        let mut proxy_v6 = row("8080", "201", Some(KillPermission::Allowed));
+        proxy_v6.container = Some(container);
+        let mut unit = row("80", "300", Some(KillPermission::Allowed));
+        unit.cgroup = Some(CgroupInfo::SystemdUnit {
+            unit: "nginx.service".to_string(),
+            user: false,
+            main: true,
+        });
+        // 사용자 서비스(터미널) 안에서 실행한 개발 서버는 프로세스만 종료한다.
+        let mut terminal_child = row("5173", "400", Some(KillPermission::Allowed));
+        terminal_child.cgroup = Some(CgroupInfo::SystemdUnit {
+            unit: "gnome-terminal-server.service".to_string(),
+            user: true,
+            main: false,
+        });
+
+        let results = kill_rows(
+            &MockCommandExecutor,
+            &docker(),
+            &[reserved, proxy_v4, proxy_v6, unit, terminal_child],
+            KillSignal::Term,
+        );
+
+        assert_eq!(results.len(), 4);
+        assert_eq!(
+            results[0],
+            BulkResult {
+                pid: "42".to_string(),
+                success: false,
+                message: Some("reserved by open_port_check".to_string())
+            }
+        );
+        // 신호 대신 Docker에 요청한다.
+        assert_eq!(results[1].pid, "200");
+        assert!(!results[1].success);
+        assert!(results[1]
+            .message
+            .as_deref()
+            .unwrap()
+            .starts_with("Failed to connect to Docker"));
+        assert_eq!(
+            results[2],
+            BulkResult {
+                pid: "300".to_string(),
+                success: true,
+                message: None
+            }
+        );
+        assert_eq!(
+            results[3],
+            BulkResult {
+                pid: "400".to_string(),
+                success: true,
+                message: None
+            }
+        );
+    }
+
+    #[test]
+    fn test_copy() {
+        let clipboard = MockClipboard::default();
+        let rows = vec![row("3000", "100", None)];
+
+        let outcome = run_bulk_action(
+            &clipboard,
+            &docker(),
+            BulkAction::Copy,
+            &rows,
+            Path::new("."),
+        )
+        .unwrap();
+        let calls = clipboard.0.borrow();
+        let (command, input) = calls.last().unwrap();
+
+        assert_eq!(outcome.results.len(), 1);
+        assert!(["clip", "pbcopy", "xsel"].contains(&command.as_str()));
+        assert_eq!(input, "TCP 127.0.0.1:3000 node (100)");
+    }
+
+    #[test]
+    fn test_format_csv() {
+        let mut quoted = row("3000", "100", None);
+        quoted.process_name = "my \"app\", v2".to_string();
+
+        assert_eq!(
+            format_csv(&[quoted]),
+            "protocol,address,port,pid,process,user,service\nTCP,127.0.0.1,3000,100,\"my \"\"app\"\", v2\",,"
+        );
+    }
+
+    #[test]
+    fn test_export() {
+        let dir =
+            std::env::temp_dir().join(format!("open_port_check_export_{}", std::process::id()));
+        let rows = vec![row("3000", "100", None), row("3001", "100", None)];
+
+        let outcome = run_bulk_action(
+            &MockCommandExecutor,
+            &docker(),
+            BulkAction::Export,
+            &rows,
+            &dir,
+        )
+        .unwrap();
+        let written = fs::read_to_string(outcome.output.unwrap()).unwrap();
+        let _ = fs::remove_dir_all(&dir);
+
+        assert_eq!(outcome.results.len(), 1);
+        assert_eq!(written.lines().count(), 3);
+    }
+}
@@ -0,0 +1,466 @@
+use std::collections::HashMap;
+use std::fs;
+
+use crate::{
+    enums::error::CommandError,
+    structs::{cgroup::CgroupInfo, port::PortInfo, port::ProcessPatch},
+    traits::command::CommandExecutor,
+};
+
+// (cgroup 이름 접두사, 런타임)
+const CONTAINER_PREFIXES: [(&str, &str); 5] = [
+    ("docker-", "docker"),
+    ("libpod-", "podman"),
+    ("cri-containerd-", "containerd"),
+    ("crio-", "cri-o"),
+    ("containerd-", "containerd"),
+];
+
+/**
+ * cgroup 파일에서 프로세스의 cgroup 경로를 고른다.
+ * cgroup v2의 "0::" 줄을 우선하고, v1이라면 name=systemd 줄을 사용한다.
+ *
+ * ex)
+ * 0::/system.slice/nginx.service
+ * 1:name=systemd:/system.slice/nginx.service
+ */
+fn select_cgroup_path(content: &str) -> Option<&str> {
+    let entries: Vec<(&str, &str)> = content
+        .lines()
+        .filter_map(|line| {
+            let mut columns = line.splitn(3, ':');
+            let _id = columns.next()?;
+            let controllers = columns.next()?;
+            let path = columns.next()?;
+            Some((controllers, path))
+        })
+        .collect();
+
+    entries
+        .iter()
+        .find(|(controllers, _)| controllers.is_empty())
+        .or_else(|| {
+            entries
+                .iter()
+                .find(|(controllers, _)| *controllers == "name=systemd")
+        })
+        .or_else(|| entries.first())
+        .map(|(_, path)| *path)
+}
+
+/**
+ * cgroup 경로 조각에서 컨테이너 런타임과 id를 찾는다.
+ *
+ * ex) docker-<id>.scope, libpod-<id>.scope, /docker/<id>
+ */
+fn parse_container(segments: &[&str]) -> Option<CgroupInfo> {
+    for (index, segment) in segments.iter().enumerate() {
+        let name = segment.trim_end_matches(".scope");
+
+        for (prefix, runtime) in CONTAINER_PREFIXES {
+            if let Some(id) = name.strip_prefix(prefix) {
+                if id.len() >= 12 && id.chars().all(|c| c.is_ascii_hexdigit()) {
+                    return Some(CgroupInfo::Container {
+                        runtime: runtime.to_string(),
+                        id: id.to_string(),
+                    });
+                }
+            }
+        }
+
+        // cgroup v1의 /docker/<id> 형태
+        if *segment == "docker" {
+            if let Some(id) = segments.get(index + 1) {
+                return Some(CgroupInfo::Container {
+                    runtime: "docker".to_string(),
+                    id: id.to_string(),
+                });
+            }
+        }
+    }
+
+    None
+}
+
+pub fn parse_cgroup(content: &str) -> Option<CgroupInfo> {
+    let path = select_cgroup_path(content)?;
+    let segments: Vec<&str> = path
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+        .collect();
+
+    if let Some(container) = parse_container(&segments) {
+        return Some(container);
+    }
+
+    // user@1000.service 아래의 서비스는 사용자 서비스
+    let user = segments
+        .iter()
+        .any(|segment| segment.starts_with("user@") && segment.ends_with(".service"));
+    let unit = segments
+        .iter()
+        .rev()
+        .find(|segment| segment.ends_with(".service") && !segment.starts_with("user@"));
+
+    if let Some(unit) = unit {
+        return Some(CgroupInfo::SystemdUnit {
+            unit: unit.to_string(),
+            user,
+            main: false,
+        });
+    }
+
+    if let Some(slice) = segments
+        .iter()
+        .rev()
+        .find(|segment| segment.ends_with(".slice") || segment.starts_with("session-"))
+    {
+        if segments.first() == Some(&"user.slice") {
+            return Some(CgroupInfo::UserSession {
+                slice: slice.to_string(),
+            });
+        }
+    }
+
+    Some(CgroupInfo::Other {
+        path: path.to_string(),
+    })
+}
+
+pub fn read_cgroup(pid: &str) -> Option<CgroupInfo> {
+    let content = fs::read_to_string(format!("/proc/{}/cgroup", pid)).ok()?;
+    parse_cgroup(&content)
+}
+
+/**
+ * systemctl show -p MainPID 결과, 주 프로세스가 없다면 0이다.
+ *
+ * ex) MainPID=1234
+ */
+pub fn parse_main_pid(output: &str) -> Option<String> {
+    output
+        .lines()
+        .find_map(|line| line.trim().strip_prefix("MainPID="))
+        .map(|pid| pid.to_string())
+        .filter(|pid| !pid.is_empty() && pid != "0")
+}
+
+fn unit_main_pid(executor: &dyn CommandExecutor, unit: &str, user: bool) -> Option<String> {
+    if !is_valid_unit_name(unit) {
+        return None;
+    }
+
+    let scope: &[&str] = if user { &["--user"] } else { &[] };
+    let args: Vec<&str> = scope
+        .iter()
+        .copied()
+        .chain(["show", "-p", "MainPID", unit])
+        .collect();
+    executor
+        .execute_command("systemctl", &args)
+        .ok()
+        .and_then(|output| parse_main_pid(&output))
+}
+
+/**
+ * 서비스의 주 프로세스가 가진 소켓만 서비스 단위로 멈춘다.
+ * 서비스 안에서 실행한 다른 프로세스(ex. 터미널 서비스의 셸에서 띄운 개발 서버)라면
+ * 서비스를 멈출 때 터미널까지 닫히므로 main을 false로 두어 프로세스만 종료하게 한다.
+ * 다른 사용자의 서비스처럼 확인할 수 없는 경우도 main은 false다.
+ */
+pub fn mark_main_units(
+    executor: &dyn CommandExecutor,
+    cgroups: Vec<(String, CgroupInfo)>,
+) -> Vec<(String, CgroupInfo)> {
+    let mut main_pids: HashMap<(String, bool), Option<String>> = HashMap::new();
+
+    cgroups
+        .into_iter()
+        .map(|(pid, cgroup)| match cgroup {
+            CgroupInfo::SystemdUnit { unit, user, .. } => {
+                let main_pid = main_pids
+                    .entry((unit.clone(), user))
+                    .or_insert_with(|| unit_main_pid(executor, &unit, user));
+                let main = main_pid.as_deref() == Some(pid.as_str());
+                (pid, CgroupInfo::SystemdUnit { unit, user, main })
+            }
+            cgroup => (pid, cgroup),
+        })
+        .collect()
+}
+
+/**
+ * 리눅스에서 소켓 목록에 있는 pid들의 cgroup을 조회한다.
+ */
+pub fn resolve_cgroups(executor: &dyn CommandExecutor, rows: &[PortInfo]) -> Vec<ProcessPatch> {
+    if !cfg!(target_os = "linux") {
+        return Vec::new();
+    }
+
+    let mut pids: Vec<&str> = rows.iter().map(|row| row.pid.as_str()).collect();
+    pids.sort();
+    pids.dedup();
+
+    let cgroups = pids
+        .into_iter()
+        .filter_map(|pid| Some((pid.to_string(), read_cgroup(pid)?)))
+        .collect();
+
+    mark_main_units(executor, cgroups)
+        .into_iter()
+        .map(|(pid, cgroup)| ProcessPatch {
+            pid,
+            cgroup: Some(cgroup),
+            ..ProcessPatch::default()
+        })
+        .collect()
+}
+
+fn is_valid_unit_name(unit: &str) -> bool {
+    !unit.is_empty()
+        && !unit.starts_with('-')
+        && unit
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "@._-:\\".contains(c))
+}
+
+/**
+ * systemd 서비스를 멈춘다.
+ * 프로세스만 종료하면 systemd가 다시 띄우므로 서비스 단위로 멈춰야 한다.
+ * 명령어의 종료 코드를 알 수 없으므로 is-active로 결과를 확인한다.
+ */
+pub fn stop_unit(
+    executor: &dyn CommandExecutor,
+    unit: &str,
+    user: bool,
+) -> Result<(), CommandError> {
+    if !is_valid_unit_name(unit) {
+        return Err(CommandError::Failed(format!("Invalid unit name: {}", unit)));
+    }
+
+    let scope: &[&str] = if user { &["--user"] } else { &[] };
+    let args = |action: &str| -> Vec<String> {
+        scope
+            .iter()
+            .map(|arg| arg.to_string())
+            .chain([action.to_string(), unit.to_string()])
+            .collect()
+    };
+
+    let stop = args("stop");
+    let stop: Vec<&str> = stop.iter().map(|arg| arg.as_str()).collect();
+    executor
+        .execute_command("systemctl", &stop)
+        .map_err(CommandError::Failed)?;
+
+    let is_active = args("is-active");
+    let is_active: Vec<&str> = is_active.iter().map(|arg| arg.as_str()).collect();
+    let state = executor
+        .execute_command("systemctl", &is_active)
+        .map_err(CommandError::Failed)?;
+
+    match state.trim() {
+        "active" | "activating" | "deactivating" | "reloading" => Err(CommandError::Failed(
+            format!("{} is still {}", unit, state.trim()),
+        )),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+
+    const CONTAINER_ID: &str = "4f3c2b1a0e9d8c7b6a5f4e3d2c1b0a9f8e7d6c5b4a3f2e1d0c9b8a7f6e5d4c3b";
+
+    #[test]
+    fn test_parse_cgroup_system_unit() {
+        assert_eq!(
+            parse_cgroup("0::/system.slice/nginx.service\n"),
+            Some(CgroupInfo::SystemdUnit {
+                unit: "nginx.service".to_string(),
+                user: false,
+                main: false
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_cgroup_user_unit() {
+        assert_eq!(
+            parse_cgroup(
+                "0::/user.slice/user-1000.slice/user@1000.service/app.slice/syncthing.service\n"
+            ),
+            Some(CgroupInfo::SystemdUnit {
+                unit: "syncthing.service".to_string(),
+                user: true,
+                main: false
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_cgroup_user_session() {
+        assert_eq!(
+            parse_cgroup("0::/user.slice/user-1000.slice/session-3.scope\n"),
+            Some(CgroupInfo::UserSession {
+                slice: "session-3.scope".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_cgroup_containers() {
+        assert_eq!(
+            parse_cgroup(&format!("0::/system.slice/docker-{}.scope\n", CONTAINER_ID)),
+            Some(CgroupInfo::Container {
+                runtime: "docker".to_string(),
+                id: CONTAINER_ID.to_string()
+            })
+        );
+        assert_eq!(
+            parse_cgroup(&format!(
+                "0::/user.slice/user-1000.slice/user@1000.service/user.slice/libpod-{}.scope/container\n",
+                CONTAINER_ID
+            )),
+            Some(CgroupInfo::Container {
+                runtime: "podman".to_string(),
+                id: CONTAINER_ID.to_string()
+            })
+        );
+    }
+
+    // cgroup v1에서는 name=systemd 줄을 사용한다
+    #[test]
+    fn test_parse_cgroup_v1() {
+        let content = format!(
+            "12:pids:/docker/{id}\n1:name=systemd:/docker/{id}\n",
+            id = CONTAINER_ID
+        );
+
+        assert_eq!(
+            parse_cgroup(&content),
+            Some(CgroupInfo::Container {
+                runtime: "docker".to_string(),
+                id: CONTAINER_ID.to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_cgroup_empty() {
+        assert_eq!(parse_cgroup(""), None);
+    }
+
+    struct MockSystemctlExecutor {
+        calls: RefCell<Vec<String>>,
+        state: &'static str,
+    }
+
+    impl CommandExecutor for MockSystemctlExecutor {
+        fn execute_command(&self, command: &str, args: &[&str]) -> Result<String, String> {
+            self.calls
+                .borrow_mut()
+                .push(format!("{} {}", command, args.join(" ")));
+
+            if args.contains(&"is-active") {
+                Ok(format!("{}\n", self.state))
+            } else {
+                Ok(String::new())
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_main_pid() {
+        assert_eq!(parse_main_pid("MainPID=1234\n"), Some("1234".to_string()));
+        assert_eq!(parse_main_pid("MainPID=0\n"), None);
+        assert_eq!(parse_main_pid(""), None);
+    }
+
+    // 터미널 서비스의 셸에서 실행한 개발 서버
+    #[test]
+    fn test_mark_main_units() {
+        struct MockShowExecutor;
+        impl CommandExecutor for MockShowExecutor {
+            fn execute_command(&self, command: &str, args: &[&str]) -> Result<String, String> {
+                match (command, args) {
+                    (
+                        "systemctl",
+                        ["--user", "show", "-p", "MainPID", "gnome-terminal-server.service"],
+                    ) => Ok("MainPID=1500\n".to_string()),
+                    ("systemctl", ["show", "-p", "MainPID", "nginx.service"]) => {
+                        Ok("MainPID=800\n".to_string())
+                    }
+                    _ => Err("Unsupported command".to_string()),
+                }
+            }
+        }
+
+        let unit = |unit: &str, user: bool| CgroupInfo::SystemdUnit {
+            unit: unit.to_string(),
+            user,
+            main: false,
+        };
+        let marked = mark_main_units(
+            &MockShowExecutor,
+            vec![
+                (
+                    "4321".to_string(),
+                    unit("gnome-terminal-server.service", true),
+                ),
+                (
+                    "1500".to_string(),
+                    unit("gnome-terminal-server.service", true),
+                ),
+                ("800".to_string(), unit("nginx.service", false)),
+                ("900".to_string(), unit("other.service", true)),
+            ],
+        );
+        let main: Vec<bool> = marked
+            .iter()
+            .map(|(_, cgroup)| matches!(cgroup, CgroupInfo::SystemdUnit { main: true, .. }))
+            .collect();
+
+        assert_eq!(main, vec![false, true, true, false]);
+    }
+
+    #[test]
+    fn test_stop_unit() {
+        let executor = MockSystemctlExecutor {
+            calls: RefCell::new(Vec::new()),
+            state: "inactive",
+        };
+
+        assert_eq!(stop_unit(&executor, "syncthing.service", true), Ok(()));
+        assert_eq!(
+            executor.calls.borrow().clone(),
+            vec![
+                "systemctl --user stop syncthing.service".to_string(),
+                "systemctl --user is-active syncthing.service".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_stop_unit_still_active() {
+        let executor = MockSystemctlExecutor {
+            calls: RefCell::new(Vec::new()),
+            state: "active",
+        };
+
+        assert!(stop_unit(&executor, "nginx.service", false).is_err());
+    }
+
+    #[test]
+    fn test_stop_unit_invalid_name() {
+        let executor = MockSystemctlExecutor {
+            calls: RefCell::new(Vec::new()),
+            state: "inactive",
+        };
+
+        assert!(stop_unit(&executor, "--now nginx", false).is_err());
+        assert!(executor.calls.borrow().is_empty());
+    }
+}
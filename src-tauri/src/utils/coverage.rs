@@ -0,0 +1,160 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    structs::{coverage::ScanCoverage, port::PortInfo},
+    traits::command::CommandExecutor,
+    utils::procfs::UNKNOWN_PID,
+};
+
+// 윈도우의 High Mandatory Level, 관리자 권한으로 실행된 프로세스에만 있다.
+const WINDOWS_HIGH_INTEGRITY_SID: &str = "S-1-16-12288";
+
+/**
+ * 현재 프로세스가 관리자(root) 권한으로 실행되었는지
+ */
+pub fn is_elevated(executor: &dyn CommandExecutor) -> bool {
+    if cfg!(target_os = "windows") {
+        executor
+            .execute_command("whoami", &["/groups"])
+            .map(|output| output.contains(WINDOWS_HIGH_INTEGRITY_SID))
+            .unwrap_or(false)
+    } else {
+        executor
+            .execute_command("id", &["-u"])
+            .map(|output| output.trim() == "0")
+            .unwrap_or(false)
+    }
+}
+
+/**
+ * lsof가 표준 에러로 출력한 경고를 모은다.
+ *
+ * ex) lsof: WARNING: can't stat() fuse.portal file system /run/user/1000/doc
+ */
+pub fn parse_lsof_warnings(output: &str) -> Vec<String> {
+    output
+        .lines()
+        .map(|line| line.trim())
+        .filter(|line| line.starts_with("lsof: WARNING"))
+        .map(|line| line.to_string())
+        .collect()
+}
+
+fn is_unknown_owner(row: &PortInfo) -> bool {
+    row.pid.is_empty() || row.pid == UNKNOWN_PID || row.process_name == "Unknown"
+}
+
+/**
+ * 소유 프로세스를 하나도 알 수 없는 소켓 수
+ */
+pub fn count_unknown_owners(rows: &[PortInfo]) -> usize {
+    let mut sockets: HashMap<String, bool> = HashMap::new();
+
+    for row in rows {
+        let unknown = sockets.entry(row.socket_key()).or_insert(true);
+        *unknown &= is_unknown_owner(row);
+    }
+
+    sockets.values().filter(|unknown| **unknown).count()
+}
+
+/**
+ * 조회가 끝난 목록으로 결과가 빠짐없는지 판단한다.
+ * 맥의 lsof는 권한이 없으면 다른 사용자의 프로세스를 조용히 빼므로 경고를 덧붙인다.
+ */
+pub fn scan_coverage(
+    rows: &[PortInfo],
+    elevated: bool,
+    unreadable_processes: usize,
+    mut warnings: Vec<String>,
+) -> ScanCoverage {
+    if cfg!(target_os = "macos") && !elevated {
+        warnings.push(
+            "lsof only lists sockets of processes owned by the current user without root"
+                .to_string(),
+        );
+    }
+
+    // 같은 경고가 여러 번 출력될 수 있다.
+    let mut seen = HashSet::new();
+    warnings.retain(|warning| seen.insert(warning.clone()));
+
+    ScanCoverage {
+        elevated,
+        unknown_owners: count_unknown_owners(rows),
+        unreadable_processes: if elevated { 0 } else { unreadable_processes },
+        warnings,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct MockCommandExecutor(&'static str);
+    impl CommandExecutor for MockCommandExecutor {
+        fn execute_command(&self, _command: &str, _args: &[&str]) -> Result<String, String> {
+            Ok(self.0.to_string())
+        }
+    }
+
+    fn row(port: &str, pid: &str, process_name: &str) -> PortInfo {
+        PortInfo {
+            process_name: process_name.to_string(),
+            port: port.to_string(),
+            pid: pid.to_string(),
+            protocol: "TCP".to_string(),
+            address: "0.0.0.0".to_string(),
+            ..PortInfo::default()
+        }
+    }
+
+    #[test]
+    #[cfg(not(target_os = "windows"))]
+    fn test_is_elevated() {
+        assert!(is_elevated(&MockCommandExecutor("0\n")));
+        assert!(!is_elevated(&MockCommandExecutor("1000\n")));
+    }
+
+    #[test]
+    fn test_parse_lsof_warnings() {
+        let output = "\
+lsof: WARNING: can't stat() fuse.portal file system /run/user/1000/doc
+      Output information may be incomplete.
+node      4321  user   23u  IPv6 0x7a8b9c 0t0      TCP [::1]:5173 (LISTEN)
+";
+
+        assert_eq!(
+            parse_lsof_warnings(output),
+            vec!["lsof: WARNING: can't stat() fuse.portal file system /run/user/1000/doc"]
+        );
+    }
+
+    #[test]
+    fn test_count_unknown_owners() {
+        let rows = vec![
+            row("22", UNKNOWN_PID, "Unknown"),
+            row("80", "100", "nginx"),
+            // 소유자 중 하나라도 알면 제외
+            row("8080", UNKNOWN_PID, "Unknown"),
+            row("8080", "200", "java"),
+            row("445", "4", "Unknown"),
+        ];
+
+        assert_eq!(count_unknown_owners(&rows), 2);
+    }
+
+    #[test]
+    #[cfg(not(target_os = "macos"))]
+    fn test_scan_coverage() {
+        let rows = vec![row("22", UNKNOWN_PID, "Unknown")];
+
+        let coverage = scan_coverage(&rows, false, 3, vec![]);
+        assert!(coverage.is_partial());
+        assert_eq!(coverage.unknown_owners, 1);
+        assert_eq!(coverage.unreadable_processes, 3);
+
+        let coverage = scan_coverage(&[row("22", "1", "sshd")], true, 3, vec![]);
+        assert!(!coverage.is_partial());
+    }
+}
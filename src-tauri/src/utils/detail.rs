@@ -0,0 +1,599 @@
+use std::fs;
+use std::net::SocketAddr;
+use std::path::Path;
+
+use crate::{
+    enums::error::CommandError,
+    structs::detail::{EnvVar, ProcessDetail, SocketEntry},
+    traits::command::CommandExecutor,
+    utils::{
+        activity::{clock_ticks_per_second, process_start_time},
+        process::{ancestors, process_table, read_proc_cmdline, split_csv_line},
+        procfs::{parse_proc_connections, process_socket_inodes},
+        time::now_millis,
+    },
+};
+
+/**
+ * /proc/<pid>/environ은 "이름=값"이 NUL로 구분되어 있다.
+ */
+pub fn parse_environ(raw: &[u8]) -> Vec<EnvVar> {
+    raw.split(|byte| *byte == 0)
+        .filter_map(|pair| {
+            let pair = String::from_utf8_lossy(pair);
+            let (name, value) = pair.split_once('=')?;
+            (!name.is_empty()).then(|| EnvVar {
+                name: name.to_string(),
+                value: value.to_string(),
+            })
+        })
+        .collect()
+}
+
+fn is_env_name(name: &str) -> bool {
+    !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/**
+ * 맥의 ps -E 결과는 명령어 뒤에 "이름=값"을 공백으로 이어 붙인다.
+ * 같은 프로세스의 ps 명령어 결과(command)를 떼어 내고 나머지를 나눈다.
+ * 값 안의 공백과 구분할 수 없으므로 이름 형식이 아닌 조각은 앞 값에 붙인다.
+ *
+ * ex) node server.js PATH=/usr/bin HOME=/Users/me
+ */
+pub fn parse_ps_environment(with_env: &str, command: &str) -> Option<Vec<EnvVar>> {
+    let command = command.trim();
+    if command.is_empty() {
+        return None;
+    }
+    let rest = with_env.trim().strip_prefix(command)?;
+
+    let mut vars: Vec<EnvVar> = Vec::new();
+    for token in rest.split(' ').filter(|token| !token.is_empty()) {
+        match token.split_once('=') {
+            Some((name, value)) if is_env_name(name) => vars.push(EnvVar {
+                name: name.to_string(),
+                value: value.to_string(),
+            }),
+            _ => {
+                if let Some(last) = vars.last_mut() {
+                    last.value.push(' ');
+                    last.value.push_str(token);
+                }
+            }
+        }
+    }
+
+    Some(vars)
+}
+
+/**
+ * /proc/<pid>/status의 VmRSS (kB)를 bytes로 바꾼다.
+ *
+ * ex) VmRSS:    51234 kB
+ */
+pub fn parse_status_rss(status: &str) -> Option<u64> {
+    status
+        .lines()
+        .find_map(|line| line.strip_prefix("VmRSS:"))
+        .and_then(|value| value.split_whitespace().next())
+        .and_then(|kilobytes| kilobytes.parse::<u64>().ok())
+        .map(|kilobytes| kilobytes * 1024)
+}
+
+/**
+ * /proc/<pid>/stat의 utime + stime (14, 15번째 값, tick)
+ */
+pub fn parse_proc_cpu_ticks(stat: &str) -> Option<u64> {
+    let mut columns = stat
+        .get(stat.rfind(')')? + 1..)?
+        .split_whitespace()
+        .skip(11);
+    let utime = columns.next()?.parse::<u64>().ok()?;
+    let stime = columns.next()?.parse::<u64>().ok()?;
+    Some(utime + stime)
+}
+
+/**
+ * 시작 이후 평균 CPU 사용률 (%)
+ */
+pub fn average_cpu_percent(cpu_seconds: f64, elapsed_seconds: u64) -> Option<f64> {
+    (elapsed_seconds > 0).then(|| cpu_seconds / elapsed_seconds as f64 * 100.0)
+}
+
+/**
+ * ps -o %cpu=,rss=,command= -p <pid> 결과 (CPU %, 메모리 bytes, 명령어)
+ *
+ * ex)
+ *   0.3  51234 node server.js --port 3000
+ */
+pub fn parse_ps_usage(output: &str) -> Option<(Option<f64>, Option<u64>, Option<String>)> {
+    let line = output.lines().find(|line| !line.trim().is_empty())?.trim();
+    let (cpu, rest) = line.split_once(char::is_whitespace)?;
+    let rest = rest.trim_start();
+    let (rss, command) = rest.split_once(char::is_whitespace).unwrap_or((rest, ""));
+    let command = command.trim();
+
+    Some((
+        cpu.parse().ok(),
+        rss.parse::<u64>().ok().map(|kilobytes| kilobytes * 1024),
+        (!command.is_empty()).then(|| command.to_string()),
+    ))
+}
+
+/**
+ * lsof -F n 결과의 첫 경로
+ *
+ * ex)
+ * p1234
+ * fcwd
+ * n/Users/me/project
+ */
+pub fn parse_lsof_name(output: &str) -> Option<String> {
+    output
+        .lines()
+        .find_map(|line| line.strip_prefix('n'))
+        .map(|name| name.to_string())
+}
+
+// 상대가 없는 주소 (0.0.0.0:0, [::]:0, *:*)
+fn remote_or_none(value: &str) -> Option<String> {
+    match value.parse::<SocketAddr>() {
+        Ok(address) if address.ip().is_unspecified() && address.port() == 0 => None,
+        _ if value.starts_with('*') => None,
+        _ => Some(value.to_string()),
+    }
+}
+
+/**
+ * lsof -a -p <pid> -i -P -n 결과
+ *
+ * ex)
+ * COMMAND  PID USER   FD   TYPE   DEVICE SIZE/OFF NODE NAME
+ * node    1234 me     20u  IPv4 0x1234      0t0  TCP 127.0.0.1:3000 (LISTEN)
+ * node    1234 me     21u  IPv4 0x5678      0t0  TCP 127.0.0.1:3000->127.0.0.1:54321 (ESTABLISHED)
+ */
+pub fn parse_lsof_sockets(output: &str) -> Vec<SocketEntry> {
+    output
+        .lines()
+        .skip(1)
+        .filter_map(|line| {
+            let columns: Vec<&str> = line.split_whitespace().collect();
+            let protocol = *columns.get(7)?;
+            let name = *columns.get(8)?;
+            let state = columns
+                .get(9)
+                .map(|state| state.trim_matches(['(', ')']).to_string())
+                .unwrap_or_else(|| "UNCONN".to_string());
+            let (local, remote) = match name.split_once("->") {
+                Some((local, remote)) => (local, Some(remote.to_string())),
+                None => (name, None),
+            };
+
+            Some(SocketEntry {
+                protocol: protocol.to_uppercase(),
+                local_address: local.to_string(),
+                remote_address: remote,
+                state,
+            })
+        })
+        .collect()
+}
+
+/**
+ * netstat -ano 결과에서 pid가 가진 소켓
+ *
+ * ex)
+ *   TCP    127.0.0.1:3000         127.0.0.1:54321        ESTABLISHED     1234
+ *   UDP    0.0.0.0:5353           *:*                                    1234
+ */
+pub fn parse_netstat_sockets(output: &str, pid: &str) -> Vec<SocketEntry> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let columns: Vec<&str> = line.split_whitespace().collect();
+            let (protocol, local, remote, state) = match columns.as_slice() {
+                [protocol, local, remote, state, owner] if *owner == pid => {
+                    (protocol, local, remote, state.to_string())
+                }
+                [protocol, local, remote, owner] if *owner == pid => {
+                    (protocol, local, remote, "UNCONN".to_string())
+                }
+                _ => return None,
+            };
+
+            Some(SocketEntry {
+                protocol: protocol.to_uppercase(),
+                local_address: local.to_string(),
+                remote_address: remote_or_none(remote),
+                state,
+            })
+        })
+        .collect()
+}
+
+// (실행 파일, 명령어, 메모리 bytes, CPU 시간 초)
+type CimDetail = (Option<String>, Option<String>, Option<u64>, Option<f64>);
+
+/**
+ * Get-CimInstance Win32_Process | ConvertTo-Csv 결과 한 줄
+ *
+ * ex)
+ * "ExecutablePath","CommandLine","WorkingSetSize","UserModeTime","KernelModeTime"
+ * "C:\node.exe","node server.js","52461568","1250000","312500"
+ */
+pub fn parse_cim_detail(output: &str) -> Option<CimDetail> {
+    let fields = split_csv_line(output.lines().nth(1)?.trim());
+    if fields.len() < 5 {
+        return None;
+    }
+
+    let non_empty = |value: &String| Some(value.clone()).filter(|value| !value.is_empty());
+    // UserModeTime, KernelModeTime은 100ns 단위
+    let cpu_seconds = fields[3]
+        .parse::<u64>()
+        .ok()
+        .zip(fields[4].parse::<u64>().ok())
+        .map(|(user, kernel)| (user + kernel) as f64 / 10_000_000.0);
+
+    Some((
+        non_empty(&fields[0]),
+        non_empty(&fields[1]),
+        fields[2].parse().ok(),
+        cpu_seconds,
+    ))
+}
+
+// 커널은 연결되지 않은 UDP 소켓을 CLOSE(07)로 표시한다.
+fn proc_sockets(pid: &str) -> Vec<SocketEntry> {
+    let inodes = process_socket_inodes(Path::new("/proc"), pid);
+    if inodes.is_empty() {
+        return Vec::new();
+    }
+
+    let mut sockets = Vec::new();
+    for (file, protocol) in [
+        ("/proc/net/tcp", "TCP"),
+        ("/proc/net/tcp6", "TCP"),
+        ("/proc/net/udp", "UDP"),
+        ("/proc/net/udp6", "UDP"),
+    ] {
+        let Ok(content) = fs::read_to_string(file) else {
+            continue;
+        };
+
+        for connection in parse_proc_connections(&content) {
+            if !inodes.contains(&connection.inode) {
+                continue;
+            }
+            let state = match (protocol, connection.state) {
+                ("UDP", "CLOSE") => "UNCONN",
+                (_, state) => state,
+            };
+            sockets.push(SocketEntry {
+                protocol: protocol.to_string(),
+                local_address: connection.local.to_string(),
+                remote_address: remote_or_none(&connection.remote.to_string()),
+                state: state.to_string(),
+            });
+        }
+    }
+
+    sockets
+}
+
+/**
+ * 상세 패널에 보여줄 정보를 모은다.
+ * 항목마다 따로 조회하므로 일부를 읽지 못해도 나머지는 돌려준다.
+ */
+pub fn process_detail(
+    executor: &dyn CommandExecutor,
+    pid: &str,
+) -> Result<ProcessDetail, CommandError> {
+    // 명령어 인자로 넘기므로 숫자만 허용한다.
+    if pid.is_empty() || !pid.chars().all(|c| c.is_ascii_digit()) {
+        return Err(CommandError::Failed(format!("Invalid pid: {}", pid)));
+    }
+
+    let table = process_table(executor)?;
+    let entry = table.iter().find(|entry| entry.pid == pid);
+    let started_at = process_start_time(executor, pid);
+    let elapsed = started_at.map(|started_at| (now_millis() / 1000).saturating_sub(started_at));
+
+    let mut detail = ProcessDetail {
+        pid: pid.to_string(),
+        process_name: entry.map(|entry| entry.name.clone()),
+        ancestors: ancestors(&table, pid),
+        started_at,
+        ..ProcessDetail::default()
+    };
+
+    if cfg!(target_os = "windows") {
+        let script = format!(
+            "Get-CimInstance Win32_Process -Filter 'ProcessId={}' | Select-Object ExecutablePath,CommandLine,WorkingSetSize,UserModeTime,KernelModeTime | ConvertTo-Csv -NoTypeInformation",
+            pid
+        );
+        if let Some((executable, command_line, memory, cpu_seconds)) = executor
+            .execute_command(
+                "powershell",
+                &["-NoProfile", "-NonInteractive", "-Command", &script],
+            )
+            .ok()
+            .and_then(|output| parse_cim_detail(&output))
+        {
+            detail.executable = executable;
+            detail.command_line = command_line;
+            detail.memory_bytes = memory;
+            detail.cpu_percent = cpu_seconds
+                .zip(elapsed)
+                .and_then(|(cpu_seconds, elapsed)| average_cpu_percent(cpu_seconds, elapsed));
+        }
+        detail.sockets = executor
+            .execute_command("netstat", &["-ano"])
+            .map(|output| parse_netstat_sockets(&output, pid))
+            .unwrap_or_default();
+    } else if cfg!(target_os = "linux") {
+        let root = Path::new("/proc").join(pid);
+        let read_link = |name: &str| {
+            fs::read_link(root.join(name))
+                .ok()
+                .map(|path| path.to_string_lossy().to_string())
+        };
+
+        detail.command_line = read_proc_cmdline(pid);
+        detail.executable = read_link("exe");
+        detail.cwd = read_link("cwd");
+        detail.environment = fs::read(root.join("environ"))
+            .ok()
+            .map(|raw| parse_environ(&raw));
+        detail.memory_bytes = fs::read_to_string(root.join("status"))
+            .ok()
+            .and_then(|status| parse_status_rss(&status));
+        detail.cpu_percent = fs::read_to_string(root.join("stat"))
+            .ok()
+            .and_then(|stat| parse_proc_cpu_ticks(&stat))
+            .zip(elapsed)
+            .and_then(|(ticks, elapsed)| {
+                average_cpu_percent(
+                    ticks as f64 / clock_ticks_per_second(executor) as f64,
+                    elapsed,
+                )
+            });
+        detail.sockets = proc_sockets(pid);
+    } else {
+        if let Some((cpu, memory, command_line)) = executor
+            .execute_command("ps", &["-o", "%cpu=,rss=,command=", "-p", pid])
+            .ok()
+            .and_then(|output| parse_ps_usage(&output))
+        {
+            detail.cpu_percent = cpu;
+            detail.memory_bytes = memory;
+            detail.command_line = command_line;
+        }
+        detail.executable = executor
+            .execute_command("ps", &["-o", "comm=", "-p", pid])
+            .ok()
+            .map(|output| output.trim().to_string())
+            .filter(|executable| !executable.is_empty());
+        detail.cwd = executor
+            .execute_command("lsof", &["-a", "-p", pid, "-d", "cwd", "-Fn"])
+            .ok()
+            .and_then(|output| parse_lsof_name(&output));
+        // 다른 사용자의 프로세스라면 환경 변수 없이 명령어만 나온다.
+        let command = executor.execute_command("ps", &["-ww", "-o", "command=", "-p", pid]);
+        let with_env = executor.execute_command("ps", &["-E", "-ww", "-o", "command=", "-p", pid]);
+        detail.environment = command
+            .ok()
+            .zip(with_env.ok())
+            .and_then(|(command, with_env)| parse_ps_environment(&with_env, &command))
+            .filter(|vars| !vars.is_empty());
+        detail.sockets = executor
+            .execute_command("lsof", &["-a", "-p", pid, "-i", "-P", "-n"])
+            .map(|output| parse_lsof_sockets(&output))
+            .unwrap_or_default();
+    }
+
+    Ok(detail)
+}
+
+/**
+ * explorer는 인자를 직접 나누므로 경로에 공백이나 쉼표가 있으면 따옴표째로 넘겨야 한다.
+ * Command의 인자 escape를 거치면 /select,"path" 형식이 깨진다.
+ */
+#[cfg(windows)]
+fn explorer_select(path: &str) -> Result<String, String> {
+    use crate::traits::command::new_command;
+    use std::os::windows::process::CommandExt;
+
+    // explorer는 성공해도 종료 코드가 1이라 결과를 확인하지 않는다.
+    new_command("explorer", &[])
+        .raw_arg(format!("/select,\"{}\"", path))
+        .output()
+        .map(|_| String::new())
+        .map_err(|e| format!("Failed to execute command: {}", e))
+}
+
+#[cfg(not(windows))]
+fn explorer_select(_path: &str) -> Result<String, String> {
+    Err("explorer is only available on Windows".to_string())
+}
+
+/**
+ * 파일 관리자에서 파일을 선택한 채로 연다.
+ * 리눅스 파일 관리자는 선택을 지원하지 않는 경우가 많아 상위 폴더를 연다.
+ */
+pub fn reveal_in_file_manager(
+    executor: &dyn CommandExecutor,
+    path: &str,
+) -> Result<(), CommandError> {
+    let target = Path::new(path);
+    if !target.exists() {
+        return Err(CommandError::Failed(format!("File not found: {}", path)));
+    }
+
+    if cfg!(target_os = "windows") {
+        explorer_select(path)
+    } else if cfg!(target_os = "macos") {
+        executor.execute_command("open", &["-R", path])
+    } else {
+        let folder = target.parent().unwrap_or(target).to_string_lossy();
+        executor.execute_command("xdg-open", &[&folder])
+    }
+    .map(|_| ())
+    .map_err(CommandError::Failed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_ps_environment() {
+        let vars = parse_ps_environment(
+            "node server.js --port 3000 PATH=/usr/bin:/bin TITLE=My App TOKEN=a=b\n",
+            "node server.js --port 3000\n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            vars,
+            vec![
+                EnvVar {
+                    name: "PATH".to_string(),
+                    value: "/usr/bin:/bin".to_string(),
+                },
+                EnvVar {
+                    name: "TITLE".to_string(),
+                    value: "My App".to_string(),
+                },
+                EnvVar {
+                    name: "TOKEN".to_string(),
+                    value: "a=b".to_string(),
+                },
+            ]
+        );
+        assert_eq!(parse_ps_environment("other", "node"), None);
+    }
+
+    #[test]
+    fn test_parse_environ() {
+        let vars = parse_environ(b"PATH=/usr/bin\0TOKEN=a=b\0=skip\0\0");
+
+        assert_eq!(
+            vars,
+            vec![
+                EnvVar {
+                    name: "PATH".to_string(),
+                    value: "/usr/bin".to_string(),
+                },
+                EnvVar {
+                    name: "TOKEN".to_string(),
+                    value: "a=b".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_proc_usage() {
+        let stat = "1234 (my app) S 1 1234 1234 0 -1 4194560 100 0 0 0 250 50 0 0 20 0 1 0 98765";
+
+        assert_eq!(parse_proc_cpu_ticks(stat), Some(300));
+        assert_eq!(
+            parse_status_rss("Name:\tnode\nVmRSS:\t   51234 kB\n"),
+            Some(51234 * 1024)
+        );
+        assert_eq!(parse_status_rss("Name:\tkthreadd\n"), None);
+        assert_eq!(average_cpu_percent(3.0, 60), Some(5.0));
+        assert_eq!(average_cpu_percent(3.0, 0), None);
+    }
+
+    #[test]
+    fn test_parse_ps_usage() {
+        assert_eq!(
+            parse_ps_usage("  0.5  2048 node server.js --port 3000\n"),
+            Some((
+                Some(0.5),
+                Some(2048 * 1024),
+                Some("node server.js --port 3000".to_string())
+            ))
+        );
+        assert_eq!(parse_ps_usage(""), None);
+    }
+
+    #[test]
+    fn test_parse_lsof() {
+        let sockets = "\
+COMMAND  PID USER   FD   TYPE   DEVICE SIZE/OFF NODE NAME
+node    1234 me     20u  IPv4 0x1234      0t0  TCP 127.0.0.1:3000 (LISTEN)
+node    1234 me     21u  IPv4 0x5678      0t0  TCP 127.0.0.1:3000->127.0.0.1:54321 (ESTABLISHED)
+node    1234 me     22u  IPv4 0x9abc      0t0  UDP *:5353
+";
+
+        assert_eq!(
+            parse_lsof_sockets(sockets),
+            vec![
+                SocketEntry {
+                    protocol: "TCP".to_string(),
+                    local_address: "127.0.0.1:3000".to_string(),
+                    remote_address: None,
+                    state: "LISTEN".to_string(),
+                },
+                SocketEntry {
+                    protocol: "TCP".to_string(),
+                    local_address: "127.0.0.1:3000".to_string(),
+                    remote_address: Some("127.0.0.1:54321".to_string()),
+                    state: "ESTABLISHED".to_string(),
+                },
+                SocketEntry {
+                    protocol: "UDP".to_string(),
+                    local_address: "*:5353".to_string(),
+                    remote_address: None,
+                    state: "UNCONN".to_string(),
+                },
+            ]
+        );
+        assert_eq!(
+            parse_lsof_name("p1234\nfcwd\nn/Users/me/project\n"),
+            Some("/Users/me/project".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_netstat_sockets() {
+        let output = "
+  Proto  Local Address          Foreign Address        State           PID
+  TCP    0.0.0.0:3000           0.0.0.0:0              LISTENING       1234
+  TCP    127.0.0.1:3000         127.0.0.1:54321        ESTABLISHED     1234
+  TCP    127.0.0.1:5432         0.0.0.0:0              LISTENING       2200
+  UDP    0.0.0.0:5353           *:*                                    1234
+";
+        let sockets = parse_netstat_sockets(output, "1234");
+
+        assert_eq!(sockets.len(), 3);
+        assert_eq!(sockets[0].remote_address, None);
+        assert_eq!(sockets[0].state, "LISTENING");
+        assert_eq!(
+            sockets[1].remote_address,
+            Some("127.0.0.1:54321".to_string())
+        );
+        assert_eq!(sockets[2].protocol, "UDP");
+        assert_eq!(sockets[2].state, "UNCONN");
+    }
+
+    #[test]
+    fn test_parse_cim_detail() {
+        let output = "\"ExecutablePath\",\"CommandLine\",\"WorkingSetSize\",\"UserModeTime\",\"KernelModeTime\"\r\n\"C:\\node.exe\",\"node server.js\",\"52461568\",\"1250000\",\"312500\"\r\n";
+
+        assert_eq!(
+            parse_cim_detail(output),
+            Some((
+                Some("C:\\node.exe".to_string()),
+                Some("node server.js".to_string()),
+                Some(52_461_568),
+                Some(0.15625)
+            ))
+        );
+        assert_eq!(parse_cim_detail("\"ExecutablePath\"\r\n"), None);
+    }
+}
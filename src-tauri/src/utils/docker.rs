@@ -0,0 +1,400 @@
+use std::collections::HashMap;
+use std::io::{Read, Write};
+use std::path::PathBuf;
+use std::time::Duration;
+
+use serde::Deserialize;
+
+use crate::{
+    enums::error::CommandError,
+    structs::{
+        container::{ContainerInfo, ContainerPatch, ContainerSummary},
+        port::PortInfo,
+    },
+};
+
+pub const DEFAULT_DOCKER_SOCKET: &str = "/var/run/docker.sock";
+const DOCKER_TIMEOUT: Duration = Duration::from_secs(2);
+const COMPOSE_PROJECT_LABEL: &str = "com.docker.compose.project";
+
+// 컨테이너 대신 포트를 점유하는 Docker 프로세스
+const DOCKER_PROXY_PROCESSES: [&str; 6] = [
+    "docker-proxy",
+    "com.docker.backend",
+    "com.docker.vpnkit",
+    "vpnkit",
+    "vpnkit-bridge",
+    "wslrelay.exe",
+];
+
+// GET /containers/json 응답 중 필요한 필드
+#[derive(Deserialize)]
+struct DockerContainer {
+    #[serde(rename = "Id")]
+    id: String,
+    #[serde(rename = "Names", default)]
+    names: Vec<String>,
+    #[serde(rename = "Image", default)]
+    image: String,
+    #[serde(rename = "Labels", default)]
+    labels: Option<HashMap<String, String>>,
+    #[serde(rename = "Ports", default)]
+    ports: Vec<DockerPort>,
+}
+
+#[derive(Deserialize)]
+struct DockerPort {
+    #[serde(rename = "PublicPort")]
+    public_port: Option<u16>,
+    #[serde(rename = "Type", default)]
+    protocol: String,
+}
+
+/**
+ * Unix socket으로 Docker Engine API를 호출한다.
+ */
+pub struct DockerClient {
+    socket_path: PathBuf,
+}
+
+impl DockerClient {
+    pub fn new(socket_path: PathBuf) -> Self {
+        DockerClient { socket_path }
+    }
+
+    /**
+     * DOCKER_HOST가 unix:// 라면 그 경로를, 아니면 기본 소켓을 사용한다.
+     */
+    pub fn from_env() -> Self {
+        let socket_path = std::env::var("DOCKER_HOST")
+            .ok()
+            .and_then(|host| host.strip_prefix("unix://").map(PathBuf::from))
+            .unwrap_or_else(|| PathBuf::from(DEFAULT_DOCKER_SOCKET));
+
+        DockerClient::new(socket_path)
+    }
+
+    #[cfg(unix)]
+    fn request(&self, method: &str, path: &str) -> Result<(u16, String), CommandError> {
+        use std::os::unix::net::UnixStream;
+
+        let mut stream = UnixStream::connect(&self.socket_path)
+            .map_err(|e| CommandError::Failed(format!("Failed to connect to Docker: {}", e)))?;
+        stream.set_read_timeout(Some(DOCKER_TIMEOUT)).ok();
+        stream.set_write_timeout(Some(DOCKER_TIMEOUT)).ok();
+
+        // HTTP/1.0으로 요청하면 응답 본문이 chunked로 오지 않고 연결이 닫힌다.
+        let request = format!(
+            "{} {} HTTP/1.0\r\nHost: docker\r\nContent-Length: 0\r\n\r\n",
+            method, path
+        );
+        stream
+            .write_all(request.as_bytes())
+            .map_err(|e| CommandError::Failed(format!("Failed to write to Docker: {}", e)))?;
+
+        let mut response = Vec::new();
+        stream
+            .read_to_end(&mut response)
+            .map_err(|e| CommandError::Failed(format!("Failed to read from Docker: {}", e)))?;
+
+        parse_http_response(&String::from_utf8_lossy(&response))
+    }
+
+    // Windows의 named pipe는 지원하지 않는다.
+    #[cfg(not(unix))]
+    fn request(&self, _method: &str, _path: &str) -> Result<(u16, String), CommandError> {
+        Err(CommandError::UnsupportedOS)
+    }
+
+    pub fn list_containers(&self) -> Result<Vec<ContainerInfo>, CommandError> {
+        let (status, body) = self.request("GET", "/containers/json")?;
+        if status != 200 {
+            return Err(CommandError::Failed(format!("Docker responded {}", status)));
+        }
+
+        parse_containers(&body)
+    }
+
+    pub fn stop_container(&self, id: &str) -> Result<(), CommandError> {
+        // 컨테이너 id는 16진수 문자열이므로 경로에 다른 문자가 섞이지 않게 막는다.
+        if id.is_empty() || !id.chars().all(|c| c.is_ascii_alphanumeric()) {
+            return Err(CommandError::Failed(format!("Invalid container id: {}", id)));
+        }
+
+        match self.request("POST", &format!("/containers/{}/stop", id))? {
+            // 304: 이미 멈춰 있음
+            (204, _) | (304, _) => Ok(()),
+            (status, body) => Err(CommandError::Failed(format!(
+                "Docker responded {}: {}",
+                status,
+                body.trim()
+            ))),
+        }
+    }
+}
+
+/**
+ * HTTP 응답을 (status, body)로 나눈다.
+ */
+fn parse_http_response(response: &str) -> Result<(u16, String), CommandError> {
+    let (head, body) = response
+        .split_once("\r\n\r\n")
+        .ok_or_else(|| CommandError::Failed("Invalid response from Docker".to_string()))?;
+
+    let status = head
+        .lines()
+        .next()
+        .and_then(|line| line.split_whitespace().nth(1))
+        .and_then(|status| status.parse::<u16>().ok())
+        .ok_or_else(|| CommandError::Failed("Invalid status line from Docker".to_string()))?;
+
+    Ok((status, body.to_string()))
+}
+
+pub fn parse_containers(body: &str) -> Result<Vec<ContainerInfo>, CommandError> {
+    let containers: Vec<DockerContainer> = serde_json::from_str(body)
+        .map_err(|e| CommandError::Failed(format!("Invalid container list: {}", e)))?;
+
+    Ok(containers
+        .into_iter()
+        .map(|container| {
+            let labels = container.labels.unwrap_or_default();
+
+            ContainerInfo {
+                summary: ContainerSummary {
+                    id: container.id,
+                    // Names는 "/web-1" 형태
+                    name: container
+                        .names
+                        .first()
+                        .map(|name| name.trim_start_matches('/').to_string())
+                        .unwrap_or_default(),
+                    image: container.image,
+                    compose_project: labels.get(COMPOSE_PROJECT_LABEL).cloned(),
+                },
+                public_ports: container
+                    .ports
+                    .into_iter()
+                    .filter_map(|port| Some((port.protocol.to_lowercase(), port.public_port?)))
+                    .collect(),
+            }
+        })
+        .collect())
+}
+
+pub fn is_docker_proxy(process_name: &str) -> bool {
+    DOCKER_PROXY_PROCESSES
+        .iter()
+        .any(|name| process_name.eq_ignore_ascii_case(name))
+}
+
+/**
+ * Docker 프로세스가 점유한 행을 공개 포트가 같은 컨테이너와 연결한다.
+ */
+pub fn match_containers(rows: &[PortInfo], containers: &[ContainerInfo]) -> Vec<ContainerPatch> {
+    rows.iter()
+        .filter(|row| is_docker_proxy(&row.process_name))
+        .filter_map(|row| {
+            let port = row.port.parse::<u16>().ok()?;
+            let protocol = row.protocol.to_lowercase();
+
+            let container = containers.iter().find(|container| {
+                container
+                    .public_ports
+                    .iter()
+                    .any(|(public_protocol, public_port)| {
+                        *public_port == port && protocol.starts_with(public_protocol.as_str())
+                    })
+            })?;
+
+            Some(ContainerPatch {
+                key: row.key(),
+                container: container.summary.clone(),
+            })
+        })
+        .collect()
+}
+
+/**
+ * Docker 프로세스가 있을 때만 Docker API를 호출한다.
+ * Docker가 없거나 응답하지 않으면 빈 결과를 돌려준다.
+ */
+pub fn resolve_containers(client: &DockerClient, rows: &[PortInfo]) -> Vec<ContainerPatch> {
+    if !rows.iter().any(|row| is_docker_proxy(&row.process_name)) {
+        return Vec::new();
+    }
+
+    client
+        .list_containers()
+        .map(|containers| match_containers(rows, &containers))
+        .unwrap_or_default()
+}
+
+pub fn apply_container_patches(rows: &mut [PortInfo], patches: &[ContainerPatch]) {
+    for patch in patches {
+        for row in rows.iter_mut().filter(|row| row.key() == patch.key) {
+            row.container = Some(patch.container.clone());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONTAINERS_JSON: &str = r#"[
+        {
+            "Id": "3f2a9c",
+            "Names": ["/shop-web-1"],
+            "Image": "nginx:latest",
+            "Labels": {"com.docker.compose.project": "shop"},
+            "Ports": [
+                {"IP": "0.0.0.0", "PrivatePort": 80, "PublicPort": 8080, "Type": "tcp"},
+                {"PrivatePort": 443, "Type": "tcp"}
+            ]
+        },
+        {
+            "Id": "7b1d0e",
+            "Names": ["/redis"],
+            "Image": "redis:7",
+            "Labels": null,
+            "Ports": [{"IP": "127.0.0.1", "PrivatePort": 6379, "PublicPort": 6379, "Type": "tcp"}]
+        }
+    ]"#;
+
+    fn port_info(process: &str, port: &str) -> PortInfo {
+        PortInfo {
+            process_name: process.to_string(),
+            port: port.to_string(),
+            pid: "100".to_string(),
+            protocol: "TCP".to_string(),
+            address: "0.0.0.0".to_string(),
+            ..PortInfo::default()
+        }
+    }
+
+    #[test]
+    fn test_parse_containers() {
+        let containers = parse_containers(CONTAINERS_JSON).unwrap();
+
+        assert_eq!(containers.len(), 2);
+        assert_eq!(containers[0].summary.name, "shop-web-1");
+        assert_eq!(
+            containers[0].summary.compose_project,
+            Some("shop".to_string())
+        );
+        assert_eq!(containers[0].public_ports, vec![("tcp".to_string(), 8080)]);
+        assert_eq!(containers[1].summary.compose_project, None);
+    }
+
+    #[test]
+    fn test_match_containers() {
+        let containers = parse_containers(CONTAINERS_JSON).unwrap();
+        let rows = vec![
+            port_info("docker-proxy", "8080"),
+            port_info("com.docker.backend", "6379"),
+            // Docker 프로세스가 아니면 포트가 같아도 연결하지 않는다
+            port_info("nginx", "8080"),
+            port_info("docker-proxy", "9999"),
+        ];
+
+        let patches = match_containers(&rows, &containers);
+
+        assert_eq!(patches.len(), 2);
+        assert_eq!(patches[0].key, rows[0].key());
+        assert_eq!(patches[0].container.image, "nginx:latest");
+        assert_eq!(patches[1].container.name, "redis");
+    }
+
+    #[test]
+    fn test_parse_http_response() {
+        let response = "HTTP/1.0 204 No Content\r\nApi-Version: 1.43\r\n\r\n";
+
+        assert_eq!(parse_http_response(response), Ok((204, String::new())));
+    }
+
+    #[test]
+    fn test_stop_container_invalid_id() {
+        let client = DockerClient::new(PathBuf::from("/nonexistent/docker.sock"));
+
+        assert!(client.stop_container("../../info").is_err());
+    }
+
+    /**
+     * 요청 한 번을 받아 응답을 돌려주는 가짜 Docker 소켓 서버
+     * 받은 요청 줄은 채널로 돌려준다.
+     */
+    #[cfg(unix)]
+    fn fake_docker(name: &str, response: String) -> (PathBuf, std::sync::mpsc::Receiver<String>) {
+        use std::io::{BufRead, BufReader};
+        use std::os::unix::net::UnixListener;
+
+        let path = std::env::temp_dir().join(format!(
+            "open_port_check_docker_{}_{}.sock",
+            name,
+            std::process::id()
+        ));
+        let _ = std::fs::remove_file(&path);
+        let listener = UnixListener::bind(&path).unwrap();
+        let (tx, rx) = std::sync::mpsc::channel();
+
+        std::thread::spawn(move || {
+            if let Ok((mut stream, _)) = listener.accept() {
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+
+                // 헤더 끝까지 읽는다
+                let mut line = String::new();
+                while reader.read_line(&mut line).unwrap() > 0 && line != "\r\n" {
+                    line.clear();
+                }
+
+                stream.write_all(response.as_bytes()).unwrap();
+                tx.send(request_line.trim().to_string()).unwrap();
+            }
+        });
+
+        (path, rx)
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_list_containers_from_socket() {
+        let response = format!(
+            "HTTP/1.0 200 OK\r\nContent-Type: application/json\r\n\r\n{}",
+            CONTAINERS_JSON
+        );
+        let (path, requests) = fake_docker("list", response);
+        let client = DockerClient::new(path.clone());
+
+        let containers = client.list_containers().unwrap();
+        let _ = std::fs::remove_file(&path);
+
+        assert_eq!(requests.recv().unwrap(), "GET /containers/json HTTP/1.0");
+        assert_eq!(containers.len(), 2);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_stop_container_from_socket() {
+        let (path, requests) = fake_docker("stop", "HTTP/1.0 204 No Content\r\n\r\n".to_string());
+        let client = DockerClient::new(path.clone());
+
+        let result = client.stop_container("3f2a9c");
+        let _ = std::fs::remove_file(&path);
+
+        assert_eq!(result, Ok(()));
+        assert_eq!(
+            requests.recv().unwrap(),
+            "POST /containers/3f2a9c/stop HTTP/1.0"
+        );
+    }
+
+    #[test]
+    fn test_resolve_containers_without_docker_rows() {
+        let client = DockerClient::new(PathBuf::from("/nonexistent/docker.sock"));
+
+        assert!(resolve_containers(&client, &[port_info("node", "3000")]).is_empty());
+    }
+}
@@ -0,0 +1,109 @@
+use crate::{
+    enums::error::CommandError,
+    structs::{coverage::ElevatedScan, port::PortInfo},
+    traits::{command::CommandExecutor, elevator::Elevator},
+    utils::{
+        coverage::scan_coverage, kill_permission::resolve_kill_permissions, port::apply_patches,
+        reservation::mark_reserved,
+    },
+};
+
+// 관리자 권한으로 다시 실행할 CLI 명령어 (cli.rs의 list)
+pub const ELEVATED_LIST_ARGS: [&str; 2] = ["list", "--json"];
+
+/**
+ * 이 앱의 CLI를 관리자 권한으로 다시 실행해 모든 소켓을 조회한다.
+ * 인증 창을 닫으면 Cancelled, 인증에 실패하거나 출력이 없으면 Failed
+ */
+pub fn elevated_scan(
+    executor: &dyn CommandExecutor,
+    elevator: &dyn Elevator,
+) -> Result<ElevatedScan, CommandError> {
+    let program = std::env::current_exe().map_err(|e| CommandError::Failed(e.to_string()))?;
+    let output = elevator.run_elevated(&program, &ELEVATED_LIST_ARGS)?;
+
+    if output.trim().is_empty() {
+        return Err(CommandError::Failed(
+            "Elevated scan returned no output".to_string(),
+        ));
+    }
+
+    let mut rows: Vec<PortInfo> = serde_json::from_str(output.trim())
+        .map_err(|e| CommandError::Failed(format!("Invalid elevated scan output: {}", e)))?;
+
+    // 다시 실행된 프로세스의 pid와 권한이 아닌 이 앱을 기준으로 예약과 종료 권한을 표시한다.
+    mark_reserved(&mut rows, std::process::id());
+    let permissions = resolve_kill_permissions(executor, &rows);
+    apply_patches(&mut rows, &permissions);
+
+    let coverage = scan_coverage(&rows, true, 0, Vec::new());
+    Ok(ElevatedScan { rows, coverage })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    struct MockCommandExecutor;
+    impl CommandExecutor for MockCommandExecutor {
+        fn execute_command(&self, _command: &str, _args: &[&str]) -> Result<String, String> {
+            Err("Unsupported command".to_string())
+        }
+    }
+
+    struct MockElevator(Result<String, CommandError>);
+    impl Elevator for MockElevator {
+        fn run_elevated(&self, _program: &Path, args: &[&str]) -> Result<String, CommandError> {
+            assert_eq!(args, ELEVATED_LIST_ARGS);
+            self.0.clone()
+        }
+    }
+
+    #[test]
+    fn test_elevated_scan() {
+        let output = r#"[
+            {"processName":"sshd","port":"22","pid":"1","protocol":"TCP","address":"0.0.0.0","user":"root"},
+            {"processName":"node","port":"3000","pid":"4321","protocol":"TCP","address":"127.0.0.1","user":"user"}
+        ]"#;
+
+        let scan =
+            elevated_scan(&MockCommandExecutor, &MockElevator(Ok(output.to_string()))).unwrap();
+
+        assert_eq!(scan.rows.len(), 2);
+        assert_eq!(scan.rows[0].process_name, "sshd");
+        assert!(scan.coverage.elevated);
+        assert_eq!(scan.coverage.unknown_owners, 0);
+    }
+
+    #[test]
+    fn test_elevated_scan_cancelled() {
+        assert_eq!(
+            elevated_scan(
+                &MockCommandExecutor,
+                &MockElevator(Err(CommandError::Cancelled))
+            ),
+            Err(CommandError::Cancelled)
+        );
+        assert_eq!(
+            elevated_scan(&MockCommandExecutor, &MockElevator(Ok("\n".to_string()))),
+            Err(CommandError::Failed(
+                "Elevated scan returned no output".to_string()
+            ))
+        );
+        assert_eq!(
+            elevated_scan(
+                &MockCommandExecutor,
+                &MockElevator(Err(CommandError::Failed("no pkexec".to_string())))
+            ),
+            Err(CommandError::Failed("no pkexec".to_string()))
+        );
+        assert!(matches!(
+            elevated_scan(
+                &MockCommandExecutor,
+                &MockElevator(Ok("Error: not authorized".to_string()))
+            ),
+            Err(CommandError::Failed(_))
+        ));
+    }
+}
@@ -0,0 +1,103 @@
+use std::collections::HashSet;
+use std::net::SocketAddr;
+use std::thread;
+use std::time::Duration;
+
+use crate::{
+    structs::{
+        fingerprint::{Fingerprint, FingerprintResult},
+        port::PortInfo,
+    },
+    utils::{
+        http_probe::fingerprint_http,
+        probe::probe_target,
+        protocol_probe::{
+            parse_mysql_greeting, parse_ssh_banner, probe_postgres, probe_redis, read_banner,
+        },
+        tls_probe::fingerprint_tls,
+    },
+};
+
+pub const DEFAULT_FINGERPRINT_TIMEOUT_MS: u64 = 1_500;
+
+// 서버가 먼저 말하는 프로토콜(SSH, MySQL)을 기다리는 시간
+const BANNER_TIMEOUT: Duration = Duration::from_millis(300);
+
+type Probe = fn(SocketAddr, Duration) -> Option<Fingerprint>;
+
+fn http(target: SocketAddr, timeout: Duration) -> Option<Fingerprint> {
+    fingerprint_http(target, timeout).map(Fingerprint::Http)
+}
+
+fn tls(target: SocketAddr, timeout: Duration) -> Option<Fingerprint> {
+    fingerprint_tls(target, timeout).map(Fingerprint::Tls)
+}
+
+// (기본 포트, 먼저 시도할 프로토콜)
+const PORT_HINTS: [(u16, Probe); 6] = [
+    (443, tls),
+    (5432, probe_postgres),
+    (6379, probe_redis),
+    (6443, tls),
+    (8443, tls),
+    (9443, tls),
+];
+
+/**
+ * 클라이언트가 먼저 말하는 프로토콜을 시도할 순서
+ * 잘 알려진 포트라면 해당 프로토콜을 먼저 시도한다.
+ */
+fn probe_order(port: u16) -> Vec<Probe> {
+    let mut probes: Vec<Probe> = vec![http, tls, probe_redis, probe_postgres];
+
+    if let Some((_, hint)) = PORT_HINTS.iter().find(|(known, _)| *known == port) {
+        probes.retain(|probe| !std::ptr::fn_addr_eq(*probe, *hint));
+        probes.insert(0, *hint);
+    }
+
+    probes
+}
+
+/**
+ * 소켓 너머의 프로토콜을 확인한다. 루프백으로 연결할 수 있는 소켓만 확인한다.
+ * 모든 요청은 읽기 전용이며, 알 수 없는 응답을 받으면 다음 프로토콜을 시도한다.
+ */
+pub fn fingerprint_row(row: &PortInfo, timeout: Duration) -> Option<Fingerprint> {
+    let target = probe_target(row).filter(|target| target.ip().is_loopback())?;
+
+    if let Some(banner) = read_banner(target, BANNER_TIMEOUT) {
+        return parse_ssh_banner(&banner).or_else(|| parse_mysql_greeting(&banner));
+    }
+
+    probe_order(target.port())
+        .into_iter()
+        .find_map(|probe| probe(target, timeout))
+}
+
+/**
+ * 소켓마다 한 번씩 동시에 확인한다.
+ */
+pub fn fingerprint_rows(rows: &[PortInfo], timeout: Duration) -> Vec<FingerprintResult> {
+    let mut seen = HashSet::new();
+    let sockets: Vec<&PortInfo> = rows
+        .iter()
+        .filter(|row| seen.insert(row.socket_key()))
+        .collect();
+
+    thread::scope(|scope| {
+        let handles: Vec<_> = sockets
+            .iter()
+            .map(|row| {
+                scope.spawn(move || FingerprintResult {
+                    socket_key: row.socket_key(),
+                    fingerprint: fingerprint_row(row, timeout),
+                })
+            })
+            .collect();
+
+        handles
+            .into_iter()
+            .filter_map(|handle| handle.join().ok())
+            .collect()
+    })
+}
@@ -0,0 +1,503 @@
+use std::collections::HashMap;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use crate::{
+    enums::error::CommandError,
+    structs::{
+        history::{HistoryEvent, HistoryEventKind, HistoryQuery, PortLifetime},
+        port::PortInfo,
+        watch::WatchDiff,
+    },
+    utils::watch::diff_by_owner,
+};
+
+pub const DAY_MS: u64 = 24 * 60 * 60 * 1000;
+// 앱이 오래 켜져 있어도 파일이 계속 커지지 않도록 보관 기간을 주기적으로 적용한다.
+pub const PRUNE_INTERVAL_MS: u64 = 60 * 60 * 1000;
+
+/**
+ * 감시 이벤트를 JSONL 파일에 이어 쓰는 이력 저장소
+ */
+pub struct HistoryStore {
+    path: PathBuf,
+    // append와 prune이 동시에 파일을 건드리지 않도록 잠근다.
+    lock: Mutex<()>,
+    // 기록 중일 때 마지막으로 기록한 열린 소켓, 기록 중이 아니면 None
+    recorded: Mutex<Option<Vec<PortInfo>>>,
+}
+
+impl HistoryStore {
+    pub fn new(path: PathBuf) -> Self {
+        HistoryStore {
+            path,
+            lock: Mutex::new(()),
+            recorded: Mutex::new(None),
+        }
+    }
+
+    /**
+     * 조회 결과를 이전 기록과 비교해 열림/닫힘 이벤트를 쓴다.
+     * 기록을 시작한 뒤 첫 조회는 이미 열려 있던 소켓을 모두 열림으로 쓰고,
+     * 이전 실행에서 닫힘 없이 끝난 소켓(ex. 비정상 종료)은 이 시각에 닫힌 것으로 쓴다.
+     */
+    pub fn record(&self, current: &[PortInfo], now: u64) -> Result<(), CommandError> {
+        let mut recorded = self.recorded.lock().unwrap_or_else(|e| e.into_inner());
+
+        let events = match recorded.as_deref() {
+            Some(previous) => events_from_diff(&diff_by_owner(previous, current), now),
+            None => {
+                let dangling: Vec<PortInfo> = build_lifetimes(&self.read()?)
+                    .into_iter()
+                    .filter(|lifetime| lifetime.closed_at.is_none())
+                    .map(|lifetime| lifetime.port)
+                    .collect();
+                events_from_diff(
+                    &WatchDiff {
+                        opened: current.to_vec(),
+                        closed: dangling,
+                    },
+                    now,
+                )
+            }
+        };
+
+        self.append(&events)?;
+        *recorded = Some(current.to_vec());
+        Ok(())
+    }
+
+    /**
+     * 기록을 멈춘다. (REC 해제, 앱 종료)
+     * 아직 열려 있는 소켓은 이후를 알 수 없으므로 이 시각에 닫힌 것으로 쓴다.
+     */
+    pub fn stop_recording(&self, now: u64) -> Result<(), CommandError> {
+        let mut recorded = self.recorded.lock().unwrap_or_else(|e| e.into_inner());
+
+        let Some(previous) = recorded.take() else {
+            return Ok(());
+        };
+        self.append(&events_from_diff(
+            &WatchDiff {
+                opened: Vec::new(),
+                closed: previous,
+            },
+            now,
+        ))
+    }
+
+    pub fn append(&self, events: &[HistoryEvent]) -> Result<(), CommandError> {
+        if events.is_empty() {
+            return Ok(());
+        }
+
+        let _guard = self.lock.lock().unwrap();
+
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)
+                .map_err(|e| CommandError::Failed(format!("Failed to create history dir: {}", e)))?;
+        }
+
+        let mut lines = String::new();
+        for event in events {
+            let line = serde_json::to_string(event)
+                .map_err(|e| CommandError::Failed(format!("Failed to serialize event: {}", e)))?;
+            lines.push_str(&line);
+            lines.push('\n');
+        }
+
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .and_then(|mut file| file.write_all(lines.as_bytes()))
+            .map_err(|e| CommandError::Failed(format!("Failed to write history: {}", e)))
+    }
+
+    /**
+     * 저장된 모든 이벤트를 읽는다. 깨진 줄은 건너뛴다.
+     */
+    pub fn read(&self) -> Result<Vec<HistoryEvent>, CommandError> {
+        let _guard = self.lock.lock().unwrap();
+        self.read_unlocked()
+    }
+
+    fn read_unlocked(&self) -> Result<Vec<HistoryEvent>, CommandError> {
+        match fs::read_to_string(&self.path) {
+            Ok(content) => Ok(content
+                .lines()
+                .filter_map(|line| serde_json::from_str(line).ok())
+                .collect()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Vec::new()),
+            Err(e) => Err(CommandError::Failed(format!("Failed to read history: {}", e))),
+        }
+    }
+
+    /**
+     * 보관 기간이 지난 이벤트를 지우고 지운 개수를 돌려준다.
+     */
+    pub fn prune(&self, retention_days: u32, now: u64) -> Result<usize, CommandError> {
+        let _guard = self.lock.lock().unwrap();
+
+        let events = self.read_unlocked()?;
+        let cutoff = now.saturating_sub(retention_days as u64 * DAY_MS);
+        let (kept, removed): (Vec<HistoryEvent>, Vec<HistoryEvent>) = events
+            .into_iter()
+            .partition(|event| event.timestamp >= cutoff);
+
+        if removed.is_empty() {
+            return Ok(0);
+        }
+
+        let mut lines = String::new();
+        for event in kept.iter() {
+            if let Ok(line) = serde_json::to_string(event) {
+                lines.push_str(&line);
+                lines.push('\n');
+            }
+        }
+
+        // 쓰는 도중 종료되어도 기존 파일이 깨지지 않도록 임시 파일을 교체한다.
+        let temp = self.path.with_extension("jsonl.tmp");
+        fs::write(&temp, lines)
+            .and_then(|_| fs::rename(&temp, &self.path))
+            .map_err(|e| CommandError::Failed(format!("Failed to prune history: {}", e)))?;
+
+        Ok(removed.len())
+    }
+
+    pub fn query(&self, query: &HistoryQuery) -> Result<Vec<PortLifetime>, CommandError> {
+        let lifetimes = build_lifetimes(&self.read()?);
+        Ok(filter_lifetimes(lifetimes, query))
+    }
+}
+
+pub fn events_from_diff(diff: &WatchDiff, timestamp: u64) -> Vec<HistoryEvent> {
+    let opened = diff.opened.iter().map(|port| HistoryEvent {
+        timestamp,
+        kind: HistoryEventKind::Opened,
+        port: port.clone(),
+    });
+    let closed = diff.closed.iter().map(|port| HistoryEvent {
+        timestamp,
+        kind: HistoryEventKind::Closed,
+        port: port.clone(),
+    });
+
+    closed.chain(opened).collect()
+}
+
+/**
+ * 열림/닫힘 이벤트를 (protocol, address, port, pid) 별 점유 기간으로 묶는다.
+ */
+pub fn build_lifetimes(events: &[HistoryEvent]) -> Vec<PortLifetime> {
+    let mut events: Vec<&HistoryEvent> = events.iter().collect();
+    events.sort_by_key(|event| event.timestamp);
+
+    let mut open: HashMap<String, PortLifetime> = HashMap::new();
+    let mut lifetimes = Vec::new();
+
+    for event in events {
+        let key = event.port.key();
+
+        match event.kind {
+            // 이미 열려 있는 소켓의 중복 열림은 처음 시각을 유지한다.
+            HistoryEventKind::Opened => {
+                open.entry(key).or_insert_with(|| PortLifetime {
+                    port: event.port.clone(),
+                    opened_at: Some(event.timestamp),
+                    closed_at: None,
+                });
+            }
+            HistoryEventKind::Closed => {
+                let mut lifetime = open.remove(&key).unwrap_or_else(|| PortLifetime {
+                    port: event.port.clone(),
+                    opened_at: None,
+                    closed_at: None,
+                });
+                lifetime.closed_at = Some(event.timestamp);
+                lifetimes.push(lifetime);
+            }
+        }
+    }
+
+    lifetimes.extend(open.into_values());
+    lifetimes.sort_by(|a, b| {
+        a.opened_at
+            .cmp(&b.opened_at)
+            .then_with(|| a.port.key().cmp(&b.port.key()))
+    });
+    lifetimes
+}
+
+/**
+ * 포트, 프로세스 이름, 시간 구간 조건에 맞는 점유 기간만 남긴다.
+ */
+pub fn filter_lifetimes(lifetimes: Vec<PortLifetime>, query: &HistoryQuery) -> Vec<PortLifetime> {
+    let process_name = query.process_name.as_ref().map(|name| name.to_lowercase());
+
+    lifetimes
+        .into_iter()
+        .filter(|lifetime| match &query.port {
+            Some(port) => &lifetime.port.port == port,
+            None => true,
+        })
+        .filter(|lifetime| match &process_name {
+            Some(name) => lifetime.port.process_name.to_lowercase().contains(name),
+            None => true,
+        })
+        .filter(|lifetime| {
+            let start = lifetime.opened_at.unwrap_or(0);
+            let end = lifetime.closed_at.unwrap_or(u64::MAX);
+
+            start <= query.to.unwrap_or(u64::MAX) && end >= query.from.unwrap_or(0)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::structs::port::PortInfo;
+
+    fn port_info(process: &str, port: &str, pid: &str) -> PortInfo {
+        PortInfo {
+            process_name: process.to_string(),
+            port: port.to_string(),
+            pid: pid.to_string(),
+            protocol: "TCP".to_string(),
+            address: "127.0.0.1".to_string(),
+            ..PortInfo::default()
+        }
+    }
+
+    fn event(timestamp: u64, kind: HistoryEventKind, port: PortInfo) -> HistoryEvent {
+        HistoryEvent {
+            timestamp,
+            kind,
+            port,
+        }
+    }
+
+    fn temp_store(name: &str) -> HistoryStore {
+        let path = std::env::temp_dir().join(format!(
+            "open_port_check_history_{}_{}.jsonl",
+            name,
+            std::process::id()
+        ));
+        let _ = fs::remove_file(&path);
+        HistoryStore::new(path)
+    }
+
+    #[test]
+    fn test_build_lifetimes() {
+        let events = vec![
+            event(100, HistoryEventKind::Opened, port_info("node", "8080", "1")),
+            event(200, HistoryEventKind::Closed, port_info("node", "8080", "1")),
+            event(250, HistoryEventKind::Opened, port_info("java", "8080", "2")),
+            event(300, HistoryEventKind::Closed, port_info("vite", "5173", "3")),
+        ];
+
+        let lifetimes = build_lifetimes(&events);
+
+        assert_eq!(
+            lifetimes,
+            vec![
+                PortLifetime {
+                    port: port_info("vite", "5173", "3"),
+                    opened_at: None,
+                    closed_at: Some(300),
+                },
+                PortLifetime {
+                    port: port_info("node", "8080", "1"),
+                    opened_at: Some(100),
+                    closed_at: Some(200),
+                },
+                PortLifetime {
+                    port: port_info("java", "8080", "2"),
+                    opened_at: Some(250),
+                    closed_at: None,
+                },
+            ]
+        );
+    }
+
+    // 특정 시각에 8080을 가진 프로세스
+    #[test]
+    fn test_filter_lifetimes_at_time() {
+        let events = vec![
+            event(100, HistoryEventKind::Opened, port_info("node", "8080", "1")),
+            event(200, HistoryEventKind::Closed, port_info("node", "8080", "1")),
+            event(250, HistoryEventKind::Opened, port_info("java", "8080", "2")),
+            event(260, HistoryEventKind::Opened, port_info("vite", "5173", "3")),
+        ];
+        let query = HistoryQuery {
+            port: Some("8080".to_string()),
+            from: Some(150),
+            to: Some(150),
+            ..HistoryQuery::default()
+        };
+
+        let lifetimes = filter_lifetimes(build_lifetimes(&events), &query);
+
+        assert_eq!(lifetimes.len(), 1);
+        assert_eq!(lifetimes[0].port.process_name, "node");
+    }
+
+    #[test]
+    fn test_filter_lifetimes_by_process_name() {
+        let events = vec![
+            event(100, HistoryEventKind::Opened, port_info("Node", "3000", "1")),
+            event(100, HistoryEventKind::Opened, port_info("java", "8080", "2")),
+        ];
+        let query = HistoryQuery {
+            process_name: Some("node".to_string()),
+            ..HistoryQuery::default()
+        };
+
+        let lifetimes = filter_lifetimes(build_lifetimes(&events), &query);
+
+        assert_eq!(lifetimes.len(), 1);
+        assert_eq!(lifetimes[0].port.port, "3000");
+    }
+
+    #[test]
+    fn test_history_store_append_and_query() {
+        let store = temp_store("append");
+        let diff = WatchDiff {
+            opened: vec![port_info("node", "3000", "1")],
+            closed: vec![],
+        };
+
+        store.append(&events_from_diff(&diff, 1_000)).unwrap();
+        store
+            .append(&events_from_diff(
+                &WatchDiff {
+                    opened: vec![],
+                    closed: vec![port_info("node", "3000", "1")],
+                },
+                2_000,
+            ))
+            .unwrap();
+
+        let lifetimes = store.query(&HistoryQuery::default()).unwrap();
+        let _ = fs::remove_file(&store.path);
+
+        assert_eq!(
+            lifetimes,
+            vec![PortLifetime {
+                port: port_info("node", "3000", "1"),
+                opened_at: Some(1_000),
+                closed_at: Some(2_000),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_history_store_record() {
+        let store = temp_store("record");
+
+        // 기록을 시작할 때 열려 있던 소켓도 열림으로 쓴다.
+        store
+            .record(
+                &[
+                    port_info("node", "3000", "1"),
+                    port_info("java", "8080", "2"),
+                ],
+                1_000,
+            )
+            .unwrap();
+        store
+            .record(
+                &[
+                    port_info("java", "8080", "2"),
+                    port_info("vite", "5173", "3"),
+                ],
+                2_000,
+            )
+            .unwrap();
+        store.stop_recording(3_000).unwrap();
+        // 기록 중이 아니면 아무것도 쓰지 않는다.
+        store.stop_recording(4_000).unwrap();
+
+        let lifetimes = store.query(&HistoryQuery::default()).unwrap();
+        let _ = fs::remove_file(&store.path);
+
+        assert_eq!(
+            lifetimes,
+            vec![
+                PortLifetime {
+                    port: port_info("node", "3000", "1"),
+                    opened_at: Some(1_000),
+                    closed_at: Some(2_000),
+                },
+                PortLifetime {
+                    port: port_info("java", "8080", "2"),
+                    opened_at: Some(1_000),
+                    closed_at: Some(3_000),
+                },
+                PortLifetime {
+                    port: port_info("vite", "5173", "3"),
+                    opened_at: Some(2_000),
+                    closed_at: Some(3_000),
+                },
+            ]
+        );
+    }
+
+    // 이전 실행이 닫힘을 쓰지 못하고 끝난 경우
+    #[test]
+    fn test_history_store_record_closes_dangling() {
+        let store = temp_store("dangling");
+
+        store
+            .append(&[event(
+                100,
+                HistoryEventKind::Opened,
+                port_info("node", "3000", "1"),
+            )])
+            .unwrap();
+        store
+            .record(&[port_info("java", "8080", "2")], 1_000)
+            .unwrap();
+
+        let lifetimes = store.query(&HistoryQuery::default()).unwrap();
+        let _ = fs::remove_file(&store.path);
+
+        assert_eq!(lifetimes.len(), 2);
+        assert_eq!(lifetimes[0].opened_at, Some(100));
+        assert_eq!(lifetimes[0].closed_at, Some(1_000));
+        assert_eq!(lifetimes[1].port.process_name, "java");
+        assert_eq!(lifetimes[1].closed_at, None);
+    }
+
+    #[test]
+    fn test_history_store_prune() {
+        let store = temp_store("prune");
+        let now = 10 * DAY_MS;
+
+        store
+            .append(&[
+                event(DAY_MS, HistoryEventKind::Opened, port_info("old", "1", "1")),
+                event(9 * DAY_MS, HistoryEventKind::Opened, port_info("new", "2", "2")),
+            ])
+            .unwrap();
+
+        let removed = store.prune(7, now).unwrap();
+        let events = store.read().unwrap();
+        let _ = fs::remove_file(&store.path);
+
+        assert_eq!(removed, 1);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].port.process_name, "new");
+    }
+
+    #[test]
+    fn test_history_store_read_missing_file() {
+        let store = temp_store("missing");
+
+        assert_eq!(store.read().unwrap(), vec![]);
+    }
+}
@@ -0,0 +1,324 @@
+use std::io::{Read, Write};
+use std::net::{SocketAddr, TcpStream};
+use std::time::{Duration, Instant};
+
+use crate::structs::fingerprint::HttpFingerprint;
+
+// 응답 전체를 기다리지 않고 앞부분만 읽는다.
+pub const MAX_RESPONSE_BYTES: usize = 64 * 1024;
+
+/**
+ * 헤더와 본문을 나눈 HTTP 응답
+ */
+#[derive(Debug, PartialEq)]
+pub struct HttpResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl HttpResponse {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/**
+ * 응답의 상태 줄, 헤더, 본문을 나눈다. HTTP가 아니면 None
+ *
+ * ex)
+ * `HTTP/1.1 200 OK`
+ * `Server: uvicorn`
+ */
+pub fn parse_response(raw: &[u8]) -> Option<HttpResponse> {
+    let text = String::from_utf8_lossy(raw);
+    let (head, body) = text
+        .split_once("\r\n\r\n")
+        .or_else(|| text.split_once("\n\n"))
+        .unwrap_or((&text, ""));
+
+    let mut lines = head.lines();
+    let status_line = lines.next()?;
+    if !status_line.starts_with("HTTP/") {
+        return None;
+    }
+    let status = status_line.split_whitespace().nth(1)?.parse::<u16>().ok()?;
+
+    let headers = lines
+        .filter_map(|line| {
+            let (key, value) = line.split_once(':')?;
+            Some((key.trim().to_string(), value.trim().to_string()))
+        })
+        .collect();
+
+    Some(HttpResponse {
+        status,
+        headers,
+        body: body.to_string(),
+    })
+}
+
+/**
+ * HTML의 <title>을 찾는다.
+ */
+pub fn extract_title(body: &str) -> Option<String> {
+    let lower = body.to_lowercase();
+    let start = lower.find("<title")?;
+    let start = start + lower[start..].find('>')? + 1;
+    let end = start + lower[start..].find("</title>")?;
+
+    let title = body
+        .get(start..end)?
+        .split_whitespace()
+        .collect::<Vec<&str>>()
+        .join(" ");
+    (!title.is_empty()).then_some(title)
+}
+
+/**
+ * 헤더와 본문의 흔적으로 개발 서버 종류를 추측한다.
+ */
+pub fn guess_framework(response: &HttpResponse) -> Option<String> {
+    let server = response.header("server").unwrap_or_default().to_lowercase();
+    let powered_by = response
+        .header("x-powered-by")
+        .unwrap_or_default()
+        .to_lowercase();
+    let location = response.header("location").unwrap_or_default();
+    let body = response.body.to_lowercase();
+
+    let framework = if body.contains("/@vite/client") {
+        "Vite"
+    } else if powered_by.contains("next.js")
+        || body.contains("__next_data__")
+        || body.contains("/_next/")
+    {
+        "Next.js"
+    } else if body.contains("jupyter")
+        || location.contains("/tree")
+        || location.contains("/lab")
+        || location.contains("/login?next=")
+    {
+        "Jupyter"
+    } else if server.contains("wsgiserver") || body.contains("csrfmiddlewaretoken") {
+        "Django"
+    } else if server.contains("puma")
+        || server.contains("webrick")
+        || response.header("x-runtime").is_some()
+    {
+        "Rails"
+    } else if body.contains("whitelabel error page") {
+        "Spring Boot"
+    } else if powered_by.contains("express") {
+        "Express"
+    } else {
+        return None;
+    };
+
+    Some(framework.to_string())
+}
+
+/**
+ * 제한 시간과 크기 제한을 두고 GET 요청을 보낸다.
+ */
+pub fn http_get(target: SocketAddr, path: &str, timeout: Duration) -> Option<HttpResponse> {
+    let deadline = Instant::now() + timeout;
+    let mut stream = TcpStream::connect_timeout(&target, timeout).ok()?;
+    stream.set_read_timeout(Some(timeout)).ok()?;
+    stream.set_write_timeout(Some(timeout)).ok()?;
+
+    let request = format!(
+        "GET {} HTTP/1.0\r\nHost: localhost:{}\r\nUser-Agent: open_port_check\r\nAccept: */*\r\nConnection: close\r\n\r\n",
+        path,
+        target.port()
+    );
+    stream.write_all(request.as_bytes()).ok()?;
+
+    let mut raw = Vec::new();
+    let mut buffer = [0_u8; 8 * 1024];
+    while raw.len() < MAX_RESPONSE_BYTES && Instant::now() < deadline {
+        match stream.read(&mut buffer) {
+            Ok(0) => break,
+            Ok(read) => raw.extend_from_slice(&buffer[..read]),
+            // 시간이 지나도 연결을 닫지 않는 서버는 받은 만큼만 사용한다.
+            Err(_) => break,
+        }
+    }
+    raw.truncate(MAX_RESPONSE_BYTES);
+
+    parse_response(&raw)
+}
+
+/**
+ * 루프백 소켓이 HTTP를 사용하는지 확인하고 서버 정보를 모은다.
+ * 외부 주소로는 요청을 보내지 않는다.
+ */
+pub fn fingerprint_http(target: SocketAddr, timeout: Duration) -> Option<HttpFingerprint> {
+    if !target.ip().is_loopback() {
+        return None;
+    }
+
+    let response = http_get(target, "/", timeout)?;
+    let mut framework = guess_framework(&response);
+
+    // Spring Boot는 / 응답으로 알기 어려워 actuator를 한 번 더 확인한다.
+    if framework.is_none() {
+        if let Some(health) = http_get(target, "/actuator/health", timeout) {
+            if health.status == 200 && health.body.contains("\"status\"") {
+                framework = Some("Spring Boot (actuator)".to_string());
+            }
+        }
+    }
+
+    Some(HttpFingerprint {
+        url: format!("http://localhost:{}", target.port()),
+        status: response.status,
+        server: response.header("server").map(|server| server.to_string()),
+        title: extract_title(&response.body),
+        framework,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::TcpListener;
+    use std::thread;
+
+    const TIMEOUT: Duration = Duration::from_millis(500);
+
+    /**
+     * 요청마다 순서대로 응답을 돌려주는 가짜 HTTP 서버
+     */
+    fn fake_http(responses: Vec<&'static str>) -> SocketAddr {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+
+        thread::spawn(move || {
+            for response in responses {
+                let Ok((mut stream, _)) = listener.accept() else {
+                    return;
+                };
+                let mut buffer = [0_u8; 1024];
+                let _ = stream.read(&mut buffer);
+                let _ = stream.write_all(response.as_bytes());
+            }
+        });
+
+        address
+    }
+
+    #[test]
+    fn test_parse_response() {
+        let response = parse_response(
+            b"HTTP/1.1 404 Not Found\r\nServer: uvicorn\r\nContent-Type: text/plain\r\n\r\nnot found",
+        )
+        .unwrap();
+
+        assert_eq!(response.status, 404);
+        assert_eq!(response.header("SERVER"), Some("uvicorn"));
+        assert_eq!(response.body, "not found");
+        assert_eq!(parse_response(b"+PONG\r\n"), None);
+    }
+
+    #[test]
+    fn test_extract_title() {
+        assert_eq!(
+            extract_title("<html><head><TITLE lang=\"en\">\n  My  App\n</TITLE></head>"),
+            Some("My App".to_string())
+        );
+        assert_eq!(extract_title("<title></title>"), None);
+        assert_eq!(extract_title("{\"status\":\"UP\"}"), None);
+    }
+
+    #[test]
+    fn test_guess_framework() {
+        let response = |headers: &[(&str, &str)], body: &str| HttpResponse {
+            status: 200,
+            headers: headers
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.to_string()))
+                .collect(),
+            body: body.to_string(),
+        };
+
+        assert_eq!(
+            guess_framework(&response(
+                &[],
+                "<script type=\"module\" src=\"/@vite/client\"></script>"
+            )),
+            Some("Vite".to_string())
+        );
+        assert_eq!(
+            guess_framework(&response(&[("X-Powered-By", "Next.js")], "")),
+            Some("Next.js".to_string())
+        );
+        assert_eq!(
+            guess_framework(&response(&[("Location", "/tree?")], "")),
+            Some("Jupyter".to_string())
+        );
+        assert_eq!(
+            guess_framework(&response(&[("Server", "WSGIServer/0.2 CPython/3.12")], "")),
+            Some("Django".to_string())
+        );
+        assert_eq!(
+            guess_framework(&response(&[("X-Runtime", "0.01")], "")),
+            Some("Rails".to_string())
+        );
+        assert_eq!(guess_framework(&response(&[("Server", "nginx")], "")), None);
+    }
+
+    #[test]
+    fn test_fingerprint_http_vite() {
+        let address = fake_http(vec![
+            "HTTP/1.1 200 OK\r\nContent-Type: text/html\r\n\r\n<html><head><title>Vite App</title><script type=\"module\" src=\"/@vite/client\"></script></head></html>",
+        ]);
+
+        let fingerprint = fingerprint_http(address, TIMEOUT).unwrap();
+
+        assert_eq!(fingerprint.status, 200);
+        assert_eq!(fingerprint.title, Some("Vite App".to_string()));
+        assert_eq!(fingerprint.framework, Some("Vite".to_string()));
+        assert_eq!(
+            fingerprint.url,
+            format!("http://localhost:{}", address.port())
+        );
+    }
+
+    // / 로 알 수 없으면 actuator를 확인한다
+    #[test]
+    fn test_fingerprint_http_spring_actuator() {
+        let address = fake_http(vec![
+            "HTTP/1.1 404 Not Found\r\nContent-Type: application/json\r\n\r\n{}",
+            "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\n\r\n{\"status\":\"UP\"}",
+        ]);
+
+        let fingerprint = fingerprint_http(address, TIMEOUT).unwrap();
+
+        assert_eq!(fingerprint.status, 404);
+        assert_eq!(
+            fingerprint.framework,
+            Some("Spring Boot (actuator)".to_string())
+        );
+    }
+
+    // HTTP가 아닌 서버
+    #[test]
+    fn test_fingerprint_http_not_http() {
+        let address = fake_http(vec!["SSH-2.0-OpenSSH_9.6\r\n"]);
+
+        assert_eq!(fingerprint_http(address, TIMEOUT), None);
+    }
+
+    // 루프백이 아닌 주소로는 요청하지 않는다
+    #[test]
+    fn test_fingerprint_http_loopback_only() {
+        assert_eq!(
+            fingerprint_http("192.0.2.1:80".parse().unwrap(), TIMEOUT),
+            None
+        );
+    }
+}
@@ -1,17 +1,27 @@
-use crate::traits::command::CommandExecutor;
+use crate::{structs::kill::KillSignal, traits::command::CommandExecutor};
 
 /**
  * kill_process, 명령어에 대한 성공 및 실패에 대한 결과마 추출
  */
 pub fn kill_process(executor: &dyn CommandExecutor, pid: String) -> bool {
+    send_signal(executor, pid, KillSignal::Kill)
+}
+
+/**
+ * 선택한 신호로 프로세스를 종료한다.
+ * 윈도우는 신호가 없으므로 KILL은 강제 종료, 나머지는 정상 종료를 요청한다.
+ */
+pub fn send_signal(executor: &dyn CommandExecutor, pid: String, signal: KillSignal) -> bool {
     if cfg!(target_os = "windows") {
+        let args: &[&str] = match signal {
+            KillSignal::Kill => &["/PID", &pid, "/F"],
+            _ => &["/PID", &pid],
+        };
+        executor.execute_command("taskkill", args).is_ok()
+    } else if cfg!(target_os = "macos") || cfg!(target_os = "linux") {
         executor
-            .execute_command("taskkill", &["/PID", &pid, "/F"])
+            .execute_command("kill", &["-s", signal.name(), &pid])
             .is_ok()
-    } else if cfg!(target_os = "macos") {
-        executor.execute_command("kill", &["-9", &pid]).is_ok()
-    } else if cfg!(target_os = "linux") {
-        executor.execute_command("kill", &["-9", &pid]).is_ok()
     } else {
         false
     }
@@ -76,6 +86,31 @@ mod tests {
         assert_eq!(result, true);
     }
 
+    #[test]
+    #[cfg(not(target_os = "windows"))]
+    fn test_send_signal() {
+        struct SignalExecutor;
+        impl CommandExecutor for SignalExecutor {
+            fn execute_command(&self, command: &str, args: &[&str]) -> Result<String, String> {
+                match (command, args) {
+                    ("kill", ["-s", "TERM", "1234"]) => Ok(String::new()),
+                    _ => Err(PROCESS_ERROR.to_string()),
+                }
+            }
+        }
+
+        assert!(send_signal(
+            &SignalExecutor,
+            TEST_PID.to_string(),
+            KillSignal::Term
+        ));
+        assert!(!send_signal(
+            &SignalExecutor,
+            TEST_PID.to_string(),
+            KillSignal::Hup
+        ));
+    }
+
     #[test]
     fn test_kill_process_failure() {
         let mock_executor = MockKillCommandExecutor;
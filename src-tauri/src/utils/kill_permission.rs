@@ -0,0 +1,551 @@
+use std::collections::HashMap;
+use std::fs;
+
+use crate::{
+    structs::{
+        kill::KillPermission,
+        port::{PortInfo, ProcessPatch},
+    },
+    traits::command::CommandExecutor,
+    utils::{coverage::is_elevated, procfs::UNKNOWN_PID},
+};
+
+// capability(7)의 CAP_KILL 비트
+const CAP_KILL: u32 = 5;
+
+// 윈도우 무결성 수준 SID(S-1-16-<RID>)의 RID, High 이상은 관리자 권한이다.
+const HIGH_INTEGRITY_RID: u32 = 0x3000;
+const SYSTEM_INTEGRITY_RID: u32 = 0x4000;
+
+/**
+ * 프로세스 토큰의 무결성 수준 SID를 "<pid>,<SID>" 형식으로 출력한다.
+ * 토큰을 열 수 없는 프로세스는 빈 값이다.
+ */
+const INTEGRITY_SCRIPT: &str = r#"Add-Type -TypeDefinition @'
+using System;
+using System.Runtime.InteropServices;
+using System.Security.Principal;
+public static class OpenPortCheckIntegrity {
+    [DllImport("kernel32.dll")] static extern IntPtr OpenProcess(uint access, bool inherit, int pid);
+    [DllImport("advapi32.dll")] static extern bool OpenProcessToken(IntPtr process, uint access, out IntPtr token);
+    [DllImport("advapi32.dll")] static extern bool GetTokenInformation(IntPtr token, int infoClass, IntPtr info, int length, out int returned);
+    [DllImport("kernel32.dll")] static extern bool CloseHandle(IntPtr handle);
+    public static string Level(int pid) {
+        IntPtr process = OpenProcess(0x1000, false, pid);
+        if (process == IntPtr.Zero) return "";
+        IntPtr token;
+        string level = "";
+        if (OpenProcessToken(process, 0x8, out token)) {
+            int length;
+            GetTokenInformation(token, 25, IntPtr.Zero, 0, out length);
+            IntPtr info = Marshal.AllocHGlobal(length);
+            if (GetTokenInformation(token, 25, info, length, out length)) {
+                level = new SecurityIdentifier(Marshal.ReadIntPtr(info)).Value;
+            }
+            Marshal.FreeHGlobal(info);
+            CloseHandle(token);
+        }
+        CloseHandle(process);
+        return level;
+    }
+}
+'@
+"#;
+
+/**
+ * 신호를 보내는 쪽(현재 프로세스)의 권한
+ */
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Identity {
+    // 유닉스의 real, effective uid
+    pub uids: Vec<String>,
+    // 윈도우의 DOMAIN\user
+    pub user: Option<String>,
+    // root, CAP_KILL 혹은 관리자 권한(High integrity)
+    pub privileged: bool,
+}
+
+/**
+ * 신호를 받는 쪽 프로세스의 소유자
+ */
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ProcessOwner {
+    // 유닉스의 real, saved uid
+    pub uids: Vec<String>,
+    pub user: Option<String>,
+    // 윈도우 무결성 수준의 RID, 토큰을 열 수 없으면 None
+    pub integrity: Option<u32>,
+}
+
+/**
+ * /proc/<pid>/status의 Uid 줄 (real, effective, saved, filesystem)
+ */
+pub fn parse_status_uids(status: &str) -> Option<Vec<String>> {
+    let line = status.lines().find_map(|line| line.strip_prefix("Uid:"))?;
+    let uids: Vec<String> = line.split_whitespace().map(|uid| uid.to_string()).collect();
+    (uids.len() == 4).then_some(uids)
+}
+
+/**
+ * /proc/<pid>/status의 CapEff에 CAP_KILL이 있는지
+ */
+pub fn has_cap_kill(status: &str) -> bool {
+    status
+        .lines()
+        .find_map(|line| line.strip_prefix("CapEff:"))
+        .and_then(|caps| u64::from_str_radix(caps.trim(), 16).ok())
+        .map(|caps| caps & (1 << CAP_KILL) != 0)
+        .unwrap_or(false)
+}
+
+/**
+ * ps -o pid=,ruid=,svuid= 결과
+ *
+ * ex)
+ *  1234   501   501
+ */
+pub fn parse_ps_uids(output: &str) -> HashMap<String, ProcessOwner> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let columns: Vec<&str> = line.split_whitespace().collect();
+            if columns.len() != 3 {
+                return None;
+            }
+
+            Some((
+                columns[0].to_string(),
+                ProcessOwner {
+                    uids: vec![columns[1].to_string(), columns[2].to_string()],
+                    user: None,
+                    integrity: None,
+                },
+            ))
+        })
+        .collect()
+}
+
+/**
+ * INTEGRITY_SCRIPT 결과로 pid => 무결성 수준 RID
+ *
+ * ex)
+ * 1234,S-1-16-8192
+ * 4321,S-1-16-12288
+ * 88,
+ */
+pub fn parse_integrity_levels(output: &str) -> HashMap<String, u32> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let (pid, sid) = line.trim().split_once(',')?;
+            let rid = sid.strip_prefix("S-1-16-")?.parse::<u32>().ok()?;
+            Some((pid.to_string(), rid))
+        })
+        .collect()
+}
+
+/**
+ * kill(2)의 규칙을 따른다.
+ * 보내는 쪽의 real 혹은 effective uid가 받는 쪽의 real 혹은 saved uid와 같아야 한다.
+ * 윈도우는 관리자 권한이면 모두 허용하고, 다른 사용자의 프로세스는 막는다.
+ * 같은 사용자라도 관리자 권한(High integrity) 이상으로 실행된 프로세스는 종료할 수 없으므로
+ * 대상의 무결성 수준을 보고, 알 수 없다면 Unknown으로 둔다.
+ */
+pub fn kill_permission(
+    identity: &Identity,
+    pid: &str,
+    owner: Option<&ProcessOwner>,
+) -> KillPermission {
+    let denied = |reason: String| KillPermission::Denied { reason };
+
+    if pid.is_empty() || pid == UNKNOWN_PID {
+        return denied("owner process is unknown".to_string());
+    }
+
+    // init, 윈도우의 System Idle Process, System
+    let system_pids: &[&str] = if cfg!(target_os = "windows") {
+        &["0", "4"]
+    } else {
+        &["0", "1"]
+    };
+    if system_pids.contains(&pid) {
+        return denied("system process".to_string());
+    }
+
+    if identity.privileged {
+        return KillPermission::Allowed;
+    }
+
+    let requirement = if cfg!(target_os = "windows") {
+        "requires administrator"
+    } else {
+        "requires root"
+    };
+
+    let Some(owner) = owner else {
+        let reason = format!("owner of pid {} is not visible — {}", pid, requirement);
+        return if cfg!(target_os = "windows") {
+            KillPermission::Unknown { reason }
+        } else {
+            denied(reason)
+        };
+    };
+
+    let same_user = match (&identity.user, &owner.user) {
+        (Some(user), Some(owner)) => user.eq_ignore_ascii_case(owner),
+        _ => false,
+    };
+    let same_uid = owner.uids.iter().any(|uid| identity.uids.contains(uid));
+
+    // tasklist는 관리자 권한으로 실행된 같은 사용자의 프로세스도 N/A로 보여준다.
+    if cfg!(target_os = "windows") && (same_user || owner.user.is_none()) {
+        return match owner.integrity {
+            Some(level) if level >= SYSTEM_INTEGRITY_RID => {
+                denied(format!("runs as system — {}", requirement))
+            }
+            Some(level) if level >= HIGH_INTEGRITY_RID => {
+                denied(format!("runs as administrator — {}", requirement))
+            }
+            Some(_) if same_user => KillPermission::Allowed,
+            _ => KillPermission::Unknown {
+                reason: format!("integrity level of pid {} is not visible", pid),
+            },
+        };
+    }
+
+    if same_user || same_uid {
+        return KillPermission::Allowed;
+    }
+
+    let owner_name = owner
+        .user
+        .clone()
+        .or_else(|| owner.uids.first().map(|uid| format!("uid {}", uid)))
+        .unwrap_or("another user".to_string());
+    denied(format!("owned by {} — {}", owner_name, requirement))
+}
+
+fn current_identity(executor: &dyn CommandExecutor) -> Identity {
+    if cfg!(target_os = "windows") {
+        Identity {
+            uids: Vec::new(),
+            user: executor
+                .execute_command("whoami", &[])
+                .ok()
+                .map(|user| user.trim().to_string())
+                .filter(|user| !user.is_empty()),
+            privileged: is_elevated(executor),
+        }
+    } else if cfg!(target_os = "linux") {
+        let status = fs::read_to_string("/proc/self/status").unwrap_or_default();
+        let uids = parse_status_uids(&status).unwrap_or_default();
+        Identity {
+            privileged: uids.get(1).map(|uid| uid == "0").unwrap_or(false) || has_cap_kill(&status),
+            uids: uids.into_iter().take(2).collect(),
+            user: None,
+        }
+    } else {
+        let id = |args: &[&str]| {
+            executor
+                .execute_command("id", args)
+                .ok()
+                .map(|uid| uid.trim().to_string())
+                .filter(|uid| !uid.is_empty())
+        };
+        let uids: Vec<String> = [id(&["-r", "-u"]), id(&["-u"])]
+            .into_iter()
+            .flatten()
+            .collect();
+
+        Identity {
+            privileged: uids.last().map(|uid| uid == "0").unwrap_or(false),
+            uids,
+            user: None,
+        }
+    }
+}
+
+fn process_owners(
+    executor: &dyn CommandExecutor,
+    rows: &[PortInfo],
+    pids: &[&str],
+) -> HashMap<String, ProcessOwner> {
+    if cfg!(target_os = "windows") {
+        let script = format!(
+            "{}{} | ForEach-Object {{ '{{0}},{{1}}' -f $_, [OpenPortCheckIntegrity]::Level($_) }}",
+            INTEGRITY_SCRIPT,
+            pids.join(",")
+        );
+        let integrity = executor
+            .execute_command(
+                "powershell",
+                &["-NoProfile", "-NonInteractive", "-Command", &script],
+            )
+            .map(|output| parse_integrity_levels(&output))
+            .unwrap_or_default();
+
+        // tasklist가 알려준 사용자, 조회할 수 없는 프로세스는 N/A(None)였다.
+        pids.iter()
+            .filter_map(|pid| {
+                let user = rows
+                    .iter()
+                    .find(|row| row.pid == *pid)
+                    .and_then(|row| row.user.clone());
+                let integrity = integrity.get(*pid).copied();
+                (user.is_some() || integrity.is_some()).then(|| {
+                    (
+                        pid.to_string(),
+                        ProcessOwner {
+                            uids: Vec::new(),
+                            user,
+                            integrity,
+                        },
+                    )
+                })
+            })
+            .collect()
+    } else if cfg!(target_os = "linux") {
+        pids.iter()
+            .filter_map(|pid| {
+                let status = fs::read_to_string(format!("/proc/{}/status", pid)).ok()?;
+                let uids = parse_status_uids(&status)?;
+                let user = rows
+                    .iter()
+                    .find(|row| row.pid == *pid)
+                    .and_then(|row| row.user.clone());
+
+                Some((
+                    pid.to_string(),
+                    ProcessOwner {
+                        uids: vec![uids[0].clone(), uids[2].clone()],
+                        user,
+                        integrity: None,
+                    },
+                ))
+            })
+            .collect()
+    } else {
+        let mut owners = executor
+            .execute_command("ps", &["-o", "pid=,ruid=,svuid=", "-p", &pids.join(",")])
+            .map(|output| parse_ps_uids(&output))
+            .unwrap_or_default();
+
+        for (pid, owner) in owners.iter_mut() {
+            owner.user = rows
+                .iter()
+                .find(|row| row.pid == *pid)
+                .and_then(|row| row.user.clone());
+        }
+        owners
+    }
+}
+
+/**
+ * 목록의 모든 pid에 대해 종료할 수 있는지 미리 확인한다.
+ * 사용자 정보가 필요하므로 프로세스 조회 단계가 끝난 뒤 호출한다.
+ */
+pub fn resolve_kill_permissions(
+    executor: &dyn CommandExecutor,
+    rows: &[PortInfo],
+) -> Vec<ProcessPatch> {
+    let mut pids: Vec<&str> = rows
+        .iter()
+        .map(|row| row.pid.as_str())
+        .filter(|pid| !pid.is_empty() && *pid != UNKNOWN_PID)
+        .collect();
+    pids.sort();
+    pids.dedup();
+
+    if pids.is_empty() {
+        return Vec::new();
+    }
+
+    let identity = current_identity(executor);
+    let owners = process_owners(executor, rows, &pids);
+
+    pids.into_iter()
+        .map(|pid| ProcessPatch {
+            pid: pid.to_string(),
+            kill_permission: Some(kill_permission(&identity, pid, owners.get(pid))),
+            ..ProcessPatch::default()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STATUS: &str = "\
+Name:\tnode
+Uid:\t1000\t1000\t1000\t1000
+Gid:\t1000\t1000\t1000\t1000
+CapEff:\t0000000000000000
+";
+
+    fn identity(uids: &[&str], privileged: bool) -> Identity {
+        Identity {
+            uids: uids.iter().map(|uid| uid.to_string()).collect(),
+            user: None,
+            privileged,
+        }
+    }
+
+    fn owner(uids: &[&str], user: Option<&str>) -> ProcessOwner {
+        ProcessOwner {
+            uids: uids.iter().map(|uid| uid.to_string()).collect(),
+            user: user.map(|user| user.to_string()),
+            integrity: None,
+        }
+    }
+
+    #[test]
+    fn test_parse_status() {
+        assert_eq!(
+            parse_status_uids(STATUS),
+            Some(vec![
+                "1000".to_string(),
+                "1000".to_string(),
+                "1000".to_string(),
+                "1000".to_string()
+            ])
+        );
+        assert!(!has_cap_kill(STATUS));
+        assert!(has_cap_kill("CapEff:\t0000000000000020\n"));
+        assert!(has_cap_kill("CapEff:\t000001ffffffffff\n"));
+    }
+
+    #[test]
+    fn test_parse_ps_uids() {
+        let owners = parse_ps_uids("  1234   501   501\n    88     0     0\n");
+
+        assert_eq!(owners.get("1234"), Some(&owner(&["501", "501"], None)));
+        assert_eq!(owners.get("88"), Some(&owner(&["0", "0"], None)));
+    }
+
+    #[test]
+    fn test_parse_integrity_levels() {
+        let levels =
+            parse_integrity_levels("1234,S-1-16-8192\r\n4321,S-1-16-12288\r\n88,\r\nnoise\r\n");
+
+        assert_eq!(levels.get("1234"), Some(&0x2000));
+        assert_eq!(levels.get("4321"), Some(&0x3000));
+        assert_eq!(levels.get("88"), None);
+        assert_eq!(levels.len(), 2);
+    }
+
+    #[test]
+    #[cfg(not(target_os = "windows"))]
+    fn test_kill_permission() {
+        let user = identity(&["1000", "1000"], false);
+
+        assert_eq!(
+            kill_permission(&user, "4321", Some(&owner(&["1000", "1000"], Some("user")))),
+            KillPermission::Allowed
+        );
+        // setuid로 effective uid만 다른 프로세스도 real uid가 같으면 종료할 수 있다.
+        assert_eq!(
+            kill_permission(&user, "4321", Some(&owner(&["1000", "0"], None))),
+            KillPermission::Allowed
+        );
+        assert_eq!(
+            kill_permission(&user, "88", Some(&owner(&["0", "0"], Some("root")))),
+            KillPermission::Denied {
+                reason: "owned by root — requires root".to_string()
+            }
+        );
+        assert!(matches!(
+            kill_permission(&user, UNKNOWN_PID, None),
+            KillPermission::Denied { .. }
+        ));
+        assert!(matches!(
+            kill_permission(&user, "4321", None),
+            KillPermission::Denied { .. }
+        ));
+
+        let root = identity(&["0", "0"], true);
+        assert_eq!(
+            kill_permission(&root, "88", Some(&owner(&["1000", "1000"], None))),
+            KillPermission::Allowed
+        );
+        assert!(matches!(
+            kill_permission(&root, "1", None),
+            KillPermission::Denied { .. }
+        ));
+    }
+
+    #[test]
+    #[cfg(target_os = "windows")]
+    fn test_kill_permission_windows() {
+        let user = Identity {
+            uids: Vec::new(),
+            user: Some("DESKTOP\\user".to_string()),
+            privileged: false,
+        };
+
+        let same_user = |integrity: Option<u32>| ProcessOwner {
+            integrity,
+            ..owner(&[], Some("desktop\\USER"))
+        };
+
+        // Medium 무결성의 같은 사용자 프로세스는 종료할 수 있다.
+        assert_eq!(
+            kill_permission(&user, "1234", Some(&same_user(Some(0x2000)))),
+            KillPermission::Allowed
+        );
+        // 관리자 권한으로 실행된 같은 사용자의 프로세스
+        assert_eq!(
+            kill_permission(&user, "1234", Some(&same_user(Some(0x3000)))),
+            KillPermission::Denied {
+                reason: "runs as administrator — requires administrator".to_string()
+            }
+        );
+        // tasklist가 사용자를 보여주지 않아도 무결성 수준으로 판단한다.
+        assert_eq!(
+            kill_permission(
+                &user,
+                "1234",
+                Some(&ProcessOwner {
+                    integrity: Some(0x4000),
+                    ..owner(&[], None)
+                })
+            ),
+            KillPermission::Denied {
+                reason: "runs as system — requires administrator".to_string()
+            }
+        );
+        assert!(matches!(
+            kill_permission(&user, "1234", Some(&same_user(None))),
+            KillPermission::Unknown { .. }
+        ));
+        assert!(matches!(
+            kill_permission(&user, "1234", None),
+            KillPermission::Unknown { .. }
+        ));
+        assert!(matches!(
+            kill_permission(
+                &user,
+                "1234",
+                Some(&owner(&[], Some("NT AUTHORITY\\SYSTEM")))
+            ),
+            KillPermission::Denied { .. }
+        ));
+
+        let admin = Identity {
+            privileged: true,
+            ..user.clone()
+        };
+        assert_eq!(
+            kill_permission(
+                &admin,
+                "1234",
+                Some(&owner(&[], Some("NT AUTHORITY\\SYSTEM")))
+            ),
+            KillPermission::Allowed
+        );
+        assert!(matches!(
+            kill_permission(&user, "4", None),
+            KillPermission::Denied { .. }
+        ));
+    }
+}
@@ -1,6 +1,22 @@
-use std::process::Command;
-
-use crate::{enums::os::OS, traits::command};
+use std::fs;
+use std::path::Path;
+
+use crate::{
+    enums::{error::CommandError, os::OS},
+    structs::port::{PortInfo, ProcessPatch, ScanEvent},
+    traits::command,
+    utils::{
+        activity::{apply_connections, count_connections, resolve_start_times},
+        cgroup::resolve_cgroups,
+        coverage::{is_elevated, parse_lsof_warnings, scan_coverage},
+        docker::{apply_container_patches, resolve_containers, DockerClient},
+        kill_permission::resolve_kill_permissions,
+        procfs::list_listeners,
+        remove_duplicate::remove_duplicates,
+        reservation::mark_reserved,
+        service::system_labeler,
+    },
+};
 
 /**
  * 열려있는 Port 확인
@@ -14,7 +30,11 @@ pub fn get_open_ports(executor: &dyn command::CommandExecutor) -> OS {
             Err(_) => OS::Unsupported,
         }
     } else if cfg!(target_os = "macos") {
-        let result = executor.execute_command("sh", &["-c", "lsof -i -P -n | grep LISTEN"]);
+        // 권한 경고(표준 에러)도 함께 받아 조회 결과가 빠짐없는지 판단한다.
+        let result = executor.execute_command(
+            "sh",
+            &["-c", "lsof -i -P -n 2>&1 | grep -E 'LISTEN|WARNING'"],
+        );
         match result {
             Ok(output) => OS::MacOS(output),
             Err(_) => OS::Unsupported,
@@ -25,12 +45,209 @@ pub fn get_open_ports(executor: &dyn command::CommandExecutor) -> OS {
 }
 
 /**
- * 윈도우의 명령어의 결과를 파싱하혀 (Process, Port, Pid)의 튜플로 나타낸다.
+ * 열려있는 Port를 단계별로 조회하며, 단계가 끝날 때마다 emit으로 결과를 전달한다.
+ *
+ * 1. 소켓 목록 조회, 중복 제거 및 정렬, 서비스 이름 및 예약 표기 => ScanEvent::Rows
+ *    리눅스는 명령어 대신 /proc을 직접 읽는다.
+ * 2. 프로세스 이름, 사용자 조회 => ScanEvent::Patch
+ *    리눅스라면 cgroup으로 systemd 서비스, 컨테이너 조회 => ScanEvent::Patch
+ *    pid마다 현재 사용자가 종료할 수 있는지 확인 => ScanEvent::Patch
+ *    pid마다 시작 시각 조회 => ScanEvent::Patch
+ *    포트마다 맺어진 연결 수 => ScanEvent::Connections
+ * 3. 권한 부족으로 빠지거나 소유자를 모르는 소켓 확인 => ScanEvent::Coverage
+ * 4. Docker 프로세스가 점유한 포트의 컨테이너 조회 => ScanEvent::Containers
+ * 5. 종료 => ScanEvent::Finished
+ */
+pub fn stream_open_ports(
+    executor: &dyn command::CommandExecutor,
+    emit: &mut dyn FnMut(ScanEvent),
+) -> Result<(), CommandError> {
+    let mut warnings = Vec::new();
+    let mut unreadable_processes = 0;
+
+    let rows = match get_open_ports(executor) {
+        OS::MacOS(output) => {
+            warnings = parse_lsof_warnings(&output);
+            parsing_mac_lsof(&output)
+        }
+        OS::Windows(output) => parsing_window_netstat(&output),
+        OS::Unsupported if cfg!(target_os = "linux") => {
+            let passwd = fs::read_to_string("/etc/passwd").unwrap_or_default();
+            let listing = list_listeners(Path::new("/proc"), &passwd);
+            unreadable_processes = listing.unreadable_processes;
+            listing.rows
+        }
+        OS::Unsupported => return Err(CommandError::UnsupportedOS),
+    };
+
+    let mut rows = remove_duplicates(rows);
+    sort_rows(&mut rows);
+    system_labeler().apply(&mut rows);
+    mark_reserved(&mut rows, std::process::id());
+    emit(ScanEvent::Rows(rows.clone()));
+
+    let patches = resolve_processes(executor, &rows);
+    if !patches.is_empty() {
+        // 컨테이너 매칭은 전체 프로세스 이름이 필요하다.
+        apply_patches(&mut rows, &patches);
+        emit(ScanEvent::Patch(patches));
+    }
+
+    let cgroups = resolve_cgroups(executor, &rows);
+    if !cgroups.is_empty() {
+        apply_patches(&mut rows, &cgroups);
+        emit(ScanEvent::Patch(cgroups));
+    }
+
+    // 윈도우는 tasklist의 사용자로 판단하므로 프로세스 조회 뒤에 확인한다.
+    let permissions = resolve_kill_permissions(executor, &rows);
+    if !permissions.is_empty() {
+        apply_patches(&mut rows, &permissions);
+        emit(ScanEvent::Patch(permissions));
+    }
+
+    let started = resolve_start_times(executor, &rows);
+    if !started.is_empty() {
+        apply_patches(&mut rows, &started);
+        emit(ScanEvent::Patch(started));
+    }
+
+    emit(ScanEvent::Connections(count_connections(executor)));
+
+    emit(ScanEvent::Coverage(scan_coverage(
+        &rows,
+        is_elevated(executor),
+        unreadable_processes,
+        warnings,
+    )));
+
+    let containers = resolve_containers(&DockerClient::from_env(), &rows);
+    if !containers.is_empty() {
+        emit(ScanEvent::Containers(containers));
+    }
+
+    emit(ScanEvent::Finished);
+    Ok(())
+}
+
+/**
+ * 조회할 때마다 같은 순서가 되도록 (port, protocol, address, pid) 순으로 정렬한다.
+ * 같은 소켓을 가진 프로세스들이 이웃하게 된다.
+ */
+pub fn sort_rows(rows: &mut [PortInfo]) {
+    // 숫자가 아닌 값(ex. "*")은 뒤로 보내고 문자열로 비교한다.
+    fn numeric(value: &str) -> (u64, &str) {
+        (value.parse::<u64>().unwrap_or(u64::MAX), value)
+    }
+
+    rows.sort_by(|a, b| {
+        numeric(&a.port)
+            .cmp(&numeric(&b.port))
+            .then_with(|| a.protocol.cmp(&b.protocol))
+            .then_with(|| a.address.cmp(&b.address))
+            .then_with(|| numeric(&a.pid).cmp(&numeric(&b.pid)))
+            .then_with(|| a.process_name.cmp(&b.process_name))
+    });
+}
+
+/**
+ * 열려있는 Port를 조회하고 모든 단계가 끝난 결과를 돌려준다.
+ */
+pub fn scan_open_ports(
+    executor: &dyn command::CommandExecutor,
+) -> Result<Vec<PortInfo>, CommandError> {
+    let mut rows = Vec::new();
+
+    stream_open_ports(executor, &mut |event| match event {
+        ScanEvent::Rows(new_rows) => rows = new_rows,
+        ScanEvent::Patch(patches) => apply_patches(&mut rows, &patches),
+        ScanEvent::Containers(patches) => apply_container_patches(&mut rows, &patches),
+        ScanEvent::Connections(connections) => apply_connections(&mut rows, &connections),
+        ScanEvent::Coverage(_) | ScanEvent::Finished => {}
+    })?;
+
+    Ok(rows)
+}
+
+/**
+ * pid가 같은 모든 행에 프로세스 정보를 덮어쓴다.
+ */
+pub fn apply_patches(rows: &mut [PortInfo], patches: &[ProcessPatch]) {
+    for patch in patches {
+        for row in rows.iter_mut().filter(|row| row.pid == patch.pid) {
+            if let Some(process_name) = &patch.process_name {
+                row.process_name = process_name.clone();
+            }
+            if let Some(user) = &patch.user {
+                row.user = Some(user.clone());
+            }
+            if let Some(cgroup) = &patch.cgroup {
+                row.cgroup = Some(cgroup.clone());
+            }
+            if let Some(kill_permission) = &patch.kill_permission {
+                row.kill_permission = Some(kill_permission.clone());
+            }
+            if let Some(started_at) = patch.started_at {
+                row.started_at = Some(started_at);
+            }
+        }
+    }
+}
+
+/**
+ * 소켓 목록에 있는 pid들의 프로세스 이름과 사용자를 조회한다.
+ * 실패하더라도 목록은 이미 전달되었으므로 빈 결과를 돌려준다.
+ */
+pub fn resolve_processes(
+    executor: &dyn command::CommandExecutor,
+    rows: &[PortInfo],
+) -> Vec<ProcessPatch> {
+    if rows.is_empty() {
+        return Vec::new();
+    }
+
+    if cfg!(target_os = "windows") {
+        executor
+            .execute_command("tasklist", &["/V", "/FO", "CSV", "/NH"])
+            .map(|output| parsing_window_tasklist(&output))
+            .unwrap_or_default()
+    } else if cfg!(target_os = "macos") {
+        let mut pids: Vec<&str> = rows.iter().map(|row| row.pid.as_str()).collect();
+        pids.sort();
+        pids.dedup();
+
+        let pids = pids.join(",");
+        executor
+            .execute_command("ps", &["-o", "pid=,user=,comm=", "-p", &pids])
+            .map(|output| parsing_mac_ps(&output))
+            .unwrap_or_default()
+    } else {
+        Vec::new()
+    }
+}
+
+/**
+ * "[::1]" 처럼 대괄호로 감싼 IPv6 주소를 벗겨낸다.
+ */
+fn split_address(local_address: &str) -> Option<(String, String)> {
+    let pos = local_address.rfind(':')?;
+    let address = local_address[..pos]
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .to_string();
+    let port = local_address[pos + 1..].to_string();
+
+    Some((address, port))
+}
+
+/**
+ * 윈도우의 명령어의 결과를 파싱하혀 PortInfo로 나타낸다.
+ * 프로세스 이름은 netstat 결과에 없으므로 resolve_processes 단계에서 채운다.
  *
  * Proto  Local Address          Foreign Address        State           PID
  * TCP    127.0.0.1:3000         0.0.0.0:0              LISTENING       1234
  */
-pub fn parsing_window_netstat(output: &str) -> Vec<(String, String, String)> {
+pub fn parsing_window_netstat(output: &str) -> Vec<PortInfo> {
     output
         .lines()
         .skip(4)
@@ -38,73 +255,114 @@ pub fn parsing_window_netstat(output: &str) -> Vec<(String, String, String)> {
             let columns: Vec<&str> = line.split_whitespace().collect();
 
             if columns.len() >= 5 && columns[3] == "LISTENING" {
-                let local_address = columns[1].to_string();
-                let pid = columns[4].to_string();
-
-                if let Some(pos) = local_address.rfind(':') {
-                    let port = &local_address[pos + 1..];
-
-                    let process_name =
-                        window_get_process_name(&pid).unwrap_or_else(|| "Unknown".to_string());
-
-                    return Some((process_name, port.to_string(), pid));
-                }
+                let (address, port) = split_address(columns[1])?;
+
+                return Some(PortInfo {
+                    process_name: "Unknown".to_string(),
+                    port,
+                    pid: columns[4].to_string(),
+                    protocol: columns[0].to_string(),
+                    address,
+                    user: None,
+                    ..PortInfo::default()
+                });
             }
             None
         })
         .collect()
 }
 
-pub fn window_get_process_name(pid: &str) -> Option<String> {
-    let output = Command::new("tasklist")
-        .arg("/FI")
-        .arg(format!("PID eq {}", pid))
-        .output()
-        .expect("failed to execute tasklist");
-
-    let result = String::from_utf8_lossy(&output.stdout);
+/**
+ * tasklist /V /FO CSV /NH 결과에서 프로세스 이름, pid, 사용자를 추출한다.
+ *
+ * "chrome.exe","1234","Console","1","120,000 K","Running","DESKTOP\user","0:00:01","N/A"
+ */
+pub fn parsing_window_tasklist(output: &str) -> Vec<ProcessPatch> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let line = line.trim().strip_prefix('"')?.strip_suffix('"')?;
+            let columns: Vec<&str> = line.split("\",\"").collect();
 
-    // tasklist 결과에서 프로세스 이름 파싱
-    for line in result.lines().skip(3) {
-        let columns: Vec<&str> = line.split_whitespace().collect();
-        if !columns.is_empty() && columns[1] == pid {
-            return Some(columns[0].to_string()); // 프로세스 이름
-        }
-    }
+            if columns.len() < 7 {
+                return None;
+            }
 
-    None
+            let user = match columns[6] {
+                "N/A" => None,
+                user => Some(user.to_string()),
+            };
+
+            Some(ProcessPatch {
+                pid: columns[1].to_string(),
+                process_name: Some(columns[0].to_string()),
+                user,
+                ..ProcessPatch::default()
+            })
+        })
+        .collect()
 }
 
 /**
- * 맥의 lsof의 결과를 파싱하여 (Process, Port, Pid)의 튜플로 나타낸다
+ * 맥의 lsof의 결과를 파싱하여 PortInfo로 나타낸다
+ * grep을 거친 결과이므로 헤더가 없을 수 있어, 컬럼 수로 헤더를 걸러낸다.
  *
  * ex)
  * COMMAND   PID   USER   FD   TYPE    DEVICE SIZE/OFF NODE NAME
  * firefox   1234  user   45u  IPv4 0x1a2b3c 0t0      TCP 127.0.0.1:3000 (LISTEN)
  */
-pub fn parsing_mac_lsof(output: &str) -> Vec<(String, String, String)> {
+pub fn parsing_mac_lsof(output: &str) -> Vec<PortInfo> {
     output
         .lines()
-        .skip(1)
         .filter_map(|line| {
             let columns: Vec<&str> = line.split_whitespace().collect();
 
             if columns.len() >= 10 && columns[9].contains("LISTEN") {
-                let process = columns[0].to_string();
-                let pid = columns[1].to_string();
-                let port_info = columns[8];
-
                 // 포트 번호는 ":" 이후에 있음
-                if let Some(pos) = port_info.rfind(':') {
-                    let port = &port_info[pos + 1..];
-                    return Some((process, port.to_string(), pid));
-                }
+                let (address, port) = split_address(columns[8])?;
+
+                return Some(PortInfo {
+                    process_name: columns[0].to_string(),
+                    port,
+                    pid: columns[1].to_string(),
+                    protocol: columns[7].to_string(),
+                    address,
+                    user: Some(columns[2].to_string()),
+                    ..PortInfo::default()
+                });
             }
             None
         })
         .collect()
 }
 
+/**
+ * ps -o pid=,user=,comm= 결과에서 프로세스 정보를 추출한다.
+ * lsof의 COMMAND는 9글자로 잘리므로 전체 이름으로 덮어쓴다.
+ *
+ * ex)
+ *  1234 user /Applications/Firefox.app/Contents/MacOS/firefox
+ */
+pub fn parsing_mac_ps(output: &str) -> Vec<ProcessPatch> {
+    output
+        .lines()
+        .filter_map(|line| {
+            // 실행 경로에 공백이 있을 수 있으므로 앞의 두 컬럼만 나눈다.
+            let (pid, rest) = line.trim().split_once(char::is_whitespace)?;
+            let (user, command) = rest.trim_start().split_once(char::is_whitespace)?;
+            let command = command.trim();
+            let process_name = command.rsplit('/').next().unwrap_or(command);
+
+            Some(ProcessPatch {
+                pid: pid.to_string(),
+                process_name: Some(process_name.to_string()),
+                user: Some(user.to_string()),
+                ..ProcessPatch::default()
+            })
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -185,12 +443,8 @@ chrome    5678  user   48u  IPv4 0x4d5e6f 0t0      TCP 127.0.0.1:8080 (LISTEN)
 ";
 
         let expected_result = vec![
-            (
-                "firefox".to_string(),
-                "3000".to_string(),
-                "1234".to_string(),
-            ),
-            ("chrome".to_string(), "8080".to_string(), "5678".to_string()),
+            port_info("firefox", "3000", "1234", Some("user")),
+            port_info("chrome", "8080", "5678", Some("user")),
         ];
 
         let result = parsing_mac_lsof(lsof_output);
@@ -202,9 +456,155 @@ chrome    5678  user   48u  IPv4 0x4d5e6f 0t0      TCP 127.0.0.1:8080 (LISTEN)
     #[test]
     fn test_parsing_mac_lsof_with_empty_output() {
         let empty_output = "";
-        let expected_result: Vec<(String, String, String)> = vec![];
+        let expected_result: Vec<PortInfo> = vec![];
         let result = parsing_mac_lsof(empty_output);
 
         assert_eq!(result, expected_result);
     }
+
+    // grep으로 헤더가 빠진 결과와 IPv6 주소
+    #[test]
+    fn test_parsing_mac_lsof_without_header() {
+        let lsof_output = "\
+node      4321  user   23u  IPv6 0x7a8b9c 0t0      TCP [::1]:5173 (LISTEN)
+";
+
+        let result = parsing_mac_lsof(lsof_output);
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].address, "::1");
+        assert_eq!(result[0].port, "5173");
+        assert_eq!(result[0].pid, "4321");
+    }
+
+    #[test]
+    fn test_parsing_window_netstat() {
+        let netstat_output = "
+Active Connections
+
+  Proto  Local Address          Foreign Address        State           PID
+  TCP    0.0.0.0:135            0.0.0.0:0              LISTENING       1012
+  TCP    127.0.0.1:3000         127.0.0.1:52000        ESTABLISHED     1234
+  TCP    [::]:445               [::]:0                 LISTENING       4
+  UDP    0.0.0.0:5353           *:*                                    2200
+";
+
+        let result = parsing_window_netstat(netstat_output);
+
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].port, "135");
+        assert_eq!(result[0].address, "0.0.0.0");
+        assert_eq!(result[0].process_name, "Unknown");
+        assert_eq!(result[1].address, "::");
+        assert_eq!(result[1].pid, "4");
+    }
+
+    #[test]
+    fn test_parsing_window_tasklist() {
+        let tasklist_output = "\
+\"System\",\"4\",\"Services\",\"0\",\"144 K\",\"Unknown\",\"N/A\",\"0:10:00\",\"N/A\"
+\"node.exe\",\"1234\",\"Console\",\"1\",\"40,120 K\",\"Running\",\"DESKTOP\\user\",\"0:00:03\",\"N/A\"
+";
+
+        let result = parsing_window_tasklist(tasklist_output);
+
+        assert_eq!(
+            result,
+            vec![
+                ProcessPatch {
+                    pid: "4".to_string(),
+                    process_name: Some("System".to_string()),
+                    user: None,
+                    ..ProcessPatch::default()
+                },
+                ProcessPatch {
+                    pid: "1234".to_string(),
+                    process_name: Some("node.exe".to_string()),
+                    user: Some("DESKTOP\\user".to_string()),
+                    ..ProcessPatch::default()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parsing_mac_ps() {
+        let ps_output = "\
+ 1234 user     /Applications/Google Chrome.app/Contents/MacOS/Google Chrome Helper
+   88 _mdnsresponder /usr/sbin/mDNSResponder
+";
+
+        let result = parsing_mac_ps(ps_output);
+
+        assert_eq!(result.len(), 2);
+        assert_eq!(
+            result[0].process_name,
+            Some("Google Chrome Helper".to_string())
+        );
+        assert_eq!(result[1].pid, "88");
+        assert_eq!(result[1].user, Some("_mdnsresponder".to_string()));
+    }
+
+    // 포트는 숫자 순서, 같은 소켓의 프로세스는 pid 순서
+    #[test]
+    fn test_sort_rows() {
+        let mut rows = vec![
+            port_info("worker", "8080", "310", None),
+            port_info("vite", "5173", "400", None),
+            port_info("Unknown", "*", "1", None),
+            port_info("worker", "8080", "42", None),
+            port_info("node", "443", "500", None),
+        ];
+
+        sort_rows(&mut rows);
+
+        let order: Vec<(String, String)> =
+            rows.into_iter().map(|row| (row.port, row.pid)).collect();
+        assert_eq!(
+            order,
+            vec![
+                ("443".to_string(), "500".to_string()),
+                ("5173".to_string(), "400".to_string()),
+                ("8080".to_string(), "42".to_string()),
+                ("8080".to_string(), "310".to_string()),
+                ("*".to_string(), "1".to_string()),
+            ]
+        );
+    }
+
+    // 같은 pid를 가진 모든 행이 갱신된다
+    #[test]
+    fn test_apply_patches() {
+        let mut rows = vec![
+            port_info("Unknown", "3000", "1234", None),
+            port_info("Unknown", "3001", "1234", None),
+            port_info("Unknown", "8080", "5678", None),
+        ];
+
+        apply_patches(
+            &mut rows,
+            &[ProcessPatch {
+                pid: "1234".to_string(),
+                process_name: Some("node.exe".to_string()),
+                user: Some("user".to_string()),
+                ..ProcessPatch::default()
+            }],
+        );
+
+        assert_eq!(rows[0].process_name, "node.exe");
+        assert_eq!(rows[1].user, Some("user".to_string()));
+        assert_eq!(rows[2].process_name, "Unknown");
+    }
+
+    fn port_info(process: &str, port: &str, pid: &str, user: Option<&str>) -> PortInfo {
+        PortInfo {
+            process_name: process.to_string(),
+            port: port.to_string(),
+            pid: pid.to_string(),
+            protocol: "TCP".to_string(),
+            address: "127.0.0.1".to_string(),
+            user: user.map(|user| user.to_string()),
+            ..PortInfo::default()
+        }
+    }
 }
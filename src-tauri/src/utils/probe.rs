@@ -0,0 +1,173 @@
+use std::collections::HashSet;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, TcpStream};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::{
+    structs::{
+        port::PortInfo,
+        probe::{ProbeResult, ProbeStatus},
+    },
+    utils::time::now_millis,
+};
+
+pub const DEFAULT_PROBE_TIMEOUT_MS: u64 = 1_000;
+
+/**
+ * 연결할 주소를 정한다. 모든 주소에 열린 소켓(0.0.0.0, ::, *)은 루프백으로 연결한다.
+ * TCP가 아니거나 주소를 알 수 없으면 None
+ */
+pub fn probe_target(row: &PortInfo) -> Option<SocketAddr> {
+    if !row.protocol.to_uppercase().starts_with("TCP") {
+        return None;
+    }
+
+    let port = row.port.parse::<u16>().ok()?;
+    let address = row.address.trim_matches(|c| c == '[' || c == ']');
+
+    let ip = match address {
+        "*" | "" => IpAddr::V4(Ipv4Addr::LOCALHOST),
+        address => match address.parse::<IpAddr>().ok()? {
+            IpAddr::V4(ip) if ip.is_unspecified() => IpAddr::V4(Ipv4Addr::LOCALHOST),
+            IpAddr::V6(ip) if ip.is_unspecified() => IpAddr::V6(Ipv6Addr::LOCALHOST),
+            ip => ip,
+        },
+    };
+
+    Some(SocketAddr::new(ip, port))
+}
+
+pub fn probe_tcp(target: SocketAddr, timeout: Duration) -> ProbeStatus {
+    let started = Instant::now();
+
+    match TcpStream::connect_timeout(&target, timeout) {
+        Ok(_) => ProbeStatus::Healthy {
+            latency_ms: started.elapsed().as_millis() as u64,
+        },
+        Err(e) => ProbeStatus::Failed {
+            message: e.to_string(),
+        },
+    }
+}
+
+/**
+ * 소켓마다 한 번씩 동시에 연결을 시도한다.
+ * 같은 소켓을 가진 여러 프로세스는 한 번만 확인한다.
+ */
+pub fn probe_rows(rows: &[PortInfo], timeout: Duration) -> Vec<ProbeResult> {
+    let mut seen = HashSet::new();
+    let sockets: Vec<&PortInfo> = rows
+        .iter()
+        .filter(|row| seen.insert(row.socket_key()))
+        .collect();
+
+    thread::scope(|scope| {
+        let handles: Vec<_> = sockets
+            .iter()
+            .map(|row| {
+                scope.spawn(move || {
+                    let target = probe_target(row);
+                    let status = match target {
+                        Some(target) => probe_tcp(target, timeout),
+                        None => ProbeStatus::Skipped,
+                    };
+
+                    ProbeResult {
+                        socket_key: row.socket_key(),
+                        target: target.map(|target| target.to_string()),
+                        status,
+                        checked_at: now_millis(),
+                    }
+                })
+            })
+            .collect();
+
+        handles
+            .into_iter()
+            .filter_map(|handle| handle.join().ok())
+            .collect()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::TcpListener;
+
+    fn port_info(protocol: &str, address: &str, port: &str) -> PortInfo {
+        PortInfo {
+            process_name: "test".to_string(),
+            port: port.to_string(),
+            pid: "1".to_string(),
+            protocol: protocol.to_string(),
+            address: address.to_string(),
+            ..PortInfo::default()
+        }
+    }
+
+    #[test]
+    fn test_probe_target() {
+        assert_eq!(
+            probe_target(&port_info("TCP", "0.0.0.0", "8080")),
+            Some("127.0.0.1:8080".parse().unwrap())
+        );
+        assert_eq!(
+            probe_target(&port_info("TCP", "*", "8080")),
+            Some("127.0.0.1:8080".parse().unwrap())
+        );
+        assert_eq!(
+            probe_target(&port_info("TCP", "[::]", "8080")),
+            Some("[::1]:8080".parse().unwrap())
+        );
+        assert_eq!(
+            probe_target(&port_info("TCP", "192.168.0.10", "22")),
+            Some("192.168.0.10:22".parse().unwrap())
+        );
+        assert_eq!(probe_target(&port_info("UDP", "0.0.0.0", "53")), None);
+    }
+
+    #[test]
+    fn test_probe_tcp_healthy() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let target = listener.local_addr().unwrap();
+
+        assert!(matches!(
+            probe_tcp(target, Duration::from_millis(500)),
+            ProbeStatus::Healthy { .. }
+        ));
+    }
+
+    #[test]
+    fn test_probe_tcp_refused() {
+        let target = {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            listener.local_addr().unwrap()
+        };
+
+        assert!(matches!(
+            probe_tcp(target, Duration::from_millis(500)),
+            ProbeStatus::Failed { .. }
+        ));
+    }
+
+    // 같은 소켓의 여러 소유 프로세스는 한 번만 확인한다
+    #[test]
+    fn test_probe_rows() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port().to_string();
+        let mut second_owner = port_info("TCP", "127.0.0.1", &port);
+        second_owner.pid = "2".to_string();
+        let rows = vec![
+            port_info("TCP", "127.0.0.1", &port),
+            second_owner,
+            port_info("UDP", "0.0.0.0", "53"),
+        ];
+
+        let results = probe_rows(&rows, Duration::from_millis(500));
+
+        assert_eq!(results.len(), 2);
+        assert!(matches!(results[0].status, ProbeStatus::Healthy { .. }));
+        assert_eq!(results[0].socket_key, rows[0].socket_key());
+        assert_eq!(results[1].status, ProbeStatus::Skipped);
+    }
+}
@@ -0,0 +1,357 @@
+use std::collections::{HashMap, VecDeque};
+use std::fs;
+
+use crate::{
+    enums::error::CommandError,
+    structs::kill::{ChildProcess, ProcessContext},
+    traits::command::CommandExecutor,
+};
+
+/**
+ * 프로세스 목록의 한 줄
+ */
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ProcessEntry {
+    pub pid: String,
+    pub ppid: String,
+    pub name: String,
+    pub command_line: Option<String>,
+}
+
+/**
+ * /proc/<pid>/stat에서 (이름, 부모 pid)를 읽는다.
+ * 이름에 공백이나 괄호가 있을 수 있으므로 마지막 ')'를 기준으로 나눈다.
+ *
+ * ex) 1234 (node) S 1000 1234 ...
+ */
+pub fn parse_proc_stat(stat: &str) -> Option<(String, String)> {
+    let open = stat.find('(')?;
+    let close = stat.rfind(')')?;
+    let name = stat.get(open + 1..close)?.to_string();
+    let ppid = stat
+        .get(close + 1..)?
+        .split_whitespace()
+        .nth(1)?
+        .to_string();
+
+    Some((name, ppid))
+}
+
+/**
+ * ps -A -o pid=,ppid=,comm= 결과
+ *
+ * ex)
+ *  1234  1000 /usr/local/bin/node
+ */
+pub fn parse_ps_table(output: &str) -> Vec<ProcessEntry> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let (pid, rest) = line.trim().split_once(char::is_whitespace)?;
+            let (ppid, command) = rest.trim_start().split_once(char::is_whitespace)?;
+            let command = command.trim();
+
+            Some(ProcessEntry {
+                pid: pid.to_string(),
+                ppid: ppid.to_string(),
+                name: command.rsplit('/').next().unwrap_or(command).to_string(),
+                command_line: None,
+            })
+        })
+        .collect()
+}
+
+/**
+ * 큰따옴표로 감싼 CSV 한 줄을 나눈다. ("" 는 따옴표 하나)
+ */
+pub fn split_csv_line(line: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(std::mem::take(&mut field)),
+            c => field.push(c),
+        }
+    }
+    fields.push(field);
+
+    fields
+}
+
+/**
+ * Get-CimInstance Win32_Process | ConvertTo-Csv 결과
+ *
+ * ex)
+ * "ProcessId","ParentProcessId","Name","CommandLine"
+ * "1234","1000","node.exe","node server.js"
+ */
+pub fn parse_cim_csv(output: &str) -> Vec<ProcessEntry> {
+    output
+        .lines()
+        .skip(1)
+        .filter_map(|line| {
+            let fields = split_csv_line(line.trim());
+            if fields.len() < 4 {
+                return None;
+            }
+
+            Some(ProcessEntry {
+                pid: fields[0].clone(),
+                ppid: fields[1].clone(),
+                name: fields[2].clone(),
+                command_line: Some(fields[3].clone()).filter(|line| !line.is_empty()),
+            })
+        })
+        .collect()
+}
+
+/**
+ * pid의 모든 자손 프로세스를 가까운 순서대로 찾는다.
+ */
+pub fn descendants(table: &[ProcessEntry], pid: &str) -> Vec<ChildProcess> {
+    let mut children_of: HashMap<&str, Vec<&ProcessEntry>> = HashMap::new();
+    for entry in table {
+        // 부모가 자기 자신인 항목(윈도우의 System Idle Process)은 건너뛴다.
+        if entry.pid != entry.ppid {
+            children_of
+                .entry(entry.ppid.as_str())
+                .or_default()
+                .push(entry);
+        }
+    }
+
+    let mut result = Vec::new();
+    let mut queue = VecDeque::from([pid]);
+    while let Some(parent) = queue.pop_front() {
+        for child in children_of.get(parent).into_iter().flatten() {
+            // 순환하는 목록이라도 멈추도록 이미 찾은 pid는 건너뛴다.
+            if child.pid == pid
+                || result
+                    .iter()
+                    .any(|found: &ChildProcess| found.pid == child.pid)
+            {
+                continue;
+            }
+            result.push(ChildProcess {
+                pid: child.pid.clone(),
+                process_name: child.name.clone(),
+            });
+            queue.push_back(child.pid.as_str());
+        }
+    }
+
+    result
+}
+
+/**
+ * pid의 부모부터 최상위 프로세스까지
+ */
+pub fn ancestors(table: &[ProcessEntry], pid: &str) -> Vec<ChildProcess> {
+    let mut result: Vec<ChildProcess> = Vec::new();
+    let mut current = pid;
+
+    while let Some(entry) = table.iter().find(|entry| entry.pid == current) {
+        let Some(parent) = table.iter().find(|parent| parent.pid == entry.ppid) else {
+            break;
+        };
+        // 자기 자신이 부모인 항목이나 순환하는 목록에서 멈춘다.
+        if parent.pid == pid || result.iter().any(|found| found.pid == parent.pid) {
+            break;
+        }
+        result.push(ChildProcess {
+            pid: parent.pid.clone(),
+            process_name: parent.name.clone(),
+        });
+        current = parent.pid.as_str();
+    }
+
+    result
+}
+
+/**
+ * /proc/<pid>/cmdline은 인자가 NUL로 구분되어 있다.
+ */
+pub fn read_proc_cmdline(pid: &str) -> Option<String> {
+    let raw = fs::read(format!("/proc/{}/cmdline", pid)).ok()?;
+    let command_line = raw
+        .split(|byte| *byte == 0)
+        .filter(|arg| !arg.is_empty())
+        .map(|arg| String::from_utf8_lossy(arg).to_string())
+        .collect::<Vec<String>>()
+        .join(" ");
+
+    (!command_line.is_empty()).then_some(command_line)
+}
+
+pub fn process_table(executor: &dyn CommandExecutor) -> Result<Vec<ProcessEntry>, CommandError> {
+    if cfg!(target_os = "windows") {
+        let script = "Get-CimInstance Win32_Process | Select-Object ProcessId,ParentProcessId,Name,CommandLine | ConvertTo-Csv -NoTypeInformation";
+        executor
+            .execute_command(
+                "powershell",
+                &["-NoProfile", "-NonInteractive", "-Command", script],
+            )
+            .map(|output| parse_cim_csv(&output))
+            .map_err(CommandError::Failed)
+    } else if cfg!(target_os = "linux") {
+        let entries = fs::read_dir("/proc")
+            .map_err(|e| CommandError::Failed(format!("Failed to read /proc: {}", e)))?;
+
+        Ok(entries
+            .flatten()
+            .filter_map(|entry| {
+                let pid = entry.file_name().to_string_lossy().to_string();
+                // self, thread-self 같은 링크는 건너뛴다.
+                if !pid.chars().all(|c| c.is_ascii_digit()) {
+                    return None;
+                }
+                let stat = fs::read_to_string(entry.path().join("stat")).ok()?;
+                let (name, ppid) = parse_proc_stat(&stat)?;
+
+                Some(ProcessEntry {
+                    pid,
+                    ppid,
+                    name,
+                    command_line: None,
+                })
+            })
+            .collect())
+    } else {
+        executor
+            .execute_command("ps", &["-A", "-o", "pid=,ppid=,comm="])
+            .map(|output| parse_ps_table(&output))
+            .map_err(CommandError::Failed)
+    }
+}
+
+/**
+ * 종료하기 전에 확인할 정보 (전체 명령어, 영향을 받는 자식 프로세스)
+ */
+pub fn process_context(
+    executor: &dyn CommandExecutor,
+    pid: &str,
+) -> Result<ProcessContext, CommandError> {
+    let table = process_table(executor)?;
+    let entry = table.iter().find(|entry| entry.pid == pid);
+
+    let command_line = if cfg!(target_os = "windows") {
+        entry.and_then(|entry| entry.command_line.clone())
+    } else if cfg!(target_os = "linux") {
+        read_proc_cmdline(pid)
+    } else {
+        executor
+            .execute_command("ps", &["-o", "command=", "-p", pid])
+            .ok()
+            .map(|output| output.trim().to_string())
+            .filter(|command_line| !command_line.is_empty())
+    };
+
+    Ok(ProcessContext {
+        pid: pid.to_string(),
+        process_name: entry.map(|entry| entry.name.clone()),
+        command_line,
+        children: descendants(&table, pid),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(pid: &str, ppid: &str, name: &str) -> ProcessEntry {
+        ProcessEntry {
+            pid: pid.to_string(),
+            ppid: ppid.to_string(),
+            name: name.to_string(),
+            command_line: None,
+        }
+    }
+
+    #[test]
+    fn test_parse_proc_stat() {
+        assert_eq!(
+            parse_proc_stat("1234 (node) S 1000 1234 1234 0 -1"),
+            Some(("node".to_string(), "1000".to_string()))
+        );
+        assert_eq!(
+            parse_proc_stat("77 (tmux: server) S 1 77 77 0 -1"),
+            Some(("tmux: server".to_string(), "1".to_string()))
+        );
+        assert_eq!(parse_proc_stat("garbage"), None);
+    }
+
+    #[test]
+    fn test_parse_ps_table() {
+        let table = parse_ps_table("    1     0 /sbin/launchd\n 1234     1 /usr/local/bin/node\n");
+
+        assert_eq!(
+            table,
+            vec![entry("1", "0", "launchd"), entry("1234", "1", "node")]
+        );
+    }
+
+    #[test]
+    fn test_parse_cim_csv() {
+        let output = "\
+\"ProcessId\",\"ParentProcessId\",\"Name\",\"CommandLine\"
+\"0\",\"0\",\"System Idle Process\",\"\"
+\"1234\",\"1000\",\"node.exe\",\"node \"\"my app\\server.js\"\", --port 3000\"
+";
+
+        let table = parse_cim_csv(output);
+
+        assert_eq!(table.len(), 2);
+        assert_eq!(table[0].command_line, None);
+        assert_eq!(table[1].pid, "1234");
+        assert_eq!(table[1].ppid, "1000");
+        assert_eq!(
+            table[1].command_line,
+            Some("node \"my app\\server.js\", --port 3000".to_string())
+        );
+    }
+
+    #[test]
+    fn test_descendants() {
+        let table = vec![
+            entry("0", "0", "idle"),
+            entry("100", "1", "npm"),
+            entry("200", "100", "node"),
+            entry("300", "200", "esbuild"),
+            entry("400", "1", "postgres"),
+        ];
+
+        let children: Vec<String> = descendants(&table, "100")
+            .into_iter()
+            .map(|child| child.pid)
+            .collect();
+        assert_eq!(children, vec!["200", "300"]);
+        assert!(descendants(&table, "400").is_empty());
+        assert!(descendants(&table, "0").is_empty());
+    }
+
+    #[test]
+    fn test_ancestors() {
+        let table = vec![
+            entry("0", "0", "idle"),
+            entry("1", "0", "init"),
+            entry("100", "1", "npm"),
+            entry("200", "100", "node"),
+        ];
+
+        let parents: Vec<String> = ancestors(&table, "200")
+            .into_iter()
+            .map(|parent| parent.process_name)
+            .collect();
+        assert_eq!(parents, vec!["npm", "init", "idle"]);
+        assert!(ancestors(&table, "0").is_empty());
+        assert!(ancestors(&table, "999").is_empty());
+    }
+}
@@ -0,0 +1,400 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io::ErrorKind;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::path::Path;
+
+use crate::structs::port::PortInfo;
+
+// 소유 프로세스를 찾지 못한 소켓의 pid
+pub const UNKNOWN_PID: &str = "-";
+
+// /proc/net/tcp의 st 컬럼, 0A = LISTEN, 01 = ESTABLISHED
+const TCP_LISTEN: &str = "0A";
+pub const TCP_ESTABLISHED: &str = "01";
+
+/**
+ * /proc/net/tcp{,6}의 한 줄
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProcSocket {
+    pub address: IpAddr,
+    pub port: u16,
+    pub uid: String,
+    pub inode: u64,
+}
+
+/**
+ * /proc 조회 결과
+ * 다른 사용자의 프로세스는 fd 목록을 읽을 수 없어 소켓의 소유자를 알 수 없다.
+ */
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ProcListing {
+    pub rows: Vec<PortInfo>,
+    pub unreadable_processes: usize,
+}
+
+/**
+ * 커널이 호스트 바이트 순서의 32비트 단위로 출력한 주소를 읽는다.
+ *
+ * ex) 0100007F => 127.0.0.1
+ */
+fn decode_address(hex: &str) -> Option<IpAddr> {
+    let words: Vec<[u8; 4]> = (0..hex.len())
+        .step_by(8)
+        .map(|start| {
+            let word = hex.get(start..start + 8)?;
+            u32::from_str_radix(word, 16)
+                .ok()
+                .map(|value| value.to_ne_bytes())
+        })
+        .collect::<Option<Vec<[u8; 4]>>>()?;
+
+    match words.as_slice() {
+        [word] => Some(IpAddr::V4(Ipv4Addr::from(*word))),
+        [a, b, c, d] => {
+            let bytes: [u8; 16] = [*a, *b, *c, *d].concat().try_into().ok()?;
+            Some(IpAddr::V6(Ipv6Addr::from(bytes)))
+        }
+        _ => None,
+    }
+}
+
+/**
+ * 모든 상태의 TCP 소켓 (상세 패널)
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProcConnection {
+    pub local: SocketAddr,
+    pub remote: SocketAddr,
+    pub state: &'static str,
+    pub inode: u64,
+}
+
+// include/net/tcp_states.h
+fn tcp_state_name(hex: &str) -> &'static str {
+    match hex {
+        "01" => "ESTABLISHED",
+        "02" => "SYN_SENT",
+        "03" => "SYN_RECV",
+        "04" => "FIN_WAIT1",
+        "05" => "FIN_WAIT2",
+        "06" => "TIME_WAIT",
+        "07" => "CLOSE",
+        "08" => "CLOSE_WAIT",
+        "09" => "LAST_ACK",
+        "0A" => "LISTEN",
+        "0B" => "CLOSING",
+        _ => "UNKNOWN",
+    }
+}
+
+fn decode_socket_address(value: &str) -> Option<SocketAddr> {
+    let (address, port) = value.split_once(':')?;
+    Some(SocketAddr::new(
+        decode_address(address)?,
+        u16::from_str_radix(port, 16).ok()?,
+    ))
+}
+
+/**
+ * /proc/net/tcp{,6}의 모든 줄을 상태와 함께 읽는다.
+ */
+pub fn parse_proc_connections(content: &str) -> Vec<ProcConnection> {
+    content
+        .lines()
+        .skip(1)
+        .filter_map(|line| {
+            let columns: Vec<&str> = line.split_whitespace().collect();
+            if columns.len() < 10 {
+                return None;
+            }
+
+            Some(ProcConnection {
+                local: decode_socket_address(columns[1])?,
+                remote: decode_socket_address(columns[2])?,
+                state: tcp_state_name(columns[3]),
+                inode: columns[9].parse().ok()?,
+            })
+        })
+        .collect()
+}
+
+/**
+ * /proc/net/tcp, /proc/net/tcp6에서 LISTEN 상태인 소켓을 읽는다.
+ *
+ * ex)
+ *   sl  local_address rem_address   st tx_queue rx_queue tr tm->when retrnsmt   uid  timeout inode
+ *    0: 0100007F:1F90 00000000:0000 0A 00000000:00000000 00:00000000 00000000  1000        0 12345 ...
+ */
+pub fn parse_proc_net(content: &str) -> Vec<ProcSocket> {
+    parse_proc_net_state(content, TCP_LISTEN)
+}
+
+/**
+ * /proc/net/tcp{,6}에서 st 컬럼이 state인 소켓을 읽는다.
+ */
+pub fn parse_proc_net_state(content: &str, state: &str) -> Vec<ProcSocket> {
+    content
+        .lines()
+        .skip(1)
+        .filter_map(|line| {
+            let columns: Vec<&str> = line.split_whitespace().collect();
+            if columns.len() < 10 || columns[3] != state {
+                return None;
+            }
+
+            let (address, port) = columns[1].split_once(':')?;
+
+            Some(ProcSocket {
+                address: decode_address(address)?,
+                port: u16::from_str_radix(port, 16).ok()?,
+                uid: columns[7].to_string(),
+                inode: columns[9].parse().ok()?,
+            })
+        })
+        .collect()
+}
+
+/**
+ * /etc/passwd에서 uid => 사용자 이름
+ */
+pub fn parse_passwd(content: &str) -> HashMap<String, String> {
+    content
+        .lines()
+        .filter_map(|line| {
+            let mut columns = line.split(':');
+            let name = columns.next()?;
+            let uid = columns.nth(1)?;
+            Some((uid.to_string(), name.to_string()))
+        })
+        .collect()
+}
+
+/**
+ * fd 링크가 소켓이라면 inode
+ *
+ * ex) socket:[12345] => 12345
+ */
+fn socket_inode(target: &str) -> Option<u64> {
+    target
+        .strip_prefix("socket:[")
+        .and_then(|rest| rest.strip_suffix(']'))
+        .and_then(|inode| inode.parse::<u64>().ok())
+}
+
+/**
+ * 프로세스 하나가 가진 소켓 inode
+ */
+pub fn process_socket_inodes(proc_root: &Path, pid: &str) -> Vec<u64> {
+    let Ok(fds) = fs::read_dir(proc_root.join(pid).join("fd")) else {
+        return Vec::new();
+    };
+
+    let mut inodes: Vec<u64> = fds
+        .flatten()
+        .filter_map(|fd| fs::read_link(fd.path()).ok())
+        .filter_map(|target| socket_inode(&target.to_string_lossy()))
+        .collect();
+    inodes.sort();
+    inodes.dedup();
+    inodes
+}
+
+/**
+ * 모든 프로세스의 fd를 훑어 소켓 inode => pid 목록을 만든다.
+ * 권한이 없어 읽지 못한 프로세스 수를 함께 돌려준다.
+ */
+fn socket_owners(proc_root: &Path) -> (HashMap<u64, Vec<String>>, usize) {
+    let mut owners: HashMap<u64, Vec<String>> = HashMap::new();
+    let mut unreadable = 0;
+
+    let Ok(entries) = fs::read_dir(proc_root) else {
+        return (owners, unreadable);
+    };
+
+    for entry in entries.flatten() {
+        let pid = entry.file_name().to_string_lossy().to_string();
+        if pid.is_empty() || !pid.chars().all(|c| c.is_ascii_digit()) {
+            continue;
+        }
+
+        let fds = match fs::read_dir(entry.path().join("fd")) {
+            Ok(fds) => fds,
+            Err(e) if e.kind() == ErrorKind::PermissionDenied => {
+                unreadable += 1;
+                continue;
+            }
+            // 조회하는 사이에 종료된 프로세스
+            Err(_) => continue,
+        };
+
+        for fd in fds.flatten() {
+            let Ok(target) = fs::read_link(fd.path()) else {
+                continue;
+            };
+            if let Some(inode) = socket_inode(&target.to_string_lossy()) {
+                let pids = owners.entry(inode).or_default();
+                // 같은 소켓을 여러 fd로 가질 수 있다.
+                if !pids.contains(&pid) {
+                    pids.push(pid.clone());
+                }
+            }
+        }
+    }
+
+    (owners, unreadable)
+}
+
+/**
+ * /proc을 직접 읽어 LISTEN 상태인 TCP 소켓과 소유 프로세스를 조회한다.
+ * 소유자를 찾지 못한 소켓도 pid를 UNKNOWN_PID로 두고 목록에 남긴다.
+ */
+pub fn list_listeners(proc_root: &Path, passwd: &str) -> ProcListing {
+    let users = parse_passwd(passwd);
+    let (owners, unreadable_processes) = socket_owners(proc_root);
+
+    let sockets: Vec<ProcSocket> = ["net/tcp", "net/tcp6"]
+        .iter()
+        .filter_map(|file| fs::read_to_string(proc_root.join(file)).ok())
+        .flat_map(|content| parse_proc_net(&content))
+        .collect();
+
+    let mut rows = Vec::new();
+    for socket in sockets {
+        let user = Some(users.get(&socket.uid).unwrap_or(&socket.uid).clone());
+        let row = PortInfo {
+            port: socket.port.to_string(),
+            protocol: "TCP".to_string(),
+            address: socket.address.to_string(),
+            user,
+            ..PortInfo::default()
+        };
+
+        match owners.get(&socket.inode) {
+            Some(pids) => {
+                for pid in pids {
+                    let process_name = fs::read_to_string(proc_root.join(pid).join("comm"))
+                        .map(|name| name.trim().to_string())
+                        .unwrap_or("Unknown".to_string());
+
+                    rows.push(PortInfo {
+                        process_name,
+                        pid: pid.clone(),
+                        ..row.clone()
+                    });
+                }
+            }
+            None => rows.push(PortInfo {
+                process_name: "Unknown".to_string(),
+                pid: UNKNOWN_PID.to_string(),
+                ..row
+            }),
+        }
+    }
+
+    ProcListing {
+        rows,
+        unreadable_processes,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PROC_NET_TCP: &str = "\
+  sl  local_address rem_address   st tx_queue rx_queue tr tm->when retrnsmt   uid  timeout inode
+   0: 0100007F:1F90 00000000:0000 0A 00000000:00000000 00:00000000 00000000  1000        0 12345 1 0000000000000000 100 0 0 10 0
+   1: 00000000:0016 00000000:0000 0A 00000000:00000000 00:00000000 00000000     0        0 999 1 0000000000000000 100 0 0 10 0
+   2: 0100007F:1F90 0100007F:D431 01 00000000:00000000 00:00000000 00000000  1000        0 555 1 0000000000000000 100 0 0 10 0
+";
+
+    #[test]
+    #[cfg(target_endian = "little")]
+    fn test_decode_address() {
+        assert_eq!(
+            decode_address("0100007F"),
+            Some("127.0.0.1".parse().unwrap())
+        );
+        assert_eq!(
+            decode_address("00000000000000000000000001000000"),
+            Some("::1".parse().unwrap())
+        );
+        assert_eq!(decode_address("0100"), None);
+    }
+
+    #[test]
+    #[cfg(target_endian = "little")]
+    fn test_parse_proc_net() {
+        let sockets = parse_proc_net(PROC_NET_TCP);
+
+        // ESTABLISHED(01)인 줄은 제외
+        assert_eq!(
+            sockets,
+            vec![
+                ProcSocket {
+                    address: "127.0.0.1".parse().unwrap(),
+                    port: 8080,
+                    uid: "1000".to_string(),
+                    inode: 12345,
+                },
+                ProcSocket {
+                    address: "0.0.0.0".parse().unwrap(),
+                    port: 22,
+                    uid: "0".to_string(),
+                    inode: 999,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    #[cfg(target_endian = "little")]
+    fn test_parse_proc_connections() {
+        let connections = parse_proc_connections(PROC_NET_TCP);
+
+        assert_eq!(connections.len(), 3);
+        assert_eq!(connections[0].state, "LISTEN");
+        assert_eq!(connections[2].state, "ESTABLISHED");
+        assert_eq!(connections[2].local.to_string(), "127.0.0.1:8080");
+        assert_eq!(connections[2].remote.to_string(), "127.0.0.1:54321");
+        assert_eq!(connections[2].inode, 555);
+    }
+
+    #[test]
+    fn test_parse_passwd() {
+        let users =
+            parse_passwd("root:x:0:0:root:/root:/bin/bash\nuser:x:1000:1000::/home/user:/bin/sh\n");
+
+        assert_eq!(users.get("0"), Some(&"root".to_string()));
+        assert_eq!(users.get("1000"), Some(&"user".to_string()));
+    }
+
+    #[test]
+    #[cfg(all(unix, target_endian = "little"))]
+    fn test_list_listeners() {
+        let root =
+            std::env::temp_dir().join(format!("open_port_check_procfs_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("net")).unwrap();
+        fs::create_dir_all(root.join("4321/fd")).unwrap();
+        fs::write(root.join("net/tcp"), PROC_NET_TCP).unwrap();
+        fs::write(root.join("4321/comm"), "node\n").unwrap();
+        std::os::unix::fs::symlink("socket:[12345]", root.join("4321/fd/3")).unwrap();
+        std::os::unix::fs::symlink("/dev/null", root.join("4321/fd/0")).unwrap();
+
+        let listing = list_listeners(&root, "root:x:0:0:root:/root:/bin/bash\n");
+        fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(listing.unreadable_processes, 0);
+        assert_eq!(listing.rows.len(), 2);
+        assert_eq!(listing.rows[0].pid, "4321");
+        assert_eq!(listing.rows[0].process_name, "node");
+        assert_eq!(listing.rows[0].user, Some("1000".to_string()));
+        // sshd의 fd는 보이지 않으므로 소유자를 알 수 없다.
+        assert_eq!(listing.rows[1].pid, UNKNOWN_PID);
+        assert_eq!(listing.rows[1].port, "22");
+        assert_eq!(listing.rows[1].user, Some("root".to_string()));
+    }
+}
@@ -0,0 +1,309 @@
+use std::io::{Read, Write};
+use std::net::{SocketAddr, TcpStream};
+use std::time::{Duration, Instant};
+
+use crate::structs::fingerprint::Fingerprint;
+
+const MAX_REPLY_BYTES: usize = 16 * 1024;
+
+// Postgres SSLRequest (길이 8, 코드 80877103)
+const POSTGRES_SSL_REQUEST: [u8; 8] = [0x00, 0x00, 0x00, 0x08, 0x04, 0xd2, 0x16, 0x2f];
+
+fn connect(target: SocketAddr, timeout: Duration) -> Option<TcpStream> {
+    let stream = TcpStream::connect_timeout(&target, timeout).ok()?;
+    stream.set_read_timeout(Some(timeout)).ok()?;
+    stream.set_write_timeout(Some(timeout)).ok()?;
+    Some(stream)
+}
+
+/**
+ * done이 true가 되거나, 제한 시간 혹은 크기 제한에 닿을 때까지 읽는다.
+ */
+fn read_until(stream: &mut TcpStream, timeout: Duration, done: impl Fn(&[u8]) -> bool) -> Vec<u8> {
+    let deadline = Instant::now() + timeout;
+    let mut raw = Vec::new();
+    let mut buffer = [0_u8; 4 * 1024];
+
+    while raw.len() < MAX_REPLY_BYTES && Instant::now() < deadline && !done(&raw) {
+        match stream.read(&mut buffer) {
+            Ok(0) | Err(_) => break,
+            Ok(read) => raw.extend_from_slice(&buffer[..read]),
+        }
+    }
+
+    raw
+}
+
+/**
+ * 연결하자마자 서버가 먼저 보내는 인사말(SSH 배너, MySQL 핸드셰이크)을 읽는다.
+ */
+pub fn read_banner(target: SocketAddr, timeout: Duration) -> Option<Vec<u8>> {
+    let mut stream = connect(target, timeout)?;
+    let banner = read_until(&mut stream, timeout, |raw| !raw.is_empty());
+    (!banner.is_empty()).then_some(banner)
+}
+
+/**
+ * `SSH-2.0-OpenSSH_9.6p1 Ubuntu-3ubuntu13` 형태의 배너
+ */
+pub fn parse_ssh_banner(banner: &[u8]) -> Option<Fingerprint> {
+    let text = String::from_utf8_lossy(banner);
+    let line = text.lines().find(|line| line.starts_with("SSH-"))?;
+
+    Some(Fingerprint::Ssh {
+        banner: line.trim().to_string(),
+    })
+}
+
+/**
+ * MySQL 초기 핸드셰이크 패킷에서 서버 버전을 읽는다.
+ * 길이(3) + 순번(1) + 프로토콜 버전(10) + NUL로 끝나는 버전 문자열
+ */
+pub fn parse_mysql_greeting(greeting: &[u8]) -> Option<Fingerprint> {
+    let length =
+        u32::from_le_bytes([*greeting.first()?, *greeting.get(1)?, *greeting.get(2)?, 0]) as usize;
+    let payload = greeting.get(4..(4 + length).min(greeting.len()))?;
+
+    if payload.first() != Some(&10) {
+        return None;
+    }
+
+    let end = payload[1..].iter().position(|byte| *byte == 0)?;
+    let version = std::str::from_utf8(&payload[1..1 + end]).ok()?;
+
+    Some(Fingerprint::Mysql {
+        version: version.to_string(),
+    })
+}
+
+/**
+ * PING을 보내고, 응답이 있으면 INFO server로 버전을 확인한다.
+ * 인증이 필요한 서버는 NOAUTH 오류로 응답한다.
+ */
+pub fn probe_redis(target: SocketAddr, timeout: Duration) -> Option<Fingerprint> {
+    let mut stream = connect(target, timeout)?;
+    stream.write_all(b"PING\r\n").ok()?;
+    let reply = read_until(&mut stream, timeout, |raw| raw.ends_with(b"\r\n"));
+    let reply = String::from_utf8_lossy(&reply);
+
+    if reply.starts_with("+PONG") {
+        stream.write_all(b"INFO server\r\n").ok()?;
+        let info = read_until(&mut stream, timeout, is_complete_bulk);
+
+        return Some(Fingerprint::Redis {
+            version: parse_redis_version(&String::from_utf8_lossy(&info)),
+            auth_required: false,
+        });
+    }
+
+    // 보호 모드(DENIED)나 인증(NOAUTH) 오류도 Redis의 응답이다.
+    if reply.starts_with("-NOAUTH")
+        || reply.starts_with("-DENIED")
+        || (reply.starts_with("-ERR") && reply.contains("auth"))
+    {
+        return Some(Fingerprint::Redis {
+            version: None,
+            auth_required: true,
+        });
+    }
+
+    None
+}
+
+/**
+ * `$<길이>\r\n<내용>\r\n` 형태의 응답을 모두 받았는지
+ */
+fn is_complete_bulk(raw: &[u8]) -> bool {
+    let text = String::from_utf8_lossy(raw);
+    let Some((header, body)) = text
+        .strip_prefix('$')
+        .and_then(|rest| rest.split_once("\r\n"))
+    else {
+        return false;
+    };
+
+    header
+        .parse::<usize>()
+        .map(|length| body.len() >= length)
+        .unwrap_or(true)
+}
+
+pub fn parse_redis_version(info: &str) -> Option<String> {
+    info.lines()
+        .find_map(|line| line.strip_prefix("redis_version:"))
+        .map(|version| version.trim().to_string())
+}
+
+/**
+ * SSLRequest를 보내 S(지원) 혹은 N(미지원) 한 바이트 응답만 확인하고 연결을 끊는다.
+ * 접속(StartupMessage)은 시도하지 않으므로 서버 로그에 인증 실패가 남지 않는다.
+ */
+pub fn probe_postgres(target: SocketAddr, timeout: Duration) -> Option<Fingerprint> {
+    let mut stream = connect(target, timeout)?;
+    stream.write_all(&POSTGRES_SSL_REQUEST).ok()?;
+    let reply = read_until(&mut stream, timeout, |raw| !raw.is_empty());
+
+    match reply.as_slice() {
+        [b'S'] => Some(Fingerprint::Postgres { ssl: true }),
+        [b'N'] => Some(Fingerprint::Postgres { ssl: false }),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::TcpListener;
+    use std::thread;
+
+    const TIMEOUT: Duration = Duration::from_millis(500);
+
+    /**
+     * 연결되면 greeting을 먼저 보내고, 요청마다 준비한 응답을 순서대로 보내는 가짜 서버
+     */
+    fn fake_server(greeting: Vec<u8>, replies: Vec<Vec<u8>>) -> SocketAddr {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+
+        thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            if !greeting.is_empty() {
+                let _ = stream.write_all(&greeting);
+            }
+            for reply in replies {
+                let mut buffer = [0_u8; 1024];
+                match stream.read(&mut buffer) {
+                    Ok(0) | Err(_) => return,
+                    Ok(_) => {
+                        let _ = stream.write_all(&reply);
+                    }
+                }
+            }
+            // 클라이언트가 끝낼 때까지 연결을 유지한다.
+            let _ = stream.read(&mut [0_u8; 64]);
+        });
+
+        address
+    }
+
+    #[test]
+    fn test_ssh_banner() {
+        let address = fake_server(b"SSH-2.0-OpenSSH_9.6p1 Ubuntu-3\r\n".to_vec(), vec![]);
+
+        let banner = read_banner(address, TIMEOUT).unwrap();
+
+        assert_eq!(
+            parse_ssh_banner(&banner),
+            Some(Fingerprint::Ssh {
+                banner: "SSH-2.0-OpenSSH_9.6p1 Ubuntu-3".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_mysql_greeting() {
+        let mut payload = vec![10];
+        payload.extend_from_slice(b"8.0.36\0");
+        payload.extend_from_slice(&[0x01, 0x00, 0x00, 0x00]);
+        let mut greeting = (payload.len() as u32).to_le_bytes()[..3].to_vec();
+        greeting.push(0);
+        greeting.extend(payload);
+        let address = fake_server(greeting, vec![]);
+
+        let banner = read_banner(address, TIMEOUT).unwrap();
+
+        assert_eq!(
+            parse_mysql_greeting(&banner),
+            Some(Fingerprint::Mysql {
+                version: "8.0.36".to_string()
+            })
+        );
+        assert_eq!(parse_mysql_greeting(b"SSH-2.0-OpenSSH\r\n"), None);
+    }
+
+    #[test]
+    fn test_probe_redis() {
+        let info = "# Server\r\nredis_version:7.2.4\r\nredis_mode:standalone\r\n";
+        let address = fake_server(
+            vec![],
+            vec![
+                b"+PONG\r\n".to_vec(),
+                format!("${}\r\n{}\r\n", info.len(), info).into_bytes(),
+            ],
+        );
+
+        assert_eq!(
+            probe_redis(address, TIMEOUT),
+            Some(Fingerprint::Redis {
+                version: Some("7.2.4".to_string()),
+                auth_required: false
+            })
+        );
+    }
+
+    #[test]
+    fn test_probe_redis_auth_required() {
+        let address = fake_server(
+            vec![],
+            vec![b"-NOAUTH Authentication required.\r\n".to_vec()],
+        );
+
+        assert_eq!(
+            probe_redis(address, TIMEOUT),
+            Some(Fingerprint::Redis {
+                version: None,
+                auth_required: true
+            })
+        );
+    }
+
+    #[test]
+    fn test_probe_redis_not_redis() {
+        let address = fake_server(vec![], vec![b"HTTP/1.1 400 Bad Request\r\n\r\n".to_vec()]);
+
+        assert_eq!(probe_redis(address, TIMEOUT), None);
+    }
+
+    #[test]
+    fn test_probe_postgres_ssl() {
+        let address = fake_server(vec![], vec![b"S".to_vec()]);
+
+        assert_eq!(
+            probe_postgres(address, TIMEOUT),
+            Some(Fingerprint::Postgres { ssl: true })
+        );
+    }
+
+    // N을 받으면 접속을 시도하지 않고 끝낸다
+    #[test]
+    fn test_probe_postgres_no_ssl() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        let server = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = [0_u8; 8];
+            stream.read_exact(&mut request).unwrap();
+            stream.write_all(b"N").unwrap();
+
+            let mut rest = Vec::new();
+            let _ = stream.read_to_end(&mut rest);
+            (request, rest)
+        });
+
+        assert_eq!(
+            probe_postgres(address, TIMEOUT),
+            Some(Fingerprint::Postgres { ssl: false })
+        );
+
+        let (request, rest) = server.join().unwrap();
+        assert_eq!(request, POSTGRES_SSL_REQUEST);
+        assert!(rest.is_empty());
+    }
+
+    #[test]
+    fn test_probe_postgres_not_postgres() {
+        let address = fake_server(vec![], vec![b"HTTP/1.1 400 Bad Request\r\n\r\n".to_vec()]);
+
+        assert_eq!(probe_postgres(address, TIMEOUT), None);
+    }
+}
@@ -1,14 +1,17 @@
 use std::collections::HashSet;
+use std::hash::Hash;
 
-pub fn remove_duplicates(ports: Vec<(String, String, String)>) -> Vec<(String, String, String)> {
-    let mut unique_ports: HashSet<(String, String, String)> = HashSet::new();
+/**
+ * 처음 나온 순서를 유지하며 중복을 제거한다.
+ * HashSet을 그대로 돌려주면 조회할 때마다 순서가 바뀐다.
+ */
+pub fn remove_duplicates<T: Eq + Hash + Clone>(ports: Vec<T>) -> Vec<T> {
+    let mut seen: HashSet<T> = HashSet::new();
 
-    // HashSet을 이용해 중복 제거
-    ports.into_iter().for_each(|port| {
-        unique_ports.insert(port);
-    });
-
-    unique_ports.into_iter().collect()
+    ports
+        .into_iter()
+        .filter(|port| seen.insert(port.clone()))
+        .collect()
 }
 
 #[cfg(test)]
@@ -51,9 +54,7 @@ mod test {
             ),
         ];
 
-        assert_eq!(result.len(), 2);
-        assert!(result.contains(&expected[0]));
-        assert!(result.contains(&expected[1]));
+        assert_eq!(result, expected);
     }
 
     // 중복된 갑이 존재하지 않을 때
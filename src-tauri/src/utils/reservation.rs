@@ -0,0 +1,263 @@
+use std::collections::BTreeMap;
+use std::io;
+use std::net::{IpAddr, SocketAddr, TcpListener};
+use std::sync::Mutex;
+
+use socket2::{Domain, Socket, Type};
+
+use crate::{
+    enums::error::CommandError,
+    structs::{port::PortInfo, reservation::Reservation},
+    utils::time::now_millis,
+};
+
+/**
+ * IPv6 listener는 IPv6만 받도록 열어 같은 포트의 IPv4 listener와 함께 붙잡을 수 있게 한다.
+ * 리눅스와 맥은 기본값이 dual-stack이라 [::]가 0.0.0.0까지 차지해 EADDRINUSE가 난다.
+ */
+fn bind_listener(address: SocketAddr) -> io::Result<TcpListener> {
+    let socket = Socket::new(Domain::for_address(address), Type::STREAM, None)?;
+    if address.is_ipv6() {
+        socket.set_only_v6(true)?;
+    }
+    // std의 TcpListener::bind와 같게 유닉스에서만 TIME_WAIT 포트를 다시 쓴다.
+    #[cfg(unix)]
+    socket.set_reuse_address(true)?;
+
+    socket.bind(&address.into())?;
+    socket.listen(128)?;
+    Ok(socket.into())
+}
+
+/**
+ * 포트를 붙잡아 두는 임대(lease)
+ * 자리만 차지하는 listener를 열어 두고, drop되면 포트를 놓는다.
+ *
+ * ex)
+ * `let lease = PortLease::acquire_free(localhost, 3000, 3100)?;`
+ * `let listener = lease.hand_off(); // 테스트 서버가 그대로 사용`
+ */
+#[derive(Debug)]
+pub struct PortLease {
+    listener: TcpListener,
+    address: SocketAddr,
+}
+
+impl PortLease {
+    /**
+     * address:port를 bind한다. port가 0이면 OS가 고른 포트를 사용한다.
+     */
+    pub fn acquire(address: IpAddr, port: u16) -> Result<PortLease, CommandError> {
+        let listener = bind_listener(SocketAddr::new(address, port)).map_err(|e| {
+            CommandError::Failed(format!(
+                "Failed to reserve {}: {}",
+                SocketAddr::new(address, port),
+                e
+            ))
+        })?;
+        let address = listener
+            .local_addr()
+            .map_err(|e| CommandError::Failed(e.to_string()))?;
+
+        Ok(PortLease { listener, address })
+    }
+
+    /**
+     * start..=end 구간에서 처음으로 bind에 성공한 포트를 붙잡는다.
+     * 확인 후 bind 사이에 다른 프로세스가 가져가는 경합이 없다.
+     */
+    pub fn acquire_free(address: IpAddr, start: u16, end: u16) -> Result<PortLease, CommandError> {
+        (start..=end)
+            .find_map(|port| PortLease::acquire(address, port).ok())
+            .ok_or_else(|| CommandError::Failed(format!("No free port in {}-{}", start, end)))
+    }
+
+    pub fn port(&self) -> u16 {
+        self.address.port()
+    }
+
+    pub fn address(&self) -> SocketAddr {
+        self.address
+    }
+
+    /**
+     * 포트를 놓는다. drop과 같다.
+     */
+    pub fn release(self) {}
+
+    /**
+     * 붙잡고 있던 listener를 넘겨준다. 포트를 놓지 않고 서버가 이어서 사용할 수 있다.
+     */
+    pub fn hand_off(self) -> TcpListener {
+        self.listener
+    }
+}
+
+/**
+ * UI에서 예약한 포트를 앱이 종료되거나 해제할 때까지 보관한다.
+ */
+#[derive(Default)]
+pub struct ReservationStore {
+    leases: Mutex<BTreeMap<SocketAddr, (PortLease, u64)>>,
+}
+
+impl ReservationStore {
+    /**
+     * 모든 주소에서 포트를 예약한다. 하나라도 실패하면 아무것도 예약하지 않는다.
+     */
+    pub fn reserve(
+        &self,
+        port: u16,
+        addresses: &[IpAddr],
+    ) -> Result<Vec<Reservation>, CommandError> {
+        let leases = addresses
+            .iter()
+            .map(|address| PortLease::acquire(*address, port))
+            .collect::<Result<Vec<PortLease>, CommandError>>()?;
+
+        let reserved_at = now_millis();
+        let mut store = self.leases.lock().unwrap();
+
+        Ok(leases
+            .into_iter()
+            .map(|lease| {
+                let reservation = to_reservation(&lease, reserved_at);
+                store.insert(lease.address(), (lease, reserved_at));
+                reservation
+            })
+            .collect())
+    }
+
+    /**
+     * 포트의 예약을 모두 해제하고 해제한 개수를 돌려준다.
+     */
+    pub fn release(&self, port: u16) -> usize {
+        let mut store = self.leases.lock().unwrap();
+        let before = store.len();
+        store.retain(|address, _| address.port() != port);
+        before - store.len()
+    }
+
+    pub fn list(&self) -> Vec<Reservation> {
+        self.leases
+            .lock()
+            .unwrap()
+            .values()
+            .map(|(lease, reserved_at)| to_reservation(lease, *reserved_at))
+            .collect()
+    }
+}
+
+fn to_reservation(lease: &PortLease, reserved_at: u64) -> Reservation {
+    Reservation {
+        port: lease.port(),
+        address: lease.address().ip().to_string(),
+        reserved_at,
+    }
+}
+
+/**
+ * 목록에서 이 프로세스가 점유한 소켓을 예약으로 표시한다.
+ * 앱은 예약 외에는 포트를 열지 않는다.
+ */
+pub fn mark_reserved(rows: &mut [PortInfo], own_pid: u32) {
+    let own_pid = own_pid.to_string();
+
+    for row in rows.iter_mut().filter(|row| row.pid == own_pid) {
+        row.reserved = true;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::availability::parse_addresses;
+
+    fn localhost() -> IpAddr {
+        "127.0.0.1".parse().unwrap()
+    }
+
+    #[test]
+    fn test_lease_holds_port_until_drop() {
+        let lease = PortLease::acquire(localhost(), 0).unwrap();
+        let port = lease.port();
+
+        assert!(TcpListener::bind((localhost(), port)).is_err());
+
+        lease.release();
+
+        assert!(TcpListener::bind((localhost(), port)).is_ok());
+    }
+
+    #[test]
+    fn test_lease_hand_off_keeps_port() {
+        let lease = PortLease::acquire(localhost(), 0).unwrap();
+        let port = lease.port();
+
+        let listener = lease.hand_off();
+
+        assert_eq!(listener.local_addr().unwrap().port(), port);
+        assert!(TcpListener::bind((localhost(), port)).is_err());
+    }
+
+    // 사용 중인 첫 포트를 건너뛴다
+    #[test]
+    fn test_lease_acquire_free() {
+        let first = PortLease::acquire(localhost(), 0).unwrap();
+        let start = first.port();
+
+        let second = PortLease::acquire_free(localhost(), start, start.saturating_add(20)).unwrap();
+
+        assert!(second.port() > start);
+    }
+
+    #[test]
+    fn test_reservation_store() {
+        let store = ReservationStore::default();
+        let port = PortLease::acquire(localhost(), 0).unwrap().port();
+
+        let reservations = store.reserve(port, &[localhost()]).unwrap();
+
+        assert_eq!(reservations.len(), 1);
+        assert_eq!(store.list(), reservations);
+        assert!(store.reserve(port, &[localhost()]).is_err());
+
+        assert_eq!(store.release(port), 1);
+        assert!(store.list().is_empty());
+        assert!(TcpListener::bind((localhost(), port)).is_ok());
+    }
+
+    // 기본 주소인 0.0.0.0과 [::]를 함께 예약한다.
+    #[test]
+    fn test_reservation_store_default_addresses() {
+        let store = ReservationStore::default();
+        let addresses = parse_addresses(&[]).unwrap();
+        let port = PortLease::acquire(addresses[0], 0).unwrap().port();
+
+        let reservations = store.reserve(port, &addresses).unwrap();
+
+        assert_eq!(reservations.len(), 2);
+        assert!(TcpListener::bind((addresses[0], port)).is_err());
+
+        assert_eq!(store.release(port), 2);
+    }
+
+    #[test]
+    fn test_mark_reserved() {
+        let mut rows = vec![
+            PortInfo {
+                pid: "100".to_string(),
+                ..PortInfo::default()
+            },
+            PortInfo {
+                pid: "200".to_string(),
+                ..PortInfo::default()
+            },
+        ];
+
+        mark_reserved(&mut rows, 100);
+
+        assert!(rows[0].reserved);
+        assert!(!rows[1].reserved);
+    }
+}
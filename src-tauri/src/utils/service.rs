@@ -0,0 +1,216 @@
+use std::collections::HashMap;
+use std::fs;
+use std::sync::OnceLock;
+
+use crate::structs::port::PortInfo;
+
+// 개발 도구가 기본으로 사용하는 포트, /etc/services보다 우선한다.
+const DEV_TOOL_PORTS: [(u16, &str); 20] = [
+    (1420, "Tauri dev server"),
+    (3000, "Dev server (React/Next/Rails)"),
+    (4000, "Phoenix / Jekyll dev server"),
+    (4200, "Angular dev server"),
+    (5000, "Flask / ASP.NET dev server"),
+    (5173, "Vite dev server"),
+    (5555, "Prisma Studio"),
+    (6006, "Storybook"),
+    (8000, "Django / uvicorn dev server"),
+    (8080, "webpack-dev-server / HTTP alt"),
+    (8081, "Metro bundler"),
+    (8888, "Jupyter"),
+    (9000, "PHP-FPM / SonarQube"),
+    (9229, "Node inspector"),
+    (9230, "Node inspector (child)"),
+    (9323, "Playwright report"),
+    (11434, "Ollama"),
+    (19000, "Expo dev server"),
+    (24678, "Vite HMR"),
+    (35729, "LiveReload"),
+];
+
+// /etc/services가 없는 환경(Windows, 컨테이너)을 위한 IANA 등록 포트 일부
+const IANA_PORTS: [(u16, &str); 32] = [
+    (20, "ftp-data"),
+    (21, "ftp"),
+    (22, "ssh"),
+    (23, "telnet"),
+    (25, "smtp"),
+    (53, "domain"),
+    (67, "bootps"),
+    (80, "http"),
+    (110, "pop3"),
+    (123, "ntp"),
+    (135, "msrpc"),
+    (137, "netbios-ns"),
+    (139, "netbios-ssn"),
+    (143, "imap"),
+    (161, "snmp"),
+    (389, "ldap"),
+    (443, "https"),
+    (445, "microsoft-ds"),
+    (465, "submissions"),
+    (587, "submission"),
+    (631, "ipp"),
+    (993, "imaps"),
+    (995, "pop3s"),
+    (1433, "ms-sql-s"),
+    (1521, "oracle"),
+    (2375, "docker"),
+    (3306, "mysql"),
+    (3389, "ms-wbt-server"),
+    (5432, "postgresql"),
+    (5672, "amqp"),
+    (6379, "redis"),
+    (27017, "mongodb"),
+];
+
+/**
+ * /etc/services 형식을 (port, protocol) => 서비스 이름으로 파싱한다.
+ * 같은 포트가 여러 번 나오면 처음 이름을 사용한다.
+ *
+ * ex)
+ * `postgresql      5432/tcp        postgres   # PostgreSQL Database`
+ */
+pub fn parse_services(content: &str) -> HashMap<(u16, String), String> {
+    let mut services = HashMap::new();
+
+    for line in content.lines() {
+        let line = line.split('#').next().unwrap_or_default();
+        let mut columns = line.split_whitespace();
+
+        let (Some(name), Some(port_protocol)) = (columns.next(), columns.next()) else {
+            continue;
+        };
+        let Some((port, protocol)) = port_protocol.split_once('/') else {
+            continue;
+        };
+        let Ok(port) = port.parse::<u16>() else {
+            continue;
+        };
+
+        services
+            .entry((port, protocol.to_lowercase()))
+            .or_insert_with(|| name.to_string());
+    }
+
+    services
+}
+
+/**
+ * 포트 번호에 사람이 읽을 수 있는 이름을 붙인다.
+ * 개발 도구 표 => /etc/services => 내장 IANA 표 순서로 찾는다.
+ */
+pub struct PortLabeler {
+    services: HashMap<(u16, String), String>,
+}
+
+impl PortLabeler {
+    pub fn new(services: HashMap<(u16, String), String>) -> Self {
+        PortLabeler { services }
+    }
+
+    pub fn from_system() -> Self {
+        let services = fs::read_to_string("/etc/services")
+            .map(|content| parse_services(&content))
+            .unwrap_or_default();
+        PortLabeler::new(services)
+    }
+
+    pub fn label(&self, port: &str, protocol: &str) -> Option<String> {
+        let port = port.parse::<u16>().ok()?;
+
+        if let Some((_, name)) = DEV_TOOL_PORTS.iter().find(|(known, _)| *known == port) {
+            return Some(name.to_string());
+        }
+
+        if let Some(name) = self.services.get(&(port, protocol.to_lowercase())) {
+            return Some(name.clone());
+        }
+
+        IANA_PORTS
+            .iter()
+            .find(|(known, _)| *known == port)
+            .map(|(_, name)| name.to_string())
+    }
+
+    pub fn apply(&self, rows: &mut [PortInfo]) {
+        for row in rows.iter_mut() {
+            row.service = self.label(&row.port, &row.protocol);
+        }
+    }
+}
+
+/**
+ * /etc/services는 실행 중 바뀌지 않으므로 한 번만 읽는다.
+ */
+pub fn system_labeler() -> &'static PortLabeler {
+    static LABELER: OnceLock<PortLabeler> = OnceLock::new();
+    LABELER.get_or_init(PortLabeler::from_system)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SERVICES: &str = "\
+# Network services, Internet style
+tcpmux          1/tcp                           # TCP port service multiplexer
+postgresql      5432/tcp        postgres        # PostgreSQL Database
+hbci            3000/tcp
+x11             6000/tcp        x11-0
+x11             6000/udp
+broken line
+";
+
+    #[test]
+    fn test_parse_services() {
+        let services = parse_services(SERVICES);
+
+        assert_eq!(
+            services.get(&(5432, "tcp".to_string())),
+            Some(&"postgresql".to_string())
+        );
+        assert_eq!(
+            services.get(&(6000, "udp".to_string())),
+            Some(&"x11".to_string())
+        );
+        assert_eq!(services.len(), 5);
+    }
+
+    // 개발 도구 표가 /etc/services보다 우선한다
+    #[test]
+    fn test_label_prefers_dev_tools() {
+        let labeler = PortLabeler::new(parse_services(SERVICES));
+
+        assert_eq!(
+            labeler.label("3000", "TCP"),
+            Some("Dev server (React/Next/Rails)".to_string())
+        );
+        assert_eq!(labeler.label("24678", "TCP"), Some("Vite HMR".to_string()));
+        assert_eq!(labeler.label("5432", "TCP"), Some("postgresql".to_string()));
+    }
+
+    // /etc/services가 없으면 내장 표를 사용한다
+    #[test]
+    fn test_label_fallback_to_iana() {
+        let labeler = PortLabeler::new(HashMap::new());
+
+        assert_eq!(labeler.label("6379", "TCP"), Some("redis".to_string()));
+        assert_eq!(labeler.label("49152", "TCP"), None);
+        assert_eq!(labeler.label("*", "UDP"), None);
+    }
+
+    #[test]
+    fn test_apply() {
+        let labeler = PortLabeler::new(HashMap::new());
+        let mut rows = vec![PortInfo {
+            port: "9229".to_string(),
+            protocol: "TCP".to_string(),
+            ..PortInfo::default()
+        }];
+
+        labeler.apply(&mut rows);
+
+        assert_eq!(rows[0].service, Some("Node inspector".to_string()));
+    }
+}
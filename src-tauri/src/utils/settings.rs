@@ -0,0 +1,88 @@
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use crate::{enums::error::CommandError, structs::settings::Settings};
+
+/**
+ * 설정 파일과 메모리상의 설정을 함께 관리한다.
+ */
+pub struct SettingsStore {
+    path: PathBuf,
+    settings: Mutex<Settings>,
+}
+
+impl SettingsStore {
+    /**
+     * 파일이 없거나 읽을 수 없으면 기본 설정으로 시작한다.
+     */
+    pub fn load(path: PathBuf) -> Self {
+        let settings = fs::read_to_string(&path)
+            .ok()
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default();
+
+        SettingsStore {
+            path,
+            settings: Mutex::new(settings),
+        }
+    }
+
+    pub fn get(&self) -> Settings {
+        self.settings.lock().unwrap().clone()
+    }
+
+    pub fn update(&self, settings: Settings) -> Result<Settings, CommandError> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)
+                .map_err(|e| CommandError::Failed(format!("Failed to create settings dir: {}", e)))?;
+        }
+
+        let json = serde_json::to_string_pretty(&settings)
+            .map_err(|e| CommandError::Failed(format!("Failed to serialize settings: {}", e)))?;
+        fs::write(&self.path, json)
+            .map_err(|e| CommandError::Failed(format!("Failed to write settings: {}", e)))?;
+
+        *self.settings.lock().unwrap() = settings.clone();
+        Ok(settings)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_settings_store_roundtrip() {
+        let path = std::env::temp_dir().join(format!(
+            "open_port_check_settings_{}/settings.json",
+            std::process::id()
+        ));
+        let store = SettingsStore::load(path.clone());
+        assert_eq!(store.get(), Settings::default());
+
+        let settings = Settings {
+            history_enabled: true,
+            history_retention_days: 30,
+            ..Settings::default()
+        };
+        store.update(settings.clone()).unwrap();
+
+        let reloaded = SettingsStore::load(path.clone());
+        let _ = fs::remove_dir_all(path.parent().unwrap());
+
+        assert_eq!(reloaded.get(), settings);
+    }
+
+    // 이전 버전의 설정 파일에 없는 필드는 기본값을 사용한다
+    #[test]
+    fn test_settings_missing_fields() {
+        let settings: Settings = serde_json::from_str(r#"{"historyEnabled":true}"#).unwrap();
+
+        assert!(settings.history_enabled);
+        assert_eq!(
+            settings.history_retention_days,
+            crate::structs::settings::DEFAULT_HISTORY_RETENTION_DAYS
+        );
+    }
+}
@@ -0,0 +1,168 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use crate::{
+    enums::error::CommandError,
+    structs::{
+        port::PortInfo,
+        snapshot::{ChangedPort, Snapshot, SnapshotDiff},
+    },
+    utils::time::now_millis,
+};
+
+/**
+ * 소켓(protocol, address, port) 단위로 행을 묶는다. 같은 소켓의 행은 pid 순으로 정렬한다.
+ */
+fn group_by_socket(ports: &[PortInfo]) -> BTreeMap<(String, String, String), Vec<PortInfo>> {
+    let mut sockets: BTreeMap<(String, String, String), Vec<PortInfo>> = BTreeMap::new();
+
+    for port in ports {
+        sockets
+            .entry((
+                port.protocol.clone(),
+                port.address.clone(),
+                port.port.clone(),
+            ))
+            .or_default()
+            .push(port.clone());
+    }
+
+    for owners in sockets.values_mut() {
+        owners.sort_by(|a, b| a.pid.cmp(&b.pid).then(a.process_name.cmp(&b.process_name)));
+    }
+
+    sockets
+}
+
+/**
+ * 두 스냅샷을 비교한다.
+ * 소켓이 새로 생기면 added, 사라지면 removed,
+ * 소켓은 같지만 pid나 프로세스 이름이 다르면 changed로 분류한다.
+ */
+pub fn diff_snapshots(before: &[PortInfo], after: &[PortInfo]) -> SnapshotDiff {
+    let before = group_by_socket(before);
+    let mut after = group_by_socket(after);
+    let mut diff = SnapshotDiff::default();
+
+    for (socket, before_owners) in before {
+        match after.remove(&socket) {
+            None => diff.removed.extend(before_owners),
+            Some(after_owners) => {
+                let owners = |ports: &[PortInfo]| -> Vec<(String, String)> {
+                    ports
+                        .iter()
+                        .map(|port| (port.pid.clone(), port.process_name.clone()))
+                        .collect()
+                };
+
+                if owners(&before_owners) != owners(&after_owners) {
+                    diff.changed.push(ChangedPort {
+                        before: before_owners,
+                        after: after_owners,
+                    });
+                }
+            }
+        }
+    }
+
+    // before에 없던 소켓만 남아 있다.
+    diff.added = after.into_values().flatten().collect();
+    diff
+}
+
+pub fn create_snapshot(ports: Vec<PortInfo>) -> Snapshot {
+    Snapshot {
+        taken_at: now_millis(),
+        ports,
+    }
+}
+
+pub fn save_snapshot(path: &Path, snapshot: &Snapshot) -> Result<(), CommandError> {
+    let json = serde_json::to_string_pretty(snapshot)
+        .map_err(|e| CommandError::Failed(format!("Failed to serialize snapshot: {}", e)))?;
+
+    fs::write(path, json)
+        .map_err(|e| CommandError::Failed(format!("Failed to write {}: {}", path.display(), e)))
+}
+
+pub fn load_snapshot(path: &Path) -> Result<Snapshot, CommandError> {
+    let json = fs::read_to_string(path)
+        .map_err(|e| CommandError::Failed(format!("Failed to read {}: {}", path.display(), e)))?;
+
+    serde_json::from_str(&json)
+        .map_err(|e| CommandError::Failed(format!("Invalid snapshot {}: {}", path.display(), e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn port_info(process: &str, port: &str, pid: &str) -> PortInfo {
+        PortInfo {
+            process_name: process.to_string(),
+            port: port.to_string(),
+            pid: pid.to_string(),
+            protocol: "TCP".to_string(),
+            address: "127.0.0.1".to_string(),
+            ..PortInfo::default()
+        }
+    }
+
+    #[test]
+    fn test_diff_snapshots() {
+        let before = vec![
+            port_info("postgres", "5432", "100"),
+            port_info("node", "3000", "200"),
+            port_info("java", "8080", "300"),
+        ];
+        let after = vec![
+            port_info("postgres", "5432", "100"),
+            port_info("node", "3000", "250"),
+            port_info("vite", "5173", "400"),
+        ];
+
+        let diff = diff_snapshots(&before, &after);
+
+        assert_eq!(diff.added, vec![port_info("vite", "5173", "400")]);
+        assert_eq!(diff.removed, vec![port_info("java", "8080", "300")]);
+        assert_eq!(
+            diff.changed,
+            vec![ChangedPort {
+                before: vec![port_info("node", "3000", "200")],
+                after: vec![port_info("node", "3000", "250")],
+            }]
+        );
+    }
+
+    // 같은 소켓의 행 순서가 달라도 변경으로 보지 않는다
+    #[test]
+    fn test_diff_snapshots_ignores_order() {
+        let before = vec![port_info("nginx", "80", "1"), port_info("nginx", "80", "2")];
+        let after = vec![port_info("nginx", "80", "2"), port_info("nginx", "80", "1")];
+
+        assert!(diff_snapshots(&before, &after).is_empty());
+    }
+
+    #[test]
+    fn test_save_and_load_snapshot() {
+        let path = std::env::temp_dir().join(format!(
+            "open_port_check_snapshot_{}.json",
+            std::process::id()
+        ));
+        let snapshot = create_snapshot(vec![port_info("node", "3000", "200")]);
+
+        save_snapshot(&path, &snapshot).unwrap();
+        let loaded = load_snapshot(&path).unwrap();
+        let _ = fs::remove_file(&path);
+
+        assert_eq!(loaded, snapshot);
+    }
+
+    #[test]
+    fn test_load_snapshot_missing_file() {
+        let path = std::env::temp_dir().join("open_port_check_missing_snapshot.json");
+
+        assert!(load_snapshot(&path).is_err());
+    }
+}
@@ -0,0 +1,132 @@
+use std::sync::Mutex;
+use std::time::Duration;
+
+use tokio::sync::oneshot;
+
+use crate::enums::error::CommandError;
+
+pub const DEFAULT_TIMEOUT_MS: u64 = 10_000;
+
+/**
+ * 진행 중인 작업의 취소 신호를 보관한다.
+ * 새 작업을 시작하면 이전 작업은 자동으로 취소된다.
+ */
+#[derive(Default)]
+pub struct CancelRegistry {
+    current: Mutex<Option<oneshot::Sender<()>>>,
+}
+
+impl CancelRegistry {
+    /**
+     * 이전 작업을 취소하고 새 작업의 취소 수신자를 돌려준다.
+     */
+    pub fn begin(&self) -> oneshot::Receiver<()> {
+        let (tx, rx) = oneshot::channel();
+        let previous = self.current.lock().unwrap().replace(tx);
+
+        if let Some(previous) = previous {
+            // 이미 끝난 작업이면 수신자가 없으므로 결과는 무시
+            let _ = previous.send(());
+        }
+
+        rx
+    }
+
+    /**
+     * 진행 중인 작업을 취소한다. 취소할 작업이 있었는지 여부를 돌려준다.
+     */
+    pub fn cancel(&self) -> bool {
+        match self.current.lock().unwrap().take() {
+            Some(tx) => tx.send(()).is_ok(),
+            None => false,
+        }
+    }
+}
+
+/**
+ * 블로킹 작업(lsof, netstat 등)을 blocking pool에서 실행한다.
+ * 제한 시간이 지나거나 취소 신호가 오면 결과를 기다리지 않고 에러를 돌려준다.
+ */
+pub async fn run_blocking<T, F>(
+    job: F,
+    timeout_ms: u64,
+    cancel: Option<oneshot::Receiver<()>>,
+) -> Result<T, CommandError>
+where
+    T: Send + 'static,
+    F: FnOnce() -> Result<T, CommandError> + Send + 'static,
+{
+    let handle = tokio::task::spawn_blocking(job);
+    let cancelled = async {
+        match cancel {
+            Some(rx) => rx.await,
+            None => std::future::pending().await,
+        }
+    };
+
+    tokio::select! {
+        result = tokio::time::timeout(Duration::from_millis(timeout_ms), handle) => match result {
+            Ok(Ok(output)) => output,
+            Ok(Err(e)) => Err(CommandError::Failed(format!("Failed to join task: {}", e))),
+            Err(_) => Err(CommandError::Timeout(timeout_ms)),
+        },
+        // Sender가 drop된 경우는 취소로 보지 않는다.
+        Ok(()) = cancelled => Err(CommandError::Cancelled),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_run_blocking_success() {
+        let registry = CancelRegistry::default();
+        let result = run_blocking(|| Ok(42), 1_000, Some(registry.begin())).await;
+
+        assert_eq!(result, Ok(42));
+    }
+
+    #[tokio::test]
+    async fn test_run_blocking_timeout() {
+        let registry = CancelRegistry::default();
+        let result: Result<(), CommandError> = run_blocking(
+            || {
+                std::thread::sleep(Duration::from_millis(500));
+                Ok(())
+            },
+            50,
+            Some(registry.begin()),
+        )
+        .await;
+
+        assert_eq!(result, Err(CommandError::Timeout(50)));
+    }
+
+    // 새 작업이 시작되면 이전 작업은 취소된다
+    #[tokio::test]
+    async fn test_run_blocking_cancelled_by_new_request() {
+        let registry = CancelRegistry::default();
+        let first = registry.begin();
+        let _second = registry.begin();
+
+        let result: Result<(), CommandError> = run_blocking(
+            || {
+                std::thread::sleep(Duration::from_millis(500));
+                Ok(())
+            },
+            1_000,
+            Some(first),
+        )
+        .await;
+
+        assert_eq!(result, Err(CommandError::Cancelled));
+    }
+
+    #[tokio::test]
+    async fn test_cancel_without_running_task() {
+        let registry = CancelRegistry::default();
+
+        assert!(!registry.cancel());
+    }
+}
@@ -0,0 +1,475 @@
+use std::io::{Read, Write};
+use std::net::{SocketAddr, TcpStream};
+use std::time::{Duration, Instant};
+
+use crate::{
+    structs::fingerprint::TlsFingerprint,
+    utils::time::{civil_to_millis, now_millis},
+};
+
+// 인증서 체인 전체를 받아도 충분한 크기
+const MAX_HANDSHAKE_BYTES: usize = 64 * 1024;
+
+const RECORD_HANDSHAKE: u8 = 22;
+const RECORD_ALERT: u8 = 21;
+const HANDSHAKE_SERVER_HELLO: u8 = 2;
+const HANDSHAKE_CERTIFICATE: u8 = 11;
+const HANDSHAKE_SERVER_HELLO_DONE: u8 = 14;
+
+// commonName, organizationName
+const OID_COMMON_NAME: [u8; 3] = [0x55, 0x04, 0x03];
+const OID_ORGANIZATION: [u8; 3] = [0x55, 0x04, 0x0a];
+
+fn with_u16_length(body: Vec<u8>) -> Vec<u8> {
+    let mut bytes = (body.len() as u16).to_be_bytes().to_vec();
+    bytes.extend(body);
+    bytes
+}
+
+fn with_u24_length(body: Vec<u8>) -> Vec<u8> {
+    let mut bytes = (body.len() as u32).to_be_bytes()[1..].to_vec();
+    bytes.extend(body);
+    bytes
+}
+
+fn extension(kind: u16, body: Vec<u8>) -> Vec<u8> {
+    let mut bytes = kind.to_be_bytes().to_vec();
+    bytes.extend(with_u16_length(body));
+    bytes
+}
+
+/**
+ * TLS 1.2 ClientHello를 만든다.
+ * supported_versions를 보내지 않아 서버가 인증서를 암호화하지 않는 TLS 1.2로 응답하게 한다.
+ * 핸드셰이크를 끝내지 않으므로 random은 예측 가능해도 괜찮다.
+ */
+pub fn client_hello(server_name: &str) -> Vec<u8> {
+    let mut random = now_millis().to_be_bytes().to_vec();
+    random.resize(32, 0x5a);
+
+    let cipher_suites: Vec<u8> = [
+        0xc02b_u16, 0xc02f, 0xc02c, 0xc030, 0xcca9, 0xcca8, 0x009c, 0x009d, 0x002f, 0x0035,
+    ]
+    .iter()
+    .flat_map(|suite| suite.to_be_bytes())
+    .collect();
+
+    let host = server_name.as_bytes().to_vec();
+    let mut server_name_entry = vec![0x00];
+    server_name_entry.extend(with_u16_length(host));
+
+    let groups: Vec<u8> = [0x001d_u16, 0x0017, 0x0018]
+        .iter()
+        .flat_map(|group| group.to_be_bytes())
+        .collect();
+    let signature_algorithms: Vec<u8> = [
+        0x0403_u16, 0x0503, 0x0603, 0x0804, 0x0805, 0x0806, 0x0401, 0x0501, 0x0601, 0x0201,
+    ]
+    .iter()
+    .flat_map(|algorithm| algorithm.to_be_bytes())
+    .collect();
+
+    let extensions = [
+        extension(0x0000, with_u16_length(server_name_entry)),
+        extension(0x000a, with_u16_length(groups)),
+        extension(0x000b, vec![0x01, 0x00]),
+        extension(0x000d, with_u16_length(signature_algorithms)),
+    ]
+    .concat();
+
+    let mut body = vec![0x03, 0x03];
+    body.extend(random);
+    body.push(0x00);
+    body.extend(with_u16_length(cipher_suites));
+    body.extend([0x01, 0x00]);
+    body.extend(with_u16_length(extensions));
+
+    let mut handshake = vec![0x01];
+    handshake.extend(with_u24_length(body));
+
+    let mut record = vec![RECORD_HANDSHAKE, 0x03, 0x01];
+    record.extend(with_u16_length(handshake));
+    record
+}
+
+/**
+ * DER의 (tag, 내용, 전체 길이)를 읽는다.
+ */
+fn read_der(bytes: &[u8]) -> Option<(u8, &[u8], usize)> {
+    let tag = *bytes.first()?;
+    let first = *bytes.get(1)? as usize;
+
+    let (length, header) = if first < 0x80 {
+        (first, 2)
+    } else {
+        let count = first & 0x7f;
+        if count == 0 || count > 4 {
+            return None;
+        }
+        let length = bytes
+            .get(2..2 + count)?
+            .iter()
+            .fold(0_usize, |acc, byte| (acc << 8) | *byte as usize);
+        (length, 2 + count)
+    };
+
+    let content = bytes.get(header..header + length)?;
+    Some((tag, content, header + length))
+}
+
+/**
+ * SEQUENCE 안의 요소를 순서대로 나눈다. (tag, 내용, 원본 바이트)
+ */
+fn der_children(content: &[u8]) -> Vec<(u8, &[u8], &[u8])> {
+    let mut children = Vec::new();
+    let mut rest = content;
+
+    while let Some((tag, inner, size)) = read_der(rest) {
+        children.push((tag, inner, &rest[..size]));
+        rest = &rest[size..];
+    }
+
+    children
+}
+
+/**
+ * Name에서 CN을 찾고, 없으면 O를 사용한다.
+ */
+fn name_to_string(name: &[u8]) -> Option<String> {
+    let attributes: Vec<(&[u8], String)> = der_children(name)
+        .into_iter()
+        .flat_map(|(_, set, _)| der_children(set))
+        .filter_map(|(_, attribute, _)| {
+            let children = der_children(attribute);
+            let (_, oid, _) = children.first()?;
+            let (_, value, _) = children.get(1)?;
+            Some((*oid, String::from_utf8_lossy(value).to_string()))
+        })
+        .collect();
+
+    [OID_COMMON_NAME, OID_ORGANIZATION].iter().find_map(|oid| {
+        attributes
+            .iter()
+            .find(|(attribute, _)| *attribute == oid.as_slice())
+            .map(|(_, value)| value.clone())
+    })
+}
+
+/**
+ * UTCTime(YYMMDDHHMMSSZ), GeneralizedTime(YYYYMMDDHHMMSSZ)을 unix time (ms)로 바꾼다.
+ */
+pub fn parse_der_time(tag: u8, value: &[u8]) -> Option<u64> {
+    let text = std::str::from_utf8(value).ok()?.trim_end_matches('Z');
+    let number = |range: std::ops::Range<usize>| text.get(range)?.parse::<u32>().ok();
+
+    let (year, rest) = match tag {
+        // UTCTime은 50 이상이면 19xx
+        0x17 => {
+            let year = number(0..2)? as i64;
+            (if year >= 50 { 1900 + year } else { 2000 + year }, 2)
+        }
+        0x18 => (number(0..4)? as i64, 4),
+        _ => return None,
+    };
+
+    civil_to_millis(
+        year,
+        number(rest..rest + 2)?,
+        number(rest + 2..rest + 4)?,
+        number(rest + 4..rest + 6)?,
+        number(rest + 6..rest + 8)?,
+        number(rest + 8..rest + 10).unwrap_or(0),
+    )
+}
+
+/**
+ * X.509 인증서에서 subject, issuer, 만료 시각을 읽는다. 서명은 검증하지 않는다.
+ */
+pub fn parse_certificate(der: &[u8]) -> Option<TlsFingerprint> {
+    let (_, certificate, _) = read_der(der)?;
+    let (_, tbs, _) = read_der(certificate)?;
+    let mut fields = der_children(tbs);
+
+    // [0] version은 생략될 수 있다.
+    if fields.first().map(|(tag, _, _)| *tag) == Some(0xa0) {
+        fields.remove(0);
+    }
+
+    // serial, signature, issuer, validity, subject
+    let (_, issuer, issuer_raw) = fields.get(2)?;
+    let (_, validity, _) = fields.get(3)?;
+    let (_, subject, subject_raw) = fields.get(4)?;
+
+    let not_after = der_children(validity)
+        .get(1)
+        .and_then(|(tag, value, _)| parse_der_time(*tag, value));
+
+    Some(TlsFingerprint {
+        subject: name_to_string(subject),
+        issuer: name_to_string(issuer),
+        not_after,
+        self_signed: issuer_raw == subject_raw,
+    })
+}
+
+/**
+ * 서버가 보낸 레코드들에서 ServerHello와 첫 번째 인증서를 찾는다.
+ * TLS가 아니면 None, 인증서를 볼 수 없으면 빈 TlsFingerprint
+ */
+pub fn parse_server_flight(bytes: &[u8]) -> Option<TlsFingerprint> {
+    let mut handshake = Vec::new();
+    let mut rest = bytes;
+
+    while rest.len() >= 5 {
+        let kind = rest[0];
+        let length = u16::from_be_bytes([rest[3], rest[4]]) as usize;
+        let Some(payload) = rest.get(5..5 + length) else {
+            break;
+        };
+
+        match kind {
+            RECORD_HANDSHAKE => handshake.extend_from_slice(payload),
+            // 경고로 끊더라도 TLS 서버임은 알 수 있다.
+            RECORD_ALERT if rest[1] == 0x03 => return Some(TlsFingerprint::default()),
+            _ => break,
+        }
+        rest = &rest[5 + length..];
+    }
+
+    let mut server_hello = false;
+    let mut messages = handshake.as_slice();
+
+    while messages.len() >= 4 {
+        let kind = messages[0];
+        let length = u32::from_be_bytes([0, messages[1], messages[2], messages[3]]) as usize;
+        let Some(body) = messages.get(4..4 + length) else {
+            break;
+        };
+
+        match kind {
+            HANDSHAKE_SERVER_HELLO => server_hello = true,
+            HANDSHAKE_CERTIFICATE => {
+                // certificate_list 길이(3) 뒤에 첫 인증서 길이(3)와 DER
+                let size =
+                    u32::from_be_bytes([0, *body.get(3)?, *body.get(4)?, *body.get(5)?]) as usize;
+                let der = body.get(6..6 + size)?;
+                return parse_certificate(der).or(Some(TlsFingerprint::default()));
+            }
+            HANDSHAKE_SERVER_HELLO_DONE => break,
+            _ => {}
+        }
+        messages = &messages[4 + length..];
+    }
+
+    server_hello.then(TlsFingerprint::default)
+}
+
+/**
+ * ClientHello를 보내고 서버 인증서를 받을 때까지 읽는다.
+ */
+pub fn fingerprint_tls(target: SocketAddr, timeout: Duration) -> Option<TlsFingerprint> {
+    let deadline = Instant::now() + timeout;
+    let mut stream = TcpStream::connect_timeout(&target, timeout).ok()?;
+    stream.set_read_timeout(Some(timeout)).ok()?;
+    stream.set_write_timeout(Some(timeout)).ok()?;
+
+    stream.write_all(&client_hello("localhost")).ok()?;
+
+    let mut raw = Vec::new();
+    let mut buffer = [0_u8; 8 * 1024];
+    while raw.len() < MAX_HANDSHAKE_BYTES && Instant::now() < deadline {
+        match stream.read(&mut buffer) {
+            Ok(0) | Err(_) => break,
+            Ok(read) => raw.extend_from_slice(&buffer[..read]),
+        }
+
+        // 인증서까지 받았다면 더 기다리지 않는다.
+        if let Some(fingerprint) = parse_server_flight(&raw) {
+            if fingerprint.subject.is_some() || fingerprint.not_after.is_some() {
+                return Some(fingerprint);
+            }
+        }
+    }
+
+    parse_server_flight(&raw)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::TcpListener;
+    use std::thread;
+
+    const TIMEOUT: Duration = Duration::from_millis(500);
+
+    fn der(tag: u8, content: &[u8]) -> Vec<u8> {
+        let mut bytes = vec![tag];
+        if content.len() < 0x80 {
+            bytes.push(content.len() as u8);
+        } else {
+            bytes.push(0x82);
+            bytes.extend((content.len() as u16).to_be_bytes());
+        }
+        bytes.extend_from_slice(content);
+        bytes
+    }
+
+    fn name(common_name: &str) -> Vec<u8> {
+        let attribute = der(
+            0x30,
+            &[
+                der(0x06, &OID_COMMON_NAME),
+                der(0x0c, common_name.as_bytes()),
+            ]
+            .concat(),
+        );
+        der(0x30, &der(0x31, &attribute))
+    }
+
+    /**
+     * 필요한 필드만 채운 테스트용 인증서
+     */
+    fn certificate(subject: &str, issuer: &str, not_after: &str) -> Vec<u8> {
+        let algorithm = der(
+            0x30,
+            &der(0x06, &[0x2a, 0x86, 0x48, 0xce, 0x3d, 0x04, 0x03, 0x02]),
+        );
+        let validity = der(
+            0x30,
+            &[der(0x17, b"240101000000Z"), der(0x17, not_after.as_bytes())].concat(),
+        );
+        let tbs = der(
+            0x30,
+            &[
+                der(0xa0, &der(0x02, &[0x02])),
+                der(0x02, &[0x01]),
+                algorithm.clone(),
+                name(issuer),
+                validity,
+                name(subject),
+            ]
+            .concat(),
+        );
+
+        der(0x30, &[tbs, algorithm, der(0x03, &[0x00])].concat())
+    }
+
+    fn record(kind: u8, payload: &[u8]) -> Vec<u8> {
+        let mut bytes = vec![kind, 0x03, 0x03];
+        bytes.extend((payload.len() as u16).to_be_bytes());
+        bytes.extend_from_slice(payload);
+        bytes
+    }
+
+    fn server_flight(certificate: &[u8]) -> Vec<u8> {
+        let mut server_hello = vec![HANDSHAKE_SERVER_HELLO];
+        server_hello.extend(with_u24_length(vec![0x03, 0x03]));
+
+        let mut certificate_message = vec![HANDSHAKE_CERTIFICATE];
+        certificate_message.extend(with_u24_length(with_u24_length(with_u24_length(
+            certificate.to_vec(),
+        ))));
+
+        [
+            record(RECORD_HANDSHAKE, &server_hello),
+            record(RECORD_HANDSHAKE, &certificate_message),
+        ]
+        .concat()
+    }
+
+    /**
+     * ClientHello를 받으면 준비한 응답을 보내는 가짜 TLS 서버
+     */
+    fn fake_tls(response: Vec<u8>) -> SocketAddr {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+
+        thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut buffer = [0_u8; 1024];
+            let read = stream.read(&mut buffer).unwrap();
+            if read > 0 && buffer[0] == RECORD_HANDSHAKE {
+                let _ = stream.write_all(&response);
+            }
+        });
+
+        address
+    }
+
+    #[test]
+    fn test_client_hello() {
+        let hello = client_hello("localhost");
+
+        assert_eq!(hello[0], RECORD_HANDSHAKE);
+        assert_eq!(
+            u16::from_be_bytes([hello[3], hello[4]]) as usize,
+            hello.len() - 5
+        );
+        assert_eq!(hello[5], 0x01);
+        assert!(hello.windows(9).any(|window| window == b"localhost"));
+    }
+
+    #[test]
+    fn test_parse_der_time() {
+        assert_eq!(parse_der_time(0x17, b"700101000000Z"), Some(0));
+        assert_eq!(
+            parse_der_time(0x17, b"240229120000Z"),
+            Some(1_709_208_000_000)
+        );
+        assert_eq!(
+            parse_der_time(0x18, b"20500101000000Z"),
+            Some(2_524_608_000_000)
+        );
+        assert_eq!(parse_der_time(0x17, b"garbage"), None);
+    }
+
+    #[test]
+    fn test_parse_certificate() {
+        let fingerprint =
+            parse_certificate(&certificate("localhost", "Local CA", "240229120000Z")).unwrap();
+
+        assert_eq!(
+            fingerprint,
+            TlsFingerprint {
+                subject: Some("localhost".to_string()),
+                issuer: Some("Local CA".to_string()),
+                not_after: Some(1_709_208_000_000),
+                self_signed: false,
+            }
+        );
+    }
+
+    #[test]
+    fn test_fingerprint_tls_self_signed() {
+        let address = fake_tls(server_flight(&certificate(
+            "dev.local",
+            "dev.local",
+            "491231235959Z",
+        )));
+
+        let fingerprint = fingerprint_tls(address, TIMEOUT).unwrap();
+
+        assert_eq!(fingerprint.subject, Some("dev.local".to_string()));
+        assert!(fingerprint.self_signed);
+        assert!(fingerprint.not_after.is_some());
+    }
+
+    // 핸드셰이크를 거절해도 TLS 서버임은 알 수 있다
+    #[test]
+    fn test_fingerprint_tls_alert() {
+        let address = fake_tls(record(RECORD_ALERT, &[0x02, 0x28]));
+
+        assert_eq!(
+            fingerprint_tls(address, TIMEOUT),
+            Some(TlsFingerprint::default())
+        );
+    }
+
+    #[test]
+    fn test_parse_server_flight_not_tls() {
+        assert_eq!(
+            parse_server_flight(b"HTTP/1.1 400 Bad Request\r\n\r\n"),
+            None
+        );
+    }
+}
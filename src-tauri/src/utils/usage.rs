@@ -0,0 +1,314 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::sync::Mutex;
+
+use crate::{
+    structs::{port::PortInfo, usage::ProcessUsage},
+    traits::command::CommandExecutor,
+    utils::{
+        activity::clock_ticks_per_second,
+        detail::{parse_proc_cpu_ticks, parse_status_rss},
+        time::now_millis,
+    },
+};
+
+/**
+ * 운영체제에서 읽은 값 그대로
+ * CPU는 누적 시간(초)으로 받아 측정 사이의 차이로 사용률을 계산한다.
+ */
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct UsageReading {
+    pub pid: String,
+    pub cpu_seconds: Option<f64>,
+    pub memory_bytes: Option<u64>,
+}
+
+/**
+ * ps의 누적 CPU 시간
+ * 맥은 분:초.xx, 리눅스는 [일-]시:분:초 형식이다.
+ *
+ * ex) 12:34.56, 1:02:03, 2-01:00:00
+ */
+pub fn parse_cpu_time(value: &str) -> Option<f64> {
+    let (days, clock) = match value.split_once('-') {
+        Some((days, clock)) => (days.parse::<f64>().ok()?, clock),
+        None => (0.0, value),
+    };
+
+    let seconds = clock.split(':').try_fold(0.0, |total, field| {
+        field.parse::<f64>().ok().map(|field| total * 60.0 + field)
+    })?;
+
+    Some(days * 86_400.0 + seconds)
+}
+
+/**
+ * ps -o pid=,time=,rss= -p <pid,...> 결과
+ * %cpu는 프로세스 수명 전체의 평균이라 현재 사용률을 나타내지 못한다.
+ *
+ * ex)
+ *  1234   0:01.50  51234
+ */
+pub fn parse_ps_usage_table(output: &str) -> Vec<UsageReading> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let columns: Vec<&str> = line.split_whitespace().collect();
+            let [pid, time, rss] = columns.as_slice() else {
+                return None;
+            };
+
+            Some(UsageReading {
+                pid: pid.to_string(),
+                cpu_seconds: parse_cpu_time(time),
+                memory_bytes: rss.parse::<u64>().ok().map(|kilobytes| kilobytes * 1024),
+            })
+        })
+        .collect()
+}
+
+/**
+ * "<pid>,<누적 CPU ms>,<working set bytes>" 형식의 줄 (윈도우 PowerShell 결과)
+ * 소수점 표기가 지역 설정을 따르므로 CPU 시간은 정수 ms로 받는다.
+ */
+pub fn parse_usage_csv(output: &str) -> Vec<UsageReading> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.trim().split(',');
+            let pid = fields.next()?;
+            let cpu_millis = fields.next()?.parse::<u64>().ok();
+            let memory_bytes = fields.next()?.parse::<u64>().ok();
+
+            Some(UsageReading {
+                pid: pid.to_string(),
+                cpu_seconds: cpu_millis.map(|millis| millis as f64 / 1000.0),
+                memory_bytes,
+            })
+        })
+        .collect()
+}
+
+/**
+ * proc_root/<pid>/stat의 누적 CPU tick과 status의 VmRSS
+ * 테스트에서 가짜 /proc을 넘길 수 있도록 경로를 받는다.
+ */
+pub fn read_proc_usage(
+    proc_root: &Path,
+    pids: &[&str],
+    ticks_per_second: u64,
+) -> Vec<UsageReading> {
+    pids.iter()
+        .filter_map(|pid| {
+            let stat = fs::read_to_string(proc_root.join(pid).join("stat")).ok()?;
+            let status = fs::read_to_string(proc_root.join(pid).join("status")).ok();
+
+            Some(UsageReading {
+                pid: pid.to_string(),
+                cpu_seconds: parse_proc_cpu_ticks(&stat)
+                    .map(|ticks| ticks as f64 / ticks_per_second as f64),
+                memory_bytes: status.and_then(|status| parse_status_rss(&status)),
+            })
+        })
+        .collect()
+}
+
+fn read_usage(executor: &dyn CommandExecutor, pids: &[&str]) -> Vec<UsageReading> {
+    if cfg!(target_os = "windows") {
+        let script = format!(
+            "Get-Process -Id {} -ErrorAction SilentlyContinue | ForEach-Object {{ '{{0}},{{1}},{{2}}' -f $_.Id, [int64]$_.TotalProcessorTime.TotalMilliseconds, $_.WorkingSet64 }}",
+            pids.join(",")
+        );
+        executor
+            .execute_command(
+                "powershell",
+                &["-NoProfile", "-NonInteractive", "-Command", &script],
+            )
+            .map(|output| parse_usage_csv(&output))
+            .unwrap_or_default()
+    } else if cfg!(target_os = "linux") {
+        read_proc_usage(Path::new("/proc"), pids, clock_ticks_per_second(executor))
+    } else {
+        executor
+            .execute_command("ps", &["-o", "pid=,time=,rss=", "-p", &pids.join(",")])
+            .map(|output| parse_ps_usage_table(&output))
+            .unwrap_or_default()
+    }
+}
+
+/**
+ * 감시 간격마다 소켓을 가진 프로세스의 사용량을 측정한다.
+ * 누적 CPU 시간은 직전 측정과의 차이로 사용률을 계산한다.
+ */
+#[derive(Default)]
+pub struct UsageSampler {
+    // pid => (누적 CPU 시간 초, 측정 시각 ms)
+    previous: Mutex<HashMap<String, (f64, u64)>>,
+}
+
+impl UsageSampler {
+    /**
+     * 읽은 값을 직전 측정과 비교해 사용량으로 바꾼다.
+     * 사라진 pid는 잊으므로 같은 pid가 다시 쓰여도 이전 값과 섞이지 않는다.
+     */
+    pub fn apply(&self, readings: Vec<UsageReading>, now: u64) -> Vec<ProcessUsage> {
+        let mut previous = self.previous.lock().unwrap_or_else(|e| e.into_inner());
+        let mut current = HashMap::new();
+
+        let usage = readings
+            .into_iter()
+            .map(|reading| {
+                let cpu_percent = reading.cpu_seconds.and_then(|cpu_seconds| {
+                    current.insert(reading.pid.clone(), (cpu_seconds, now));
+
+                    let (last_seconds, last_at) = previous.get(&reading.pid)?;
+                    let elapsed = now.checked_sub(*last_at).filter(|elapsed| *elapsed > 0)?;
+                    // 누적 시간이 줄었다면 다른 프로세스가 같은 pid를 쓰고 있다.
+                    (cpu_seconds >= *last_seconds)
+                        .then(|| (cpu_seconds - last_seconds) / (elapsed as f64 / 1000.0) * 100.0)
+                });
+
+                ProcessUsage {
+                    pid: reading.pid,
+                    cpu_percent,
+                    memory_bytes: reading.memory_bytes,
+                    sampled_at: now,
+                }
+            })
+            .collect();
+
+        *previous = current;
+        usage
+    }
+
+    /**
+     * 소켓 목록에 있는 pid들의 사용량
+     */
+    pub fn sample(&self, executor: &dyn CommandExecutor, rows: &[PortInfo]) -> Vec<ProcessUsage> {
+        // 명령어 인자로 넘기므로 숫자인 pid만 사용한다. (소유자를 모르는 소켓은 "-")
+        let mut pids: Vec<&str> = rows
+            .iter()
+            .map(|row| row.pid.as_str())
+            .filter(|pid| !pid.is_empty() && pid.chars().all(|c| c.is_ascii_digit()))
+            .collect();
+        pids.sort();
+        pids.dedup();
+
+        if pids.is_empty() {
+            return Vec::new();
+        }
+
+        self.apply(read_usage(executor, &pids), now_millis())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reading(pid: &str, cpu_seconds: f64, memory_bytes: u64) -> UsageReading {
+        UsageReading {
+            pid: pid.to_string(),
+            cpu_seconds: Some(cpu_seconds),
+            memory_bytes: Some(memory_bytes),
+        }
+    }
+
+    #[test]
+    fn test_parse_cpu_time() {
+        assert_eq!(parse_cpu_time("0:01.50"), Some(1.5));
+        assert_eq!(parse_cpu_time("12:34.56"), Some(754.56));
+        assert_eq!(parse_cpu_time("1:02:03"), Some(3723.0));
+        assert_eq!(parse_cpu_time("2-01:00:00"), Some(176_400.0));
+        assert_eq!(parse_cpu_time("-"), None);
+    }
+
+    #[test]
+    fn test_parse_ps_usage_table() {
+        let readings =
+            parse_ps_usage_table(" 1234   0:01.50  2048\n   99  12:00.00     0\ngarbage\n");
+
+        assert_eq!(
+            readings,
+            vec![reading("1234", 1.5, 2048 * 1024), reading("99", 720.0, 0)]
+        );
+    }
+
+    #[test]
+    fn test_read_proc_usage() {
+        let root =
+            std::env::temp_dir().join(format!("open_port_check_usage_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("4321")).unwrap();
+        // utime 150, stime 50 tick
+        fs::write(
+            root.join("4321/stat"),
+            "4321 (my app) S 1 4321 4321 0 -1 4194560 100 0 0 0 150 50 0 0 20 0 1 0 98765 1000 200",
+        )
+        .unwrap();
+        fs::write(
+            root.join("4321/status"),
+            "Name:\tmy app\nVmRSS:\t    2048 kB\n",
+        )
+        .unwrap();
+
+        let readings = read_proc_usage(&root, &["4321", "9999"], 100);
+        fs::remove_dir_all(&root).unwrap();
+
+        // 사라진 pid는 건너뛴다.
+        assert_eq!(readings, vec![reading("4321", 2.0, 2048 * 1024)]);
+    }
+
+    #[test]
+    fn test_parse_usage_csv() {
+        let readings = parse_usage_csv("1234,1500,52461568\r\n4,,\r\n");
+
+        assert_eq!(readings[0], reading("1234", 1.5, 52_461_568));
+        assert_eq!(readings[1].cpu_seconds, None);
+        assert_eq!(readings[1].memory_bytes, None);
+    }
+
+    #[test]
+    fn test_apply_cpu_delta() {
+        let sampler = UsageSampler::default();
+
+        // 첫 측정은 비교할 값이 없다.
+        let first = sampler.apply(vec![reading("1", 10.0, 1024)], 1_000);
+        assert_eq!(first[0].cpu_percent, None);
+        assert_eq!(first[0].memory_bytes, Some(1024));
+
+        // 2초 동안 CPU 1초 => 50%
+        let second = sampler.apply(vec![reading("1", 11.0, 2048)], 3_000);
+        assert_eq!(second[0].cpu_percent, Some(50.0));
+        assert_eq!(second[0].sampled_at, 3_000);
+
+        // 누적 시간이 줄면 다른 프로세스로 보고 비우며, 다음 측정부터 다시 계산한다.
+        let reused = sampler.apply(vec![reading("1", 0.5, 2048)], 4_000);
+        assert_eq!(reused[0].cpu_percent, None);
+        let next = sampler.apply(vec![reading("1", 1.0, 2048)], 5_000);
+        assert_eq!(next[0].cpu_percent, Some(50.0));
+    }
+
+    #[test]
+    fn test_apply_forgets_closed_pids() {
+        let sampler = UsageSampler::default();
+
+        sampler.apply(vec![reading("1", 10.0, 0)], 1_000);
+        sampler.apply(vec![reading("2", 1.0, 0)], 2_000);
+        let usage = sampler.apply(vec![reading("1", 12.0, 0)], 3_000);
+
+        assert_eq!(usage[0].cpu_percent, None);
+    }
+
+    // 맥도 ps의 누적 시간 차이로 계산한다.
+    #[test]
+    fn test_apply_ps_usage_delta() {
+        let sampler = UsageSampler::default();
+
+        sampler.apply(parse_ps_usage_table(" 1234   0:10.00  2048\n"), 1_000);
+        let usage = sampler.apply(parse_ps_usage_table(" 1234   0:10.25  2048\n"), 2_000);
+
+        assert_eq!(usage[0].cpu_percent, Some(25.0));
+    }
+}
@@ -0,0 +1,298 @@
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::time::Duration;
+
+use tokio::sync::Notify;
+
+use crate::{
+    enums::error::CommandError,
+    structs::{
+        port::PortInfo,
+        watch::{WatchDiff, WatchStatus},
+    },
+    utils::task::{run_blocking, DEFAULT_TIMEOUT_MS},
+};
+
+pub const DEFAULT_WATCH_INTERVAL_MS: u64 = 3_000;
+pub const MIN_WATCH_INTERVAL_MS: u64 = 500;
+
+/**
+ * (protocol, address, port, pid)를 기준으로 두 스냅샷을 비교한다.
+ */
+pub fn diff_by_owner(previous: &[PortInfo], current: &[PortInfo]) -> WatchDiff {
+    let previous_keys: HashSet<String> = previous.iter().map(|port| port.key()).collect();
+    let current_keys: HashSet<String> = current.iter().map(|port| port.key()).collect();
+
+    WatchDiff {
+        opened: current
+            .iter()
+            .filter(|port| !previous_keys.contains(&port.key()))
+            .cloned()
+            .collect(),
+        closed: previous
+            .iter()
+            .filter(|port| !current_keys.contains(&port.key()))
+            .cloned()
+            .collect(),
+    }
+}
+
+/**
+ * 주기적으로 포트를 조회하는 감시자의 상태
+ * 앱이 시작될 때는 일시정지 상태이며, UI에서 재개한다.
+ * 이력 기록(REC)이 켜져 있으면 일시정지 중에도 조회는 계속한다.
+ */
+pub struct WatchState {
+    interval_ms: AtomicU64,
+    paused: AtomicBool,
+    recording: AtomicBool,
+    // 간격이 바뀌거나 재개될 때 대기 중인 루프를 깨운다.
+    wake: Notify,
+}
+
+impl Default for WatchState {
+    fn default() -> Self {
+        WatchState {
+            interval_ms: AtomicU64::new(DEFAULT_WATCH_INTERVAL_MS),
+            paused: AtomicBool::new(true),
+            recording: AtomicBool::new(false),
+            wake: Notify::new(),
+        }
+    }
+}
+
+impl WatchState {
+    pub fn status(&self) -> WatchStatus {
+        WatchStatus {
+            paused: self.paused.load(Ordering::SeqCst),
+            interval_ms: self.interval_ms.load(Ordering::SeqCst),
+        }
+    }
+
+    pub fn set_interval(&self, interval_ms: u64) {
+        self.interval_ms
+            .store(interval_ms.max(MIN_WATCH_INTERVAL_MS), Ordering::SeqCst);
+        self.wake.notify_one();
+    }
+
+    pub fn pause(&self) {
+        self.paused.store(true, Ordering::SeqCst);
+        self.wake.notify_one();
+    }
+
+    pub fn resume(&self) {
+        self.paused.store(false, Ordering::SeqCst);
+        self.wake.notify_one();
+    }
+
+    pub fn set_recording(&self, recording: bool) {
+        self.recording.store(recording, Ordering::SeqCst);
+        self.wake.notify_one();
+    }
+
+    /**
+     * 감시 루프, 간격마다 scan을 실행하고 변화가 있으면 on_diff를 호출한다.
+     * 일시정지 후 재개하면 첫 조회는 기준 스냅샷으로만 사용한다.
+     * 기록 중에는 조회 결과를 on_record(Some)로 넘기고, 기록이 꺼지면 on_record(None)을 한 번 호출한다.
+     */
+    pub async fn run<S, C, R>(&self, scan: S, mut on_diff: C, mut on_record: R)
+    where
+        S: Fn() -> Result<Vec<PortInfo>, CommandError> + Clone + Send + 'static,
+        C: FnMut(WatchDiff),
+        R: FnMut(Option<&[PortInfo]>),
+    {
+        let mut previous: Option<Vec<PortInfo>> = None;
+        let mut was_recording = false;
+
+        loop {
+            let live = !self.paused.load(Ordering::SeqCst);
+            let recording = self.recording.load(Ordering::SeqCst);
+
+            if was_recording && !recording {
+                on_record(None);
+                was_recording = false;
+            }
+            if !live {
+                previous = None;
+            }
+            if !live && !recording {
+                self.wake.notified().await;
+                continue;
+            }
+
+            let interval_ms = self.interval_ms.load(Ordering::SeqCst);
+            // 조회에 실패하면 모두 닫힌 것으로 보지 않도록 이전 스냅샷을 유지한다.
+            if let Ok(current) = run_blocking(scan.clone(), DEFAULT_TIMEOUT_MS, None).await {
+                if recording {
+                    on_record(Some(&current));
+                    was_recording = true;
+                }
+                if live {
+                    if let Some(previous) = &previous {
+                        let diff = diff_by_owner(previous, &current);
+                        if !diff.is_empty() {
+                            on_diff(diff);
+                        }
+                    }
+                    previous = Some(current);
+                }
+            }
+
+            // 간격이 바뀌거나 일시정지되면 바로 깨어난다.
+            let _ = tokio::time::timeout(
+                Duration::from_millis(interval_ms),
+                self.wake.notified(),
+            )
+            .await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    fn port_info(port: &str, pid: &str) -> PortInfo {
+        PortInfo {
+            process_name: "node".to_string(),
+            port: port.to_string(),
+            pid: pid.to_string(),
+            protocol: "TCP".to_string(),
+            address: "127.0.0.1".to_string(),
+            ..PortInfo::default()
+        }
+    }
+
+    #[test]
+    fn test_diff_by_owner() {
+        let previous = vec![port_info("3000", "1"), port_info("8080", "2")];
+        let current = vec![port_info("3000", "1"), port_info("8080", "3")];
+
+        let diff = diff_by_owner(&previous, &current);
+
+        assert_eq!(diff.opened, vec![port_info("8080", "3")]);
+        assert_eq!(diff.closed, vec![port_info("8080", "2")]);
+    }
+
+    #[test]
+    fn test_diff_by_owner_without_changes() {
+        let ports = vec![port_info("3000", "1")];
+
+        assert!(diff_by_owner(&ports, &ports).is_empty());
+    }
+
+    #[test]
+    fn test_set_interval_minimum() {
+        let state = WatchState::default();
+        state.set_interval(10);
+
+        assert_eq!(state.status().interval_ms, MIN_WATCH_INTERVAL_MS);
+    }
+
+    // 조회할 때마다 다음 스냅샷을 돌려주고, 변화만 on_diff로 전달되는지 확인
+    #[tokio::test]
+    async fn test_run_emits_diffs() {
+        let snapshots = Arc::new(Mutex::new(vec![
+            vec![port_info("3000", "1")],
+            vec![port_info("3000", "1"), port_info("5173", "2")],
+            vec![port_info("5173", "2")],
+        ]));
+        let diffs = Arc::new(Mutex::new(Vec::new()));
+
+        let state = WatchState::default();
+        state.set_interval(MIN_WATCH_INTERVAL_MS);
+        state.resume();
+
+        let scan = {
+            let snapshots = snapshots.clone();
+            move || {
+                let mut snapshots = snapshots.lock().unwrap();
+                if snapshots.len() > 1 {
+                    Ok(snapshots.remove(0))
+                } else {
+                    Ok(snapshots[0].clone())
+                }
+            }
+        };
+        let on_diff = {
+            let diffs = diffs.clone();
+            move |diff| diffs.lock().unwrap().push(diff)
+        };
+
+        let _ = tokio::time::timeout(
+            Duration::from_millis(1_300),
+            state.run(scan, on_diff, |_| {}),
+        )
+        .await;
+
+        let diffs = diffs.lock().unwrap();
+        assert_eq!(diffs.len(), 2);
+        assert_eq!(diffs[0].opened, vec![port_info("5173", "2")]);
+        assert_eq!(diffs[1].closed, vec![port_info("3000", "1")]);
+    }
+
+    #[tokio::test]
+    async fn test_run_paused_does_not_scan() {
+        let state = WatchState::default();
+        let scanned = Arc::new(AtomicBool::new(false));
+
+        let scan = {
+            let scanned = scanned.clone();
+            move || {
+                scanned.store(true, Ordering::SeqCst);
+                Ok(Vec::new())
+            }
+        };
+
+        let _ =
+            tokio::time::timeout(Duration::from_millis(100), state.run(scan, |_| {}, |_| {})).await;
+
+        assert!(!scanned.load(Ordering::SeqCst));
+    }
+
+    // 일시정지 중에도 기록은 계속하고, 기록이 꺼지면 한 번 알린다.
+    #[tokio::test]
+    async fn test_run_records_while_paused() {
+        let state = WatchState::default();
+        state.set_interval(MIN_WATCH_INTERVAL_MS);
+        state.set_recording(true);
+
+        let records = Arc::new(Mutex::new(Vec::new()));
+        let diffs = Arc::new(Mutex::new(0));
+
+        let on_record = {
+            let records = records.clone();
+            move |current: Option<&[PortInfo]>| {
+                records
+                    .lock()
+                    .unwrap()
+                    .push(current.map(|ports| ports.len()))
+            }
+        };
+        let on_diff = {
+            let diffs = diffs.clone();
+            move |_| *diffs.lock().unwrap() += 1
+        };
+        let stop = async {
+            tokio::time::sleep(Duration::from_millis(200)).await;
+            state.set_recording(false);
+            tokio::time::sleep(Duration::from_millis(200)).await;
+        };
+
+        let _ = tokio::time::timeout(Duration::from_millis(600), async {
+            tokio::join!(
+                state.run(|| Ok(vec![port_info("3000", "1")]), on_diff, on_record),
+                stop
+            )
+        })
+        .await;
+
+        // 설정을 바꿀 때 남은 알림으로 한 번 더 조회할 수 있으므로 처음과 끝만 확인한다.
+        let records = records.lock().unwrap();
+        assert_eq!(records.first(), Some(&Some(1)));
+        assert_eq!(records.last(), Some(&None));
+        assert_eq!(records.iter().filter(|record| record.is_none()).count(), 1);
+        assert_eq!(*diffs.lock().unwrap(), 0);
+    }
+}
@@ -1,74 +1,445 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
 use leptos::*;
-use serde_wasm_bindgen::from_value;
-use wasm_bindgen::prelude::*;
 
+use crate::components::availability::PortCheck;
+use crate::components::coverage::CoverageBanner;
+use crate::components::detail_panel::ProcessPanel;
+use crate::components::kill_dialog::KillDialog;
 use crate::components::loading::Loading;
+use crate::components::probe::ProbeControl;
 use crate::components::search::SearchBar;
+use crate::components::settings::HistorySettings;
 use crate::components::table::PortTable;
-use crate::interfaces::kill::KillArgs;
-use crate::interfaces::port::PortInfo;
+use crate::components::timeline::Timeline;
+use crate::components::tree::{GroupByControl, PortTree};
+use crate::components::watch::WatchControl;
+use crate::interfaces::bulk::{BulkAction, BulkActionArgs, BulkOutcome};
+use crate::interfaces::cgroup::StopUnitArgs;
+use crate::interfaces::container::StopContainerArgs;
+use crate::interfaces::coverage::{ElevatedScan, ScanCoverage};
+use crate::interfaces::error::CommandError;
+use crate::interfaces::kill::{KillArgs, KillSignal};
+use crate::interfaces::page::PAGE;
+use crate::interfaces::port::{GetPortsArgs, PortInfo, ScanEvent};
+use crate::interfaces::fingerprint::FingerprintResult;
+use crate::interfaces::group::GroupBy;
+use crate::interfaces::probe::ProbeResult;
+use crate::interfaces::reservation::ReleasePortArgs;
+use crate::interfaces::settings::{Settings, SettingsArgs};
+use crate::interfaces::usage::{push_samples, ProcessUsage};
+use crate::interfaces::view_model::PortViewModel;
+use crate::interfaces::watch::RowHighlight;
+use crate::utils::tauri::{invoke_command, invoke_streaming, listen};
 
-#[wasm_bindgen]
-extern "C" {
-    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "core"])]
-    async fn invoke(cmd: &str, args: JsValue) -> JsValue;
-}
+// 백엔드 명령어의 제한 시간 (lsof가 NFS 등에서 멈추는 경우 대비)
+const COMMAND_TIMEOUT_MS: u64 = 10_000;
+// 감시 이벤트로 바뀐 행을 강조하는 시간
+const HIGHLIGHT_MS: u64 = 1_500;
 
 #[component]
 pub fn App() -> impl IntoView {
-    let (filter_ports, set_filter_ports) = create_signal(Vec::<PortInfo>::new());
+    // 조회 결과와 검색, 정렬, 선택 상태
+    let view_model = PortViewModel::new();
 
     let (loading, set_loading) = create_signal(false);
+    let (error, set_error) = create_signal(None::<CommandError>);
+    // 일괄 작업 결과 (ex. 내보낸 파일 경로)
+    let (notice, set_notice) = create_signal(None::<String>);
+    let (page, set_page) = create_signal(PAGE::TABLE);
+    // 권한 부족으로 일부만 보이는지, 조회가 끝날 때 채워진다.
+    let (coverage, set_coverage) = create_signal(None::<ScanCoverage>);
+    let (elevating, set_elevating) = create_signal(false);
+
+    // 새로고침마다 증가, 이전 조회의 늦은 이벤트를 무시하기 위해 사용
+    let generation = store_value(0_u32);
 
     let fetch_ports = create_resource(
         || (), // 의존성이 없으므로 빈 튜플 사용
         move |_| async move {
+            generation.update_value(|value| *value += 1);
+            let current = generation.get_value();
+
             set_loading.set(true);
+            set_coverage.set(None);
 
-            let args = JsValue::NULL;
-            let response = invoke("get_open_ports", args).await;
+            // 결과는 단계별로 채널을 통해 들어오며, 테이블은 점진적으로 채워진다.
+            // 진행 중인 조회가 있다면 백엔드에서 취소된다.
+            let on_event = move |event: ScanEvent| {
+                if generation.get_value() != current {
+                    return;
+                }
 
-            match from_value::<Vec<PortInfo>>(response) {
-                Ok(results) => {
+                match event {
+                    ScanEvent::Rows(results) => {
+                        view_model.source.set(results);
+                        set_loading.set(false);
+                    }
+                    ScanEvent::Patch(patches) => {
+                        let apply = |ports: &mut Vec<PortInfo>| {
+                            for port in ports.iter_mut() {
+                                if let Some(patch) =
+                                    patches.iter().find(|patch| patch.pid == port.get_pid())
+                                {
+                                    port.apply_patch(patch);
+                                }
+                            }
+                        };
+                        view_model.source.update(apply);
+                    }
+                    ScanEvent::Containers(patches) => {
+                        let apply = |ports: &mut Vec<PortInfo>| {
+                            for port in ports.iter_mut() {
+                                for patch in patches.iter() {
+                                    port.apply_container_patch(patch);
+                                }
+                            }
+                        };
+                        view_model.source.update(apply);
+                    }
+                    ScanEvent::Connections(counts) => view_model.source.update(|ports| {
+                        for port in ports.iter_mut() {
+                            port.apply_connections(&counts);
+                        }
+                    }),
+                    ScanEvent::Coverage(result) => set_coverage.set(Some(result)),
+                    ScanEvent::Finished => {}
+                }
+            };
+
+            let args = GetPortsArgs {
+                timeout_ms: Some(COMMAND_TIMEOUT_MS),
+            };
+
+            match invoke_streaming("stream_open_ports", &args, "onEvent", on_event).await {
+                Ok(()) => {
                     set_loading.set(false);
-                    set_filter_ports.set(results.clone());
-                    return results;
-                },
-                Err(_) => {
+                    set_error.set(None);
+                }
+                // 새로고침으로 취소된 요청은 뒤이은 요청이 상태를 갱신한다.
+                Err(CommandError::Cancelled) => {}
+                Err(e) => {
                     set_loading.set(false);
-                    set_filter_ports.set(Vec::<PortInfo>::new());
-                    return Vec::<PortInfo>::new();
+                    set_error.set(Some(e));
+                    view_model.source.set(Vec::<PortInfo>::new());
                 }
             }
         },
     );
 
+    // 소켓(socket_key)마다 마지막 연결 확인 결과
+    let (probes, set_probes) = create_signal(HashMap::<String, ProbeResult>::new());
+    // 소켓마다 HTTP 등 프로토콜 확인 결과
+    let (fingerprints, set_fingerprints) = create_signal(HashMap::<String, FingerprintResult>::new());
+
+    // 감시 이벤트는 전체를 다시 불러오지 않고 바뀐 행만 반영한다.
+    let (highlights, set_highlights) = create_signal(HashMap::<String, RowHighlight>::new());
+
+    listen("port-opened", move |opened: Vec<PortInfo>| {
+        let keys: Vec<String> = opened.iter().map(|port| port.key()).collect();
+
+        view_model.source.update(|ports| {
+            for port in opened {
+                if !ports.iter().any(|exist| exist.key() == port.key()) {
+                    ports.push(port);
+                }
+            }
+        });
+        set_highlights.update(|highlights| {
+            for key in keys.iter() {
+                highlights.insert(key.clone(), RowHighlight::Added);
+            }
+        });
+
+        set_timeout(
+            move || {
+                set_highlights.update(|highlights| {
+                    for key in keys.iter() {
+                        highlights.remove(key);
+                    }
+                });
+            },
+            Duration::from_millis(HIGHLIGHT_MS),
+        );
+    });
+
+    listen("port-closed", move |closed: Vec<PortInfo>| {
+        let keys: Vec<String> = closed.iter().map(|port| port.key()).collect();
+
+        // 닫힌 행은 잠시 강조한 뒤 제거한다.
+        set_highlights.update(|highlights| {
+            for key in keys.iter() {
+                highlights.insert(key.clone(), RowHighlight::Removed);
+            }
+        });
+
+        set_timeout(
+            move || {
+                view_model.source.update(|ports| ports.retain(|port| !keys.contains(&port.key())));
+                set_highlights.update(|highlights| {
+                    for key in keys.iter() {
+                        highlights.remove(key);
+                    }
+                });
+            },
+            Duration::from_millis(HIGHLIGHT_MS),
+        );
+    });
+
+    // 감시 간격마다 소켓을 가진 프로세스의 CPU, 메모리 사용량
+    listen("process-usage", move |samples: Vec<ProcessUsage>| {
+        view_model.usage.update(|usage| push_samples(usage, samples));
+    });
+
     let clear_event_cb = move || {
         fetch_ports.refetch();
     };
 
-    let delet_event_cb = move |pid: String| {
+    // 종료 확인 창에 띄울 pid, 비어 있으면 창을 닫는다.
+    let (pending_kill, set_pending_kill) = create_signal(Vec::<String>::new());
+    let (kill_signal, set_kill_signal) = create_signal(KillSignal::default());
+
+    let send_kill = move |pid: String, signal: KillSignal| {
+        spawn_local(async move {
+            let args = KillArgs {
+                pid: &pid,
+                signal: Some(signal),
+                timeout_ms: Some(COMMAND_TIMEOUT_MS),
+            };
+
+            match invoke_command::<bool>("kill_process", &args).await {
+                Ok(true) => fetch_ports.refetch(),
+                Ok(false) => {}
+                Err(e) => set_error.set(Some(e)),
+            }
+        });
+    };
+
+    // 여러 행에 한 번에 실행하고, 실패한 pid만 모아서 알린다.
+    let run_bulk = move |action: BulkAction, ports: Vec<PortInfo>| {
+        let count = ports.len();
+
         spawn_local(async move {
-            let args = serde_wasm_bindgen::to_value(&KillArgs { pid: &pid }).unwrap();
+            let args = BulkActionArgs {
+                action: action.clone(),
+                ports,
+            };
+
+            match invoke_command::<BulkOutcome>("run_bulk_action", &args).await {
+                Ok(outcome) => {
+                    match (&action, &outcome.output) {
+                        (BulkAction::Export, Some(path)) => set_notice.set(Some(format!("Exported to {}", path))),
+                        (BulkAction::Copy, _) => set_notice.set(Some(format!("Copied {} rows", count))),
+                        _ => set_notice.set(None),
+                    }
+                    match outcome.failures() {
+                        Some(failures) => set_error.set(Some(CommandError::Failed(format!("Failed to kill {}", failures)))),
+                        None => set_error.set(None),
+                    }
+                    if let BulkAction::Kill { .. } = action {
+                        fetch_ports.refetch();
+                    }
+                }
+                Err(e) => set_error.set(Some(e)),
+            }
+        });
+    };
 
-            let kill = invoke("kill_process", args)
+    // 다시 묻지 않기로 한 프로세스 이름이 아니라면 확인 창을 띄운다.
+    let delet_event_cb = move |pid: String| {
+        let process_name = view_model
+            .source
+            .get_untracked()
+            .iter()
+            .find(|port| port.get_pid() == pid)
+            .map(|port| port.get_process_name());
+
+        spawn_local(async move {
+            let settings = invoke_command::<Settings>("get_settings", &())
                 .await
-                .as_bool()
-                .unwrap_or_else(|| false);
-            if kill {
-                fetch_ports.refetch();
+                .unwrap_or_default();
+            set_kill_signal.set(settings.kill_signal);
+
+            let skip = process_name
+                .map(|name| settings.skip_kill_confirm.contains(&name))
+                .unwrap_or(false);
+            if skip {
+                send_kill(pid, settings.kill_signal);
+            } else {
+                set_pending_kill.update(|pending| {
+                    if !pending.contains(&pid) {
+                        pending.push(pid);
+                    }
+                });
             }
         });
     };
 
+    let cancel_kill_cb = move || set_pending_kill.set(Vec::new());
+
+    let confirm_kill_cb = move |pids: Vec<String>, signal: KillSignal, remember: bool| {
+        let ports = view_model.source.get_untracked();
+        let names: Vec<String> = pids
+            .iter()
+            .filter_map(|pid| ports.iter().find(|port| port.get_pid() == *pid))
+            .map(|port| port.get_process_name())
+            .collect();
+
+        // 고른 신호는 다음 확인 창의 기본값이 된다.
+        spawn_local(async move {
+            if let Ok(mut settings) = invoke_command::<Settings>("get_settings", &()).await {
+                settings.kill_signal = signal;
+                if remember {
+                    for name in names {
+                        if !settings.skip_kill_confirm.contains(&name) {
+                            settings.skip_kill_confirm.push(name);
+                        }
+                    }
+                }
+                let _ = invoke_command::<Settings>("update_settings", &SettingsArgs { settings }).await;
+            }
+        });
+
+        set_pending_kill.set(Vec::new());
+        if pids.len() > 1 {
+            let rows = ports.into_iter().filter(|port| pids.contains(&port.get_pid())).collect();
+            run_bulk(BulkAction::Kill { signal }, rows);
+        } else {
+            for pid in pids {
+                send_kill(pid, signal);
+            }
+        }
+    };
+
+    // 고른 신호를 기본값으로 확인 창을 띄운다.
+    let request_kill = move |pids: Vec<String>, signal: KillSignal| {
+        set_kill_signal.set(signal);
+        set_pending_kill.update(|pending| {
+            for pid in pids {
+                if !pending.contains(&pid) {
+                    pending.push(pid);
+                }
+            }
+        });
+    };
+
+    // 선택한 행의 종료는 확인 창을 거치고, 내보내기와 복사는 바로 실행한다.
+    let bulk_cb = move |action: BulkAction, rows: Vec<PortInfo>| match action {
+        BulkAction::Kill { signal } => request_kill(rows.iter().map(|row| row.get_pid()).collect(), signal),
+        action => run_bulk(action, rows),
+    };
+
+    // 상세 패널을 연 프로세스
+    let (detail_pid, set_detail_pid) = create_signal(None::<String>);
+    let detail_cb = move |pid: String| set_detail_pid.set(Some(pid));
+    let close_detail_cb = move || set_detail_pid.set(None);
+
+    // docker-proxy를 종료하는 대신 컨테이너를 멈춘다.
+    let stop_container_cb = move |id: String| {
+        spawn_local(async move {
+            match invoke_command::<()>("stop_container", &StopContainerArgs { id: &id }).await {
+                Ok(()) => fetch_ports.refetch(),
+                Err(e) => set_error.set(Some(e)),
+            }
+        });
+    };
+
+    // systemd가 다시 띄우는 프로세스는 서비스를 멈춘다.
+    let stop_unit_cb = move |unit: String, user: bool| {
+        spawn_local(async move {
+            match invoke_command::<()>("stop_unit", &StopUnitArgs { unit: &unit, user }).await {
+                Ok(()) => fetch_ports.refetch(),
+                Err(e) => set_error.set(Some(e)),
+            }
+        });
+    };
+
+    // 앱이 붙잡고 있던 포트를 놓는다.
+    let release_cb = move |port: String| {
+        let Ok(port) = port.parse::<u16>() else {
+            return;
+        };
+
+        spawn_local(async move {
+            match invoke_command::<usize>("release_port", &ReleasePortArgs { port }).await {
+                Ok(_) => fetch_ports.refetch(),
+                Err(e) => set_error.set(Some(e)),
+            }
+        });
+    };
+
+    // 관리자 권한으로 다시 조회해 다른 사용자의 소켓까지 채운다.
+    let elevate_cb = move || {
+        set_elevating.set(true);
+
+        spawn_local(async move {
+            match invoke_command::<ElevatedScan>("scan_open_ports_elevated", &()).await {
+                Ok(scan) => {
+                    view_model.source.set(scan.rows);
+                    set_coverage.set(Some(scan.coverage));
+                    set_error.set(None);
+                }
+                // 인증 창을 닫은 경우
+                Err(CommandError::Cancelled) => {}
+                Err(e) => set_error.set(Some(e)),
+            }
+            set_elevating.set(false);
+        });
+    };
+
     view! {
         <div>
-            <div class="w-screen fixed z-50 bg-white">
-                <SearchBar ports=fetch_ports.get().unwrap_or_default() set_filter_ports=set_filter_ports clear_event=clear_event_cb/>
+            <div class="w-screen fixed z-50 bg-white flex flex-nowrap items-center">
+                <SearchBar view_model=view_model clear_event=clear_event_cb/>
+                <WatchControl on_resume=clear_event_cb/>
+                <HistorySettings/>
+                <PortCheck on_reserved=clear_event_cb/>
+                <ProbeControl ports=view_model.visible set_probes=set_probes set_fingerprints=set_fingerprints/>
+                <div class="flex flex-nowrap items-center px-2 space-x-1">
+                    {[PAGE::TABLE, PAGE::TIMELINE]
+                        .into_iter()
+                        .map(|item| view! {
+                            <button
+                                class=move || if page.get() == item {
+                                    "rounded-lg px-3 py-2 text-sm font-bold bg-gray-800 text-white"
+                                } else {
+                                    "rounded-lg px-3 py-2 text-sm font-bold text-gray-800"
+                                }
+                                on:click=move |_e| set_page.set(item)
+                            >
+                                {item.to_string()}
+                            </button>
+                        })
+                        .collect_view()}
+                    <GroupByControl view_model=view_model/>
+                </div>
             </div>
             <div class="pt-10">
-                <Show when=move || loading.get() == false fallback=|| view! {<Loading/>}>
-                    <PortTable props=filter_ports delete_cb=delet_event_cb/>
+                <Show when=move || error.get().is_some()>
+                    <div class="mx-4 mt-4 p-3 rounded-lg bg-red-50 text-sm text-red-700">
+                        {move || error.get().map(|e| e.to_string()).unwrap_or_default()}
+                    </div>
+                </Show>
+                <Show when=move || notice.get().is_some()>
+                    <div class="mx-4 mt-4 p-3 rounded-lg bg-green-50 text-sm text-green-700 flex justify-between">
+                        <span>{move || notice.get().unwrap_or_default()}</span>
+                        <button class="font-bold" on:click=move |_e| set_notice.set(None)>"Dismiss"</button>
+                    </div>
+                </Show>
+                <CoverageBanner coverage=coverage elevating=elevating on_elevate=elevate_cb/>
+                <Show when=move || !pending_kill.get().is_empty()>
+                    <KillDialog pids=pending_kill ports=view_model.source.read_only() default_signal=kill_signal on_cancel=cancel_kill_cb on_confirm=confirm_kill_cb/>
+                </Show>
+                {move || detail_pid.get().map(|pid| view! {
+                    <ProcessPanel pid=pid view_model=view_model probes=probes fingerprints=fingerprints on_close=close_detail_cb kill_cb=request_kill/>
+                })}
+                <Show when=move || page.get() == PAGE::TABLE fallback=|| view! {<Timeline/>}>
+                    <Show when=move || loading.get() == false fallback=|| view! {<Loading/>}>
+                        <Show when=move || view_model.group_by.get() == GroupBy::NONE fallback=move || view! {<PortTree view_model=view_model delete_cb=delet_event_cb detail_cb=detail_cb/>}>
+                            <PortTable view_model=view_model highlights=highlights probes=probes fingerprints=fingerprints delete_cb=delet_event_cb stop_container_cb=stop_container_cb stop_unit_cb=stop_unit_cb release_cb=release_cb bulk_cb=bulk_cb detail_cb=detail_cb/>
+                        </Show>
+                    </Show>
                 </Show>
             </div>
         </div>
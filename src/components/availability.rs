@@ -0,0 +1,186 @@
+use leptos::*;
+
+use crate::interfaces::availability::{CheckPortArgs, FindFreePortArgs, PortAvailability};
+use crate::interfaces::reservation::{Reservation, ReservePortArgs};
+use crate::utils::tauri::invoke_command;
+
+// (표시 이름, bind를 시도할 주소), 빈 목록은 백엔드 기본값 (0.0.0.0, ::)
+const ADDRESS_OPTIONS: [(&str, &[&str]); 4] = [
+    ("All", &[]),
+    ("IPv4", &["0.0.0.0"]),
+    ("IPv6", &["::"]),
+    ("Loopback", &["127.0.0.1", "::1"]),
+];
+
+// 0은 OS가 임의로 고르는 포트라 확인할 수 없다.
+fn parse_port(value: &str) -> Option<u16> {
+    value.trim().parse().ok().filter(|port| *port != 0)
+}
+
+/**
+ * "8080" 혹은 "3000-3100" 형태의 입력을 (start, end)로 나눈다.
+ */
+fn parse_query(query: &str) -> Option<(u16, Option<u16>)> {
+    match query.trim().split_once('-') {
+        Some((start, end)) => Some((parse_port(start)?, Some(parse_port(end)?))),
+        None => Some((parse_port(query)?, None)),
+    }
+}
+
+fn describe(availability: &PortAvailability) -> String {
+    if availability.available {
+        return format!("{} is free", availability.port);
+    }
+
+    let reasons = availability
+        .addresses
+        .iter()
+        .filter_map(|address| {
+            address
+                .reason
+                .as_ref()
+                .map(|reason| format!("{}: {}", address.address, reason.to_string()))
+        })
+        .collect::<Vec<String>>()
+        .join(", ");
+
+    format!("{} is unavailable ({})", availability.port, reasons)
+}
+
+fn selected_addresses(option: usize) -> Option<Vec<String>> {
+    Some(
+        ADDRESS_OPTIONS[option]
+            .1
+            .iter()
+            .map(|address| address.to_string())
+            .collect(),
+    )
+}
+
+#[component]
+pub fn PortCheck(
+    // 포트를 예약하면 목록에 표시되도록 다시 불러온다.
+    on_reserved: impl Fn() + 'static + Clone,
+) -> impl IntoView {
+    let (query, set_query) = create_signal(String::new());
+    let (address_option, set_address_option) = create_signal(0_usize);
+    let (result, set_result) = create_signal(None::<(bool, String)>);
+    // 확인 결과 비어 있는 포트, Reserve 버튼으로 붙잡을 수 있다.
+    let (free_port, set_free_port) = create_signal(None::<u16>);
+
+    let on_check = move |_e| {
+        let Some((start, end)) = parse_query(&query.get_untracked()) else {
+            set_result.set(Some((
+                false,
+                "Enter a port or a range like 3000-3100".to_string(),
+            )));
+            return;
+        };
+        let addresses = selected_addresses(address_option.get_untracked());
+        set_free_port.set(None);
+
+        spawn_local(async move {
+            let message = match end {
+                None => invoke_command::<PortAvailability>(
+                    "check_port",
+                    &CheckPortArgs {
+                        port: start,
+                        addresses,
+                    },
+                )
+                .await
+                .map(|availability| {
+                    if availability.available {
+                        set_free_port.set(Some(availability.port));
+                    }
+                    (availability.available, describe(&availability))
+                }),
+                Some(end) => invoke_command::<Option<PortAvailability>>(
+                    "find_free_port",
+                    &FindFreePortArgs {
+                        start,
+                        end,
+                        addresses,
+                    },
+                )
+                .await
+                .map(|found| match found {
+                    Some(availability) => {
+                        set_free_port.set(Some(availability.port));
+                        (true, format!("First free port: {}", availability.port))
+                    }
+                    None => (false, format!("No free port in {}-{}", start, end)),
+                }),
+            };
+
+            set_result.set(Some(message.unwrap_or_else(|e| (false, e.to_string()))));
+        });
+    };
+
+    let on_reserve = move |_e| {
+        let Some(port) = free_port.get_untracked() else {
+            return;
+        };
+        let addresses = selected_addresses(address_option.get_untracked());
+        let on_reserved = on_reserved.clone();
+
+        spawn_local(async move {
+            let args = ReservePortArgs { port, addresses };
+            match invoke_command::<Vec<Reservation>>("reserve_port", &args).await {
+                Ok(_) => {
+                    set_free_port.set(None);
+                    set_result.set(Some((true, format!("Reserved {}", port))));
+                    on_reserved();
+                }
+                Err(e) => set_result.set(Some((false, e.to_string()))),
+            }
+        });
+    };
+
+    view! {
+        <div class="flex flex-nowrap items-center px-2 space-x-2">
+            <input
+                class="text-sm text-gray-800 outline-none border-2 px-2 py-2 rounded-lg w-28"
+                type="text"
+                placeholder="8080 or 3000-3100"
+                prop:value=move || query.get()
+                on:input=move |e| set_query.set(event_target_value(&e))
+            />
+            <select
+                title="address"
+                class="text-sm font-bold text-gray-800 outline-none border-2 px-2 py-2 rounded-lg"
+                on:change=move |e| {
+                    if let Ok(index) = event_target_value(&e).parse::<usize>() {
+                        set_address_option.set(index);
+                    }
+                }
+            >
+                {ADDRESS_OPTIONS
+                    .iter()
+                    .enumerate()
+                    .map(|(index, (label, _))| view! { <option value=index.to_string()>{*label}</option> })
+                    .collect_view()}
+            </select>
+            <button
+                class="rounded-lg px-3 py-2 text-sm font-bold border-2 text-gray-800 hover:bg-gray-100"
+                on:click=on_check
+            >
+                "Check"
+            </button>
+            <Show when=move || free_port.get().is_some()>
+                <button
+                    class="rounded-lg px-3 py-2 text-sm font-bold border-2 border-indigo-500 text-indigo-600 hover:bg-indigo-50"
+                    title="Hold this port until released"
+                    on:click=on_reserve.clone()
+                >
+                    "Reserve"
+                </button>
+            </Show>
+            {move || result.get().map(|(available, message)| view! {
+                <span class=if available { "text-sm text-green-600" } else { "text-sm text-red-600" }>
+                    {message}
+                </span>
+            })}
+        </div>
+    }
+}
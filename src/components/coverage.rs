@@ -0,0 +1,51 @@
+use leptos::*;
+
+use crate::interfaces::coverage::ScanCoverage;
+
+/**
+ * 권한이 없어 일부 소켓만 보이는 경우 알리고, 관리자 권한으로 다시 조회할 수 있게 한다.
+ */
+#[component]
+pub fn CoverageBanner(
+    coverage: ReadSignal<Option<ScanCoverage>>,
+    // 관리자 인증을 기다리는 중
+    elevating: ReadSignal<bool>,
+    on_elevate: impl Fn() + 'static + Clone,
+) -> impl IntoView {
+    let partial = move || {
+        coverage
+            .get()
+            .filter(|coverage| !coverage.elevated && coverage.is_partial())
+    };
+
+    view! {
+        <Show when=move || partial().is_some()>
+            <div class="mx-4 mt-4 p-3 rounded-lg bg-amber-50 text-sm text-amber-800 flex items-center justify-between">
+                <div>
+                    <div class="font-bold">
+                        {move || partial().map(|coverage| coverage.summary()).unwrap_or_default()}
+                    </div>
+                    {move || {
+                        partial()
+                            .map(|coverage| coverage.warnings)
+                            .unwrap_or_default()
+                            .into_iter()
+                            .map(|warning| view! { <div class="text-xs text-amber-700">{warning}</div> })
+                            .collect_view()
+                    }}
+                </div>
+                <button
+                    class="rounded-lg px-3 py-2 text-sm font-bold bg-amber-600 text-white disabled:opacity-50"
+                    title="Run the scan again with administrator privileges"
+                    disabled=move || elevating.get()
+                    on:click={
+                        let on_elevate = on_elevate.clone();
+                        move |_e| on_elevate()
+                    }
+                >
+                    {move || if elevating.get() { "Waiting for authorization..." } else { "Scan as administrator" }}
+                </button>
+            </div>
+        </Show>
+    }
+}
@@ -0,0 +1,265 @@
+use std::collections::HashMap;
+
+use leptos::*;
+
+use crate::components::table::open_in_browser;
+use crate::interfaces::detail::{CopyTextArgs, ProcessDetail, ProcessDetailArgs, RevealPathArgs};
+use crate::interfaces::error::CommandError;
+use crate::interfaces::fingerprint::FingerprintResult;
+use crate::interfaces::kill::KillSignal;
+use crate::interfaces::port::PortInfo;
+use crate::interfaces::probe::ProbeResult;
+use crate::interfaces::view_model::PortViewModel;
+use crate::utils::tauri::invoke_command;
+
+// 확인된 HTTP 주소가 없다면 로컬 주소로 연다.
+fn browser_url(port: &PortInfo, fingerprint: Option<&FingerprintResult>) -> String {
+    fingerprint
+        .and_then(|result| result.fingerprint.as_ref())
+        .and_then(|fingerprint| fingerprint.url())
+        .unwrap_or(format!("http://localhost:{}", port.get_port()))
+}
+
+/**
+ * 테이블에서 클릭한 프로세스의 상세 정보
+ * 명령어, 환경 변수, 부모 프로세스, 소켓, 사용량은 열 때 한 번 조회한다.
+ */
+#[component]
+pub fn ProcessPanel(
+    pid: String,
+    // 이 프로세스가 점유한 행을 찾을 목록
+    view_model: PortViewModel,
+    probes: ReadSignal<HashMap<String, ProbeResult>>,
+    fingerprints: ReadSignal<HashMap<String, FingerprintResult>>,
+    on_close: impl Fn() + 'static + Clone,
+    // (pid 목록, 신호), 확인 창을 거쳐 종료한다.
+    kill_cb: impl Fn(Vec<String>, KillSignal) + 'static + Clone,
+) -> impl IntoView {
+    // 환경 변수 값은 기본으로 가린다.
+    let (show_env, set_show_env) = create_signal(false);
+    // 복사, 열기 등의 결과
+    let (status, set_status) = create_signal(None::<String>);
+
+    let detail = {
+        let pid = pid.clone();
+        create_resource(
+            || (),
+            move |_| {
+                let pid = pid.clone();
+                async move {
+                    invoke_command::<ProcessDetail>("get_process_detail", &ProcessDetailArgs { pid: &pid }).await
+                }
+            },
+        )
+    };
+
+    let rows = {
+        let pid = pid.clone();
+        create_memo(move |_| {
+            view_model
+                .source
+                .get()
+                .into_iter()
+                .filter(|port| port.get_pid() == pid)
+                .collect::<Vec<PortInfo>>()
+        })
+    };
+
+    // 브라우저로 열 때와 같은 주소, 지문으로 찾은 주소가 있다면 그 주소를 쓴다.
+    let copy_curl = move |url: String| {
+        let text = format!("curl -i {}", url);
+        spawn_local(async move {
+            match invoke_command::<()>("copy_text", &CopyTextArgs { text: text.clone() }).await {
+                Ok(()) => set_status.set(Some(format!("Copied: {}", text))),
+                Err(e) => set_status.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    let reveal = move |path: String| {
+        spawn_local(async move {
+            if let Err(e) = invoke_command::<()>("reveal_path", &RevealPathArgs { path }).await {
+                set_status.set(Some(e.to_string()));
+            }
+        });
+    };
+
+    let kill_button = {
+        let pid = pid.clone();
+        move |label: &'static str, title: &'static str, signal: KillSignal| {
+            let kill_cb = kill_cb.clone();
+            let pid = pid.clone();
+            view! {
+                <button
+                    class="px-3 py-1 rounded-lg text-xs font-bold border-2 border-red-500 text-red-600 hover:bg-red-50"
+                    title=title
+                    on:click=move |_e| kill_cb(vec![pid.clone()], signal)
+                >
+                    {label}
+                </button>
+            }
+        }
+    };
+
+    let on_close_click = move |_e| on_close();
+
+    view! {
+        <aside class="fixed top-10 right-0 bottom-0 z-40 w-[32rem] overflow-y-auto border-l bg-white p-6 shadow-xl">
+            <div class="flex items-start justify-between">
+                <h2 class="text-lg font-bold text-gray-900">
+                    {
+                        let pid = pid.clone();
+                        move || {
+                            let name = detail
+                                .get()
+                                .and_then(|detail| detail.ok())
+                                .and_then(|detail| detail.process_name)
+                                .or(rows.get().first().map(|row| row.get_process_name()))
+                                .unwrap_or("Unknown".to_string());
+                            format!("{} ({})", name, pid)
+                        }
+                    }
+                </h2>
+                <button class="text-sm font-bold text-gray-500 hover:underline" on:click=on_close_click>"Close"</button>
+            </div>
+            <div class="mt-3 flex flex-wrap gap-2">
+                {kill_button("Kill", "Force kill this process", KillSignal::KILL)}
+                {kill_button("Terminate", "Ask this process to exit", KillSignal::TERM)}
+                <button
+                    class="px-3 py-1 rounded-lg text-xs font-bold border-2 text-gray-800 hover:bg-gray-100"
+                    title="Load the details again"
+                    on:click=move |_e| detail.refetch()
+                >
+                    "Refresh"
+                </button>
+            </div>
+            <Show when=move || status.get().is_some()>
+                <div class="mt-3 p-2 rounded-lg bg-gray-50 text-xs text-gray-700 break-all">{move || status.get().unwrap_or_default()}</div>
+            </Show>
+
+            <h3 class="mt-6 text-sm font-bold text-gray-900">"Ports"</h3>
+            <ul class="mt-2 space-y-2 text-sm">
+                {move || rows.get().into_iter().map(|port| {
+                    let socket_key = port.socket_key();
+                    let probe = probes.get().get(&socket_key).cloned();
+                    let fingerprint = fingerprints.get().get(&socket_key).cloned();
+                    let url = browser_url(&port, fingerprint.as_ref());
+                    let is_tcp = port.get_protocol().eq_ignore_ascii_case("TCP");
+                    let curl_url = url.clone();
+
+                    view! {
+                        <li class="rounded-lg border p-2">
+                            <div class="flex items-center space-x-2">
+                                {probe.clone().map(|probe| view! {
+                                    <span class=format!("inline-block w-2 h-2 rounded-full {}", probe.color())></span>
+                                })}
+                                <span class="font-medium text-gray-900">
+                                    {format!("{} {}:{}", port.get_protocol(), port.get_address(), port.get_port())}
+                                </span>
+                            </div>
+                            {probe.map(|probe| view! {
+                                <div class="text-xs text-gray-500">{probe.describe()}</div>
+                            })}
+                            {fingerprint.and_then(|result| result.fingerprint).map(|fingerprint| view! {
+                                <div class="text-xs text-indigo-600">{fingerprint.summary()}</div>
+                            })}
+                            {is_tcp.then(|| view! {
+                                <div class="mt-1 flex space-x-3">
+                                    <button class="text-xs font-bold text-indigo-600 hover:underline" title=format!("Open {}", url) on:click=move |_e| open_in_browser(url.clone())>
+                                        "Open in browser"
+                                    </button>
+                                    <button class="text-xs font-bold text-indigo-600 hover:underline" title="Copy a curl command for this port" on:click=move |_e| copy_curl(curl_url.clone())>
+                                        "Copy curl"
+                                    </button>
+                                </div>
+                            })}
+                        </li>
+                    }
+                }).collect_view()}
+            </ul>
+
+            <Suspense fallback=|| view! {<p class="mt-6 text-sm text-gray-500">"Loading..."</p>}>
+                {move || detail.get().map(|result: Result<ProcessDetail, CommandError>| match result {
+                    Err(e) => view! {
+                        <p class="mt-6 text-sm text-red-700">{e.to_string()}</p>
+                    }.into_view(),
+                    Ok(detail) => {
+                        let not_available = || "(not available)".to_string();
+                        let executable = detail.executable.clone();
+
+                        view! {
+                            <dl class="mt-6 grid grid-cols-[7rem_1fr] gap-x-3 gap-y-2 text-sm">
+                                <dt class="font-bold text-gray-500">"Command"</dt>
+                                <dd class="text-gray-900 font-mono text-xs break-all">{detail.command_line.clone().unwrap_or_else(not_available)}</dd>
+                                <dt class="font-bold text-gray-500">"Executable"</dt>
+                                <dd class="text-gray-900 font-mono text-xs break-all">
+                                    {executable.clone().unwrap_or_else(not_available)}
+                                    {executable.map(|path| view! {
+                                        <button class="ml-2 font-sans font-bold text-indigo-600 hover:underline" title="Show in the file manager" on:click=move |_e| reveal(path.clone())>
+                                            "Reveal"
+                                        </button>
+                                    })}
+                                </dd>
+                                <dt class="font-bold text-gray-500">"Directory"</dt>
+                                <dd class="text-gray-900 font-mono text-xs break-all">{detail.cwd.clone().unwrap_or_else(not_available)}</dd>
+                                <dt class="font-bold text-gray-500">"Parents"</dt>
+                                <dd class="text-gray-900 text-xs">
+                                    {if detail.ancestors.is_empty() { "none".to_string() } else { detail.ancestry_label() }}
+                                </dd>
+                                <dt class="font-bold text-gray-500">"CPU"</dt>
+                                <dd class="text-gray-900" title="Average since the process started">{detail.cpu_label().unwrap_or_else(not_available)}</dd>
+                                <dt class="font-bold text-gray-500">"Memory"</dt>
+                                <dd class="text-gray-900">{detail.memory_label().unwrap_or_else(not_available)}</dd>
+                                <dt class="font-bold text-gray-500">"Started"</dt>
+                                <dd class="text-gray-900">{detail.started_label().unwrap_or_else(not_available)}</dd>
+                            </dl>
+
+                            <h3 class="mt-6 text-sm font-bold text-gray-900">"Sockets"</h3>
+                            {if detail.sockets.is_empty() {
+                                view! {<p class="mt-2 text-xs text-gray-500">"No sockets found"</p>}.into_view()
+                            } else {
+                                view! {
+                                    <table class="mt-2 w-full text-xs">
+                                        <tbody class="divide-y divide-gray-200">
+                                            {detail.sockets.iter().map(|socket| view! {
+                                                <tr>
+                                                    <td class="py-1 pr-2 text-gray-500">{socket.protocol.clone()}</td>
+                                                    <td class="py-1 pr-2 font-mono text-gray-900 break-all">{socket.local_address.clone()}</td>
+                                                    <td class="py-1 pr-2 font-mono text-gray-500 break-all">{socket.remote_address.clone().unwrap_or_default()}</td>
+                                                    <td class="py-1 text-gray-900">{socket.state.clone()}</td>
+                                                </tr>
+                                            }).collect_view()}
+                                        </tbody>
+                                    </table>
+                                }.into_view()
+                            }}
+
+                            <div class="mt-6 flex items-center justify-between">
+                                <h3 class="text-sm font-bold text-gray-900">"Environment"</h3>
+                                <label class="flex items-center space-x-1 text-xs text-gray-600">
+                                    <input type="checkbox" prop:checked=show_env on:change=move |e| set_show_env.set(event_target_checked(&e))/>
+                                    <span>"Show values"</span>
+                                </label>
+                            </div>
+                            {match detail.environment.clone() {
+                                None => view! {<p class="mt-2 text-xs text-gray-500">"(not available: Linux and macOS only, and only for processes you can read)"</p>}.into_view(),
+                                Some(vars) => view! {
+                                    <dl class="mt-2 grid grid-cols-[minmax(0,12rem)_1fr] gap-x-3 gap-y-1 font-mono text-xs">
+                                        {vars.into_iter().map(|var| {
+                                            let masked = var.masked();
+                                            let name = var.name.clone();
+                                            view! {
+                                                <dt class="text-gray-500 break-all">{name}</dt>
+                                                <dd class="text-gray-900 break-all">{move || if show_env.get() { var.value.clone() } else { masked.to_string() }}</dd>
+                                            }
+                                        }).collect_view()}
+                                    </dl>
+                                }.into_view(),
+                            }}
+                        }.into_view()
+                    }
+                })}
+            </Suspense>
+        </aside>
+    }
+}
@@ -0,0 +1,166 @@
+use leptos::*;
+
+use crate::interfaces::kill::{KillSignal, ProcessContext, ProcessContextArgs};
+use crate::interfaces::port::PortInfo;
+use crate::utils::tauri::invoke_command;
+
+/**
+ * 종료하기 전에 어떤 프로세스인지 보여주고 신호를 고르게 한다.
+ * 여러 pid("Kill all")를 한 번에 확인할 수 있다.
+ */
+#[component]
+pub fn KillDialog(
+    // 종료를 기다리는 pid
+    pids: ReadSignal<Vec<String>>,
+    // 프로세스 이름, 사용자, 점유한 포트를 찾을 목록
+    ports: ReadSignal<Vec<PortInfo>>,
+    default_signal: ReadSignal<KillSignal>,
+    on_cancel: impl Fn() + 'static + Clone,
+    // (pid 목록, 신호, 같은 이름은 다시 묻지 않기)
+    on_confirm: impl Fn(Vec<String>, KillSignal, bool) + 'static + Clone,
+) -> impl IntoView {
+    let (signal, set_signal) = create_signal(default_signal.get_untracked());
+    let (remember, set_remember) = create_signal(false);
+
+    let contexts = create_resource(
+        move || pids.get(),
+        |pids| async move {
+            let mut contexts = Vec::new();
+            for pid in pids {
+                let context =
+                    invoke_command::<ProcessContext>("get_process_context", &ProcessContextArgs { pid: &pid })
+                        .await
+                        .ok();
+                contexts.push((pid, context));
+            }
+            contexts
+        },
+    );
+
+    let process_names = move || {
+        let ports = ports.get();
+        let mut names: Vec<String> = pids
+            .get()
+            .iter()
+            .filter_map(|pid| ports.iter().find(|port| port.get_pid() == *pid))
+            .map(|port| port.get_process_name())
+            .collect();
+        names.sort();
+        names.dedup();
+        names
+    };
+
+    let on_cancel_click = {
+        let on_cancel = on_cancel.clone();
+        move |_e| on_cancel()
+    };
+    let on_confirm_click = {
+        let on_confirm = on_confirm.clone();
+        move |_e| on_confirm(pids.get_untracked(), signal.get_untracked(), remember.get_untracked())
+    };
+
+    view! {
+        <div class="fixed inset-0 z-50 flex items-center justify-center bg-black bg-opacity-40">
+            <div class="w-[36rem] max-h-[80vh] overflow-y-auto rounded-lg bg-white p-6 shadow-xl">
+                <h2 class="text-lg font-bold text-gray-900">
+                    {move || {
+                        let count = pids.get().len();
+                        if count == 1 { "Kill this process?".to_string() } else { format!("Kill {} processes?", count) }
+                    }}
+                </h2>
+                {move || pids.get().into_iter().map(|pid| {
+                    let all_ports = ports.get();
+                    let rows: Vec<PortInfo> = all_ports.into_iter().filter(|port| port.get_pid() == pid).collect();
+                    let name = rows.first().map(|row| row.get_process_name()).unwrap_or("Unknown".to_string());
+                    let user = rows.first().and_then(|row| row.get_user()).unwrap_or("-".to_string());
+                    let held = rows
+                        .iter()
+                        .map(|row| format!("{} {}:{}", row.get_protocol(), row.get_address(), row.get_port()))
+                        .collect::<Vec<String>>()
+                        .join(", ");
+                    let context = {
+                        let pid = pid.clone();
+                        move || {
+                            contexts
+                                .get()
+                                .and_then(|contexts| contexts.into_iter().find(|(found, _)| *found == pid))
+                                .map(|(_, context)| context)
+                        }
+                    };
+
+                    view! {
+                        <dl class="mt-4 grid grid-cols-[8rem_1fr] gap-x-3 gap-y-1 text-sm">
+                            <dt class="font-bold text-gray-500">"Process"</dt>
+                            <dd class="text-gray-900">{name}</dd>
+                            <dt class="font-bold text-gray-500">"PID"</dt>
+                            <dd class="text-gray-900">{pid.clone()}</dd>
+                            <dt class="font-bold text-gray-500">"User"</dt>
+                            <dd class="text-gray-900">{user}</dd>
+                            <dt class="font-bold text-gray-500">"Command"</dt>
+                            <dd class="text-gray-900 font-mono text-xs break-all">
+                                {
+                                    let context = context.clone();
+                                    move || match context() {
+                                        None => "Loading...".to_string(),
+                                        Some(context) => context
+                                            .and_then(|context| context.command_line)
+                                            .unwrap_or("(not available)".to_string()),
+                                    }
+                                }
+                            </dd>
+                            <dt class="font-bold text-gray-500">"Ports"</dt>
+                            <dd class="text-gray-900">{held}</dd>
+                            <dt class="font-bold text-gray-500">"Children"</dt>
+                            <dd class="text-gray-900">
+                                {move || match context() {
+                                    None => "Loading...".to_string(),
+                                    Some(None) => "(not available)".to_string(),
+                                    Some(Some(context)) if context.children.is_empty() => "none".to_string(),
+                                    Some(Some(context)) => context
+                                        .children
+                                        .iter()
+                                        .map(|child| format!("{} ({})", child.process_name, child.pid))
+                                        .collect::<Vec<String>>()
+                                        .join(", "),
+                                }}
+                            </dd>
+                        </dl>
+                    }
+                }).collect_view()}
+                <div class="mt-4 flex items-center space-x-2 text-sm">
+                    <span class="font-bold text-gray-500">"Signal"</span>
+                    <select
+                        class="text-sm font-bold text-gray-800 outline-none border-2 px-2 py-1 rounded-lg"
+                        prop:value=move || signal.get().to_string()
+                        on:change=move |e| {
+                            if let Ok(selected) = event_target_value(&e).parse::<KillSignal>() {
+                                set_signal.set(selected);
+                            }
+                        }
+                    >
+                        {KillSignal::ALL
+                            .into_iter()
+                            .map(|item| view! { <option value=item.to_string()>{item.label()}</option> })
+                            .collect_view()}
+                    </select>
+                </div>
+                <label class="mt-3 flex items-center space-x-2 text-sm text-gray-700 select-none">
+                    <input
+                        type="checkbox"
+                        prop:checked=move || remember.get()
+                        on:change=move |e| set_remember.set(event_target_checked(&e))
+                    />
+                    <span>{move || format!("Don't ask again for {}", process_names().join(", "))}</span>
+                </label>
+                <div class="mt-6 flex justify-end space-x-2">
+                    <button class="px-4 py-2 rounded-lg text-sm font-bold border-2 text-gray-800 hover:bg-gray-100" on:click=on_cancel_click>
+                        "Cancel"
+                    </button>
+                    <button class="px-4 py-2 rounded-lg text-sm font-bold bg-red-600 text-white hover:bg-red-700" on:click=on_confirm_click>
+                        {move || format!("Send SIG{}", signal.get().to_string())}
+                    </button>
+                </div>
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,124 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use leptos::leptos_dom::helpers::IntervalHandle;
+use leptos::*;
+
+use crate::interfaces::fingerprint::{FingerprintPortsArgs, FingerprintResult};
+use crate::interfaces::port::PortInfo;
+use crate::interfaces::probe::{ProbePortsArgs, ProbeResult};
+use crate::utils::tauri::invoke_command;
+
+// (표시 이름, 주기 ms), 0이면 직접 누를 때만 확인한다.
+const PROBE_INTERVALS: [(&str, u64); 4] =
+    [("Off", 0), ("5s", 5_000), ("15s", 15_000), ("60s", 60_000)];
+
+const PROBE_TIMEOUT_MS: u64 = 1_000;
+const FINGERPRINT_TIMEOUT_MS: u64 = 1_500;
+
+#[component]
+pub fn ProbeControl(
+    // 검색으로 걸러진 목록만 확인한다.
+    ports: Memo<Vec<PortInfo>>,
+    set_probes: WriteSignal<HashMap<String, ProbeResult>>,
+    set_fingerprints: WriteSignal<HashMap<String, FingerprintResult>>,
+) -> impl IntoView {
+    let (probing, set_probing) = create_signal(false);
+    let interval_handle = store_value(None::<IntervalHandle>);
+
+    let probe = move || {
+        // 이전 확인이 끝나지 않았다면 건너뛴다.
+        if probing.get_untracked() {
+            return;
+        }
+        set_probing.set(true);
+
+        spawn_local(async move {
+            let args = ProbePortsArgs {
+                ports: ports.get_untracked(),
+                timeout_ms: Some(PROBE_TIMEOUT_MS),
+            };
+
+            if let Ok(results) = invoke_command::<Vec<ProbeResult>>("probe_ports", &args).await {
+                set_probes.set(
+                    results
+                        .into_iter()
+                        .map(|result| (result.socket_key.clone(), result))
+                        .collect(),
+                );
+            }
+            set_probing.set(false);
+        });
+    };
+
+    // HTTP 요청은 서버 로그에 남으므로 직접 누를 때만 보낸다.
+    let fingerprint = move || {
+        spawn_local(async move {
+            let args = FingerprintPortsArgs {
+                ports: ports.get_untracked(),
+                timeout_ms: Some(FINGERPRINT_TIMEOUT_MS),
+            };
+
+            if let Ok(results) =
+                invoke_command::<Vec<FingerprintResult>>("fingerprint_ports", &args).await
+            {
+                set_fingerprints.set(
+                    results
+                        .into_iter()
+                        .map(|result| (result.socket_key.clone(), result))
+                        .collect(),
+                );
+            }
+        });
+    };
+
+    let on_interval_change = move |e| {
+        if let Some(handle) = interval_handle.get_value() {
+            handle.clear();
+            interval_handle.set_value(None);
+        }
+
+        let Ok(interval_ms) = event_target_value(&e).parse::<u64>() else {
+            return;
+        };
+        if interval_ms == 0 {
+            return;
+        }
+
+        probe();
+        if let Ok(handle) = set_interval_with_handle(probe, Duration::from_millis(interval_ms)) {
+            interval_handle.set_value(Some(handle));
+        }
+    };
+
+    on_cleanup(move || {
+        if let Some(handle) = interval_handle.get_value() {
+            handle.clear();
+        }
+    });
+
+    view! {
+        <div class="flex flex-nowrap items-center px-2 space-x-2">
+            <button
+                class="rounded-lg px-3 py-2 text-sm font-bold border-2 text-gray-800 hover:bg-gray-100"
+                title="Connect to each listening socket and detect HTTP, TLS, Redis, PostgreSQL, MySQL and SSH servers"
+                on:click=move |_e| {
+                    probe();
+                    fingerprint();
+                }
+            >
+                {move || if probing.get() { "PROBING" } else { "PROBE" }}
+            </button>
+            <select
+                title="probe interval"
+                class="text-sm font-bold text-gray-800 outline-none border-2 px-2 py-2 rounded-lg"
+                on:change=on_interval_change
+            >
+                {PROBE_INTERVALS
+                    .iter()
+                    .map(|(label, ms)| view! { <option value=ms.to_string()>{*label}</option> })
+                    .collect_view()}
+            </select>
+        </div>
+    }
+}
@@ -2,55 +2,69 @@ use leptos::*;
 use std::str::FromStr;
 
 use crate::interfaces::filter::FILTER;
-use crate::interfaces::port::PortInfo;
+use crate::interfaces::view_model::PortViewModel;
 
 #[component]
 pub fn SearchBar(
-    ports: Vec<PortInfo>,
-    set_filter_ports: WriteSignal<Vec<PortInfo>>,
+    // 검색어와 드롭다운은 테이블과 같은 상태를 쓰므로 새로고침한 목록에도 그대로 적용된다.
+    view_model: PortViewModel,
     // static: 클로저가 프로그램의 전체 수명 동안 유효하다는 것을 보장
     // Clone: 이벤트 핸들러를 여러 요소에 걸쳐 사용해야 할 경우가 있는데, 이 경우 핸들러를 복사해서 사용
     // 'static + Clone을 명시적으로 지정하여, 이벤트 핸들러로 사용되는 클로저가 컴포넌트의 라이프타임 동안 안전하게 사용될 수 있도록 보장
     clear_event: impl Fn() + 'static + Clone,
 ) -> impl IntoView {
-    let (filter, set_filter) = create_signal(FILTER::PROCESS);
-    let input_ref = create_node_ref::<html::Input>();
+    let query = view_model.query;
+    let query_error = view_model.query_error;
 
-    let on_search_change = move |e| {
-        let value = event_target_value(&e);
-        // 필터링 로직 적용
+    let on_search_change = move |e| query.set(event_target_value(&e));
 
-        set_filter_ports.set(ports.clone());
-        set_filter_ports.update(|ports| {
-            ports.retain(|port| match filter.get() {
-                FILTER::PROCESS => port
-                    .get_process_name()
-                    .to_lowercase()
-                    .contains(&value.to_lowercase()),
-                FILTER::PORT => port.get_port().contains(&value),
-                FILTER::PID => port.get_pid().contains(&value),
-            });
-        });
+    // 잘못된 부분을 표시한 검색어, 입력 끝에서 빠진 경우 빈 칸을 표시한다.
+    let error_view = move || {
+        query_error.get().map(|e| {
+            let text = query.get();
+            let start = e.start.min(text.len());
+            let end = e.end.clamp(start, text.len());
+            let marked = if start == end { " ".to_string() } else { text[start..end].to_string() };
+
+            view! {
+                <div class="absolute left-0 top-full mt-1 z-50 w-max max-w-xl rounded-lg bg-white p-2 shadow-lg text-sm">
+                    <div class="font-mono whitespace-pre text-gray-700">
+                        {text[..start].to_string()}
+                        <span class="bg-red-100 text-red-700 underline decoration-wavy decoration-red-500">{marked}</span>
+                        {text[end..].to_string()}
+                    </div>
+                    <div class="mt-1 text-red-600">{e.message}</div>
+                </div>
+            }
+        })
     };
 
     view! {
-        <div class="flex flex-nowrap items-center bg-white overflow-hidden px-2 py-1 justify-between mx-auto shadow-gray-200 shadow-lg rounded-lg h-min">
-            <input
-                node_ref=input_ref
-                class="text-base text-gray-500 flex-grow outline-none px-2 w-72"
-                type="text"
-                placeholder=""
-                on:input=on_search_change
-            />
+        <div class="flex flex-nowrap items-center bg-white px-2 py-1 justify-between mx-auto shadow-gray-200 shadow-lg rounded-lg h-min">
+            <div class="relative flex-grow">
+                <input
+                    class=move || if query_error.get().is_some() {
+                        "text-base text-red-600 outline-none px-2 w-96 rounded ring-2 ring-red-300"
+                    } else {
+                        "text-base text-gray-500 outline-none px-2 w-96"
+                    }
+                    type="text"
+                    title="ex) port:3000-3999 proc:node !user:root state:listen addr:0.0.0.0, proc:/^python\\d/ OR port:5432"
+                    placeholder="port:3000-3999 proc:node !user:root"
+                    prop:value=move || query.get()
+                    on:input=on_search_change
+                />
+                {error_view}
+            </div>
             <div class="flex flex-nowrap items-center px-2 rounded-lg space-x-4 mx-auto h-full">
                 <select
-                    title="list"
+                    title="Field for words without a prefix"
                     class="text-base font-extrabold text-gray-800 outline-none border-2 px-4 py-2 rounded-lg h-auto"
-                    prop:value={move || filter.get().to_string()}
+                    prop:value={move || view_model.shorthand.get().to_string()}
                     on:change=move |e| {
                         let value = event_target_value(&e);
                         if let Ok(selected_filter) = FILTER::from_str(&value) {
-                            set_filter.set(selected_filter);
+                            view_model.shorthand.set(selected_filter);
                         }
                     }
                 >
@@ -62,9 +76,7 @@ pub fn SearchBar(
                     class="bg-indigo-600 text-white rounded-lg px-4 py-2 hover:bg-indigo-500"
                     on:click=move |_e| {
                         clear_event();
-                        if let Some(input) = input_ref.get() {
-                            input.set_value("");
-                        }
+                        query.set(String::new());
                     }
                 >
                     <img src="/public/refresh.svg" width="20" height="20" alt="Icon" />
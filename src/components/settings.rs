@@ -0,0 +1,73 @@
+use leptos::*;
+
+use crate::interfaces::settings::{Settings, SettingsArgs};
+use crate::utils::tauri::invoke_command;
+
+// 이력 보관 기간 (일)
+const RETENTION_DAYS: [u32; 4] = [1, 7, 30, 90];
+
+#[component]
+pub fn HistorySettings() -> impl IntoView {
+    let (settings, set_settings) = create_signal(Settings::default());
+
+    spawn_local(async move {
+        if let Ok(current) = invoke_command::<Settings>("get_settings", &()).await {
+            set_settings.set(current);
+        }
+    });
+
+    // 종료 확인 창도 설정을 바꾸므로 저장 직전의 설정에 변경분만 적용한다.
+    let save = move |change: Box<dyn FnOnce(&mut Settings)>| {
+        spawn_local(async move {
+            let Ok(mut updated) = invoke_command::<Settings>("get_settings", &()).await else {
+                return;
+            };
+            change(&mut updated);
+
+            let args = SettingsArgs { settings: updated };
+            if let Ok(current) = invoke_command::<Settings>("update_settings", &args).await {
+                set_settings.set(current);
+            }
+        });
+    };
+
+    view! {
+        <div class="flex flex-nowrap items-center px-2 space-x-2">
+            <label class="flex items-center space-x-1 text-sm font-bold text-gray-800 select-none" title="Record port open/close events in the background, even while LIVE is paused">
+                <input
+                    type="checkbox"
+                    prop:checked=move || settings.get().history_enabled
+                    on:change=move |e| {
+                        let enabled = event_target_checked(&e);
+                        save(Box::new(move |settings| settings.history_enabled = enabled));
+                    }
+                />
+                <span>"REC"</span>
+            </label>
+            <select
+                title="retention"
+                class="text-sm font-bold text-gray-800 outline-none border-2 px-2 py-2 rounded-lg"
+                prop:value=move || settings.get().history_retention_days.to_string()
+                on:change=move |e| {
+                    if let Ok(days) = event_target_value(&e).parse::<u32>() {
+                        save(Box::new(move |settings| settings.history_retention_days = days));
+                    }
+                }
+            >
+                {RETENTION_DAYS
+                    .iter()
+                    .map(|days| view! { <option value=days.to_string()>{format!("{}d", days)}</option> })
+                    .collect_view()}
+            </select>
+            <Show when=move || !settings.get().skip_kill_confirm.is_empty()>
+                <button
+                    class="text-xs font-bold text-gray-500 hover:underline"
+                    title=move || format!("Ask again before killing {}", settings.get().skip_kill_confirm.join(", "))
+                    on:click=move |_e| save(Box::new(|settings| settings.skip_kill_confirm.clear()))
+                >
+                    {move || format!("Reset kill prompts ({})", settings.get().skip_kill_confirm.len())}
+                </button>
+            </Show>
+        </div>
+    }
+}
@@ -0,0 +1,47 @@
+use leptos::*;
+
+const WIDTH: f64 = 60.0;
+const HEIGHT: f64 = 16.0;
+
+// 측정값을 svg polyline 좌표로 바꾼다. 오래된 값이 왼쪽
+fn points(values: &[f64], ceiling: f64) -> String {
+    let top = values.iter().copied().fold(ceiling, f64::max);
+    let step = WIDTH / (values.len().max(2) - 1) as f64;
+
+    values
+        .iter()
+        .enumerate()
+        .map(|(index, value)| {
+            let ratio = if top > 0.0 { value / top } else { 0.0 };
+            let y = HEIGHT - ratio.clamp(0.0, 1.0) * HEIGHT;
+            format!("{:.1},{:.1}", index as f64 * step, y)
+        })
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+/**
+ * 최근 측정값의 작은 추세선
+ * 값이 ceiling보다 작으면 ceiling을 위쪽 끝으로 잡아 작은 변화가 과장되지 않게 한다.
+ */
+#[component]
+pub fn Sparkline(
+    values: Vec<f64>,
+    // 세로 축의 최소 상한 (ex. CPU 10%), 0이면 최댓값 기준
+    #[prop(optional)] ceiling: f64,
+    #[prop(default = "stroke-indigo-500")] class: &'static str,
+) -> impl IntoView {
+    // 선을 그리려면 두 점 이상 필요하다.
+    (values.len() >= 2).then(|| {
+        view! {
+            <svg
+                class="inline-block align-middle"
+                width=WIDTH
+                height=HEIGHT
+                viewBox=format!("0 0 {} {}", WIDTH, HEIGHT)
+            >
+                <polyline class=class fill="none" stroke-width="1.5" points=points(&values, ceiling)/>
+            </svg>
+        }
+    })
+}
@@ -1,126 +1,527 @@
-use std::str::FromStr;
+use std::collections::{HashMap, HashSet};
 
 use ev::MouseEvent;
 // components/port_table.rs
-use crate::interfaces::{filter::FILTER, port::PortInfo, sort::SORT};
+use crate::components::sparkline::Sparkline;
+use crate::interfaces::{
+    bulk::BulkAction,
+    cgroup::CgroupInfo,
+    fingerprint::{FingerprintResult, OpenArgs},
+    kill::KillSignal,
+    port::{group_by_socket, PortInfo, SocketGroup},
+    probe::ProbeResult,
+    sort::SortField,
+    view_model::PortViewModel,
+    watch::RowHighlight,
+};
+use crate::utils::tauri::invoke_command;
 use leptos::*;
 use leptos_dom::logging::console_error;
-use wasm_bindgen::JsCast;
+
+// HTTP 서버로 확인된 포트를 기본 브라우저로 연다.
+pub fn open_in_browser(url: String) {
+    spawn_local(async move {
+        if let Err(e) = invoke_command::<()>("plugin:shell|open", &OpenArgs { path: url }).await {
+            console_error(&e.to_string());
+        }
+    });
+}
 
 #[component]
 pub fn PortTable(
-    props: ReadSignal<Vec<PortInfo>>,
+    // 검색, 정렬, 선택 상태와 보이는 목록
+    view_model: PortViewModel,
+    // 감시 이벤트로 열리거나 닫힌 행의 강조 상태
+    highlights: ReadSignal<HashMap<String, RowHighlight>>,
+    // 소켓마다 마지막 연결 확인 결과
+    probes: ReadSignal<HashMap<String, ProbeResult>>,
+    fingerprints: ReadSignal<HashMap<String, FingerprintResult>>,
     delete_cb: impl Fn(String) + 'static + Clone,
+    // 컨테이너가 점유한 포트는 프록시 프로세스 대신 컨테이너를 멈춘다.
+    stop_container_cb: impl Fn(String) + 'static + Clone,
+    // systemd 서비스가 다시 띄우는 프로세스는 서비스를 멈춘다. (unit, user)
+    stop_unit_cb: impl Fn(String, bool) + 'static + Clone,
+    // 앱이 예약한 포트는 프로세스(앱 자신)를 종료하지 않고 예약만 해제한다.
+    release_cb: impl Fn(String) + 'static + Clone,
+    // 선택한 행에 한 번에 실행할 작업
+    bulk_cb: impl Fn(BulkAction, Vec<PortInfo>) + 'static + Clone,
+    // 행을 클릭하면 그 프로세스(pid)의 상세 패널을 연다.
+    detail_cb: impl Fn(String) + 'static + Clone,
 ) -> impl IntoView {
-    let (filter, set_filter) = create_signal(FILTER::PROCESS);
-    let (select_sort, set_select_sort) = create_signal(SORT::NONE);
-
-    // 헤더 클릭에 따라 select_sort, filter의 상태변화
-    let on_header_click = move |e: MouseEvent| {
-        if let Some(target) = e.target() {
-            // 대상 요소를 HtmlElement로 변환
-            // 요소의 텍스트 내용(innerText)을 가져옴
-            if let Some(element) = target.dyn_ref::<web_sys::HtmlElement>() {
-                let header_text = element.inner_text();
-
-                let event_value = match FILTER::from_str(&header_text) {
-                    Ok(ok) => ok,
-                    Err(_) => {
-                        console_error("Failed to parse FILTER value");
-                        return;
-                    }
-                };
-
-                if filter.get() == event_value {
-                    set_select_sort.update(|option| {
-                        *option = match *option {
-                            SORT::NONE => SORT::ASC,
-                            SORT::ASC => SORT::DESC,
-                            SORT::DESC => SORT::NONE,
-                        };
-                    });
-                } else {
-                    set_filter.set(event_value);
-                    set_select_sort.set(SORT::ASC);
-                }
-            }
+    // 소유 프로세스 목록을 펼친 소켓 (socket_key)
+    let (expanded, set_expanded) = create_signal(HashSet::<String>::new());
+    let selected = view_model.selected;
+    // shift 클릭은 마지막으로 클릭한 소켓부터 범위를 선택한다.
+    let anchor = store_value(None::<String>);
+
+    // 클릭하면 이 열로만 정렬하고, shift 클릭하면 정렬 기준에 추가한다.
+    let sort_header = move |field: SortField| {
+        view! {
+            <th
+                scope="col"
+                class="p-5 text-left text-sm leading-6 font-semibold text-gray-900 capitalize cursor-pointer whitespace-nowrap"
+                title="Click to sort, shift-click to add a sort key"
+                on:click=move |e: MouseEvent| view_model.toggle_sort(field, e.shift_key())
+            >
+                {field.to_string()}
+                <span class="ml-1 text-xs text-indigo-600">{move || view_model.sort_indicator(field)}</span>
+            </th>
         }
     };
 
-    // create_memo를 이용해 계산된 값을 뷰를 이용하는데 그린다.
-    // 현재 상태에 따라 값 갱신
-    let sort_and_filter_ports = create_memo(move |_| {
-        let mut filtered_ports = props.get();
-        let current_filter = filter.get();
-        let current_sort = select_sort.get();
-
-        match current_filter {
-            FILTER::PROCESS => match current_sort {
-                SORT::ASC => {
-                    filtered_ports.sort_by(|a, b| a.get_process_name().cmp(&b.get_process_name()));
-                }
-                SORT::DESC => {
-                    filtered_ports.sort_by(|a, b| b.get_process_name().cmp(&a.get_process_name()));
-                }
-                SORT::NONE => {}
-            },
-            FILTER::PORT => match current_sort {
-                SORT::ASC => {
-                    filtered_ports
-                        .sort_by(|a, b| a.get_port_as_usize().cmp(&b.get_port_as_usize()));
-                }
-                SORT::DESC => {
-                    filtered_ports
-                        .sort_by(|a, b| b.get_port_as_usize().cmp(&a.get_port_as_usize()));
-                }
-                SORT::NONE => {}
-            },
-            FILTER::PID => match current_sort {
-                SORT::ASC => {
-                    filtered_ports.sort_by(|a, b| a.get_pid_as_usize().cmp(&b.get_pid_as_usize()));
-                }
-                SORT::DESC => {
-                    filtered_ports.sort_by(|a, b| b.get_pid_as_usize().cmp(&a.get_pid_as_usize()));
+    let visible_groups = create_memo(move |_| group_by_socket(view_model.visible.get()));
+
+    // 현재 보이는 행 중 선택한 행 (필터로 가려진 행은 제외)
+    let selected_rows = create_memo(move |_| {
+        let selected = selected.get();
+        visible_groups
+            .get()
+            .into_iter()
+            .filter(|group| selected.contains(&group.socket_key()))
+            .flat_map(|group| group.owners)
+            .collect::<Vec<PortInfo>>()
+    });
+
+    let all_selected = move || {
+        let selected = selected.get();
+        let groups = visible_groups.get();
+        !groups.is_empty() && groups.iter().all(|group| selected.contains(&group.socket_key()))
+    };
+
+    let toggle_all = move |e| {
+        let checked = event_target_checked(&e);
+        let keys: Vec<String> = visible_groups
+            .get_untracked()
+            .iter()
+            .map(|group| group.socket_key())
+            .collect();
+
+        selected.update(|selected| {
+            if checked {
+                selected.extend(keys);
+            } else {
+                selected.clear();
+            }
+        });
+    };
+
+    let toggle_select = move |socket_key: String, shift: bool| {
+        let keys: Vec<String> = visible_groups
+            .get_untracked()
+            .iter()
+            .map(|group| group.socket_key())
+            .collect();
+        let range = anchor
+            .get_value()
+            .filter(|_| shift)
+            .and_then(|anchor| keys.iter().position(|key| *key == anchor))
+            .zip(keys.iter().position(|key| *key == socket_key));
+
+        selected.update(|selected| match range {
+            Some((from, to)) => {
+                let (start, end) = if from <= to { (from, to) } else { (to, from) };
+                selected.extend(keys[start..=end].iter().cloned());
+            }
+            None => {
+                if !selected.remove(&socket_key) {
+                    selected.insert(socket_key.clone());
                 }
-                SORT::NONE => {}
-            },
+            }
+        });
+        anchor.set_value(Some(socket_key));
+    };
+
+    // pid의 최근 사용량과 추세선, 감시 중일 때만 채워진다.
+    let usage_cells = move |pid: String, cell_class: &'static str| {
+        let samples = move || view_model.usage.with(|usage| usage.get(&pid).cloned().unwrap_or_default());
+        let cpu_samples = samples.clone();
+        view! {
+            <td class=cell_class>
+                {move || {
+                    let samples = cpu_samples();
+                    let label = samples.last().and_then(|usage| usage.cpu_label()).unwrap_or_default();
+                    let values: Vec<f64> = samples.iter().filter_map(|usage| usage.cpu_percent).collect();
+                    view! {
+                        <div class="flex items-center space-x-2">
+                            <span class="w-12 text-right">{label}</span>
+                            <Sparkline values=values ceiling=10.0/>
+                        </div>
+                    }
+                }}
+            </td>
+            <td class=cell_class>
+                {move || {
+                    let samples = samples();
+                    let label = samples.last().and_then(|usage| usage.memory_label()).unwrap_or_default();
+                    let values: Vec<f64> = samples
+                        .iter()
+                        .filter_map(|usage| usage.memory_bytes)
+                        .map(|bytes| bytes as f64)
+                        .collect();
+                    view! {
+                        <div class="flex items-center space-x-2">
+                            <span class="w-16 text-right">{label}</span>
+                            <Sparkline values=values class="stroke-emerald-500"/>
+                        </div>
+                    }
+                }}
+            </td>
         }
+    };
 
-        filtered_ports
-    });
+    // 예약한 행, 컨테이너, systemd 서비스의 주 프로세스는 프로세스 종료 대신 각 행의 버튼으로 멈춘다.
+    let kill_blocked = move || {
+        selected_rows.with(|rows| {
+            rows.iter().any(|row| {
+                row.is_reserved()
+                    || row.get_container().is_some()
+                    || matches!(
+                        row.get_cgroup(),
+                        Some(CgroupInfo::SystemdUnit { main: true, .. })
+                    )
+            })
+        })
+    };
+
+    let bulk_button = {
+        let bulk_cb = bulk_cb.clone();
+        move |label: &'static str, title: &'static str, action: BulkAction| {
+            let bulk_cb = bulk_cb.clone();
+            let blocked = {
+                let kills = matches!(action, BulkAction::Kill { .. });
+                move || kills && kill_blocked()
+            };
+            view! {
+                <button
+                    class=move || if blocked() {
+                        "px-3 py-1 rounded-lg text-xs font-bold border-2 border-gray-300 text-gray-400 cursor-not-allowed"
+                    } else {
+                        "px-3 py-1 rounded-lg text-xs font-bold border-2 text-gray-800 hover:bg-gray-100"
+                    }
+                    title=move || if blocked() {
+                        "Selection includes reserved rows, containers or services. Stop them from their own rows"
+                    } else {
+                        title
+                    }
+                    disabled=blocked
+                    on:click=move |_e| bulk_cb(action.clone(), selected_rows.get_untracked())
+                >
+                    {label}
+                </button>
+            }
+        }
+    };
 
     view! {
         <div class="overflow-hidden ">
+        <Show when=move || !selected_rows.get().is_empty()>
+            <div class="mx-4 my-2 flex items-center space-x-2 text-sm">
+                <span class="font-bold text-gray-800">{move || format!("{} selected", selected_rows.get().len())}</span>
+                {bulk_button("Kill", "Force kill selected processes", BulkAction::Kill { signal: KillSignal::KILL })}
+                {bulk_button("Terminate", "Ask selected processes to exit", BulkAction::Kill { signal: KillSignal::TERM })}
+                {bulk_button("Export", "Save selected rows as CSV in the downloads folder", BulkAction::Export)}
+                {bulk_button("Copy", "Copy selected rows to the clipboard", BulkAction::Copy)}
+                <button class="text-xs font-bold text-gray-500 hover:underline" on:click=move |_e| selected.set(HashSet::new())>
+                    "Clear"
+                </button>
+            </div>
+        </Show>
         <table class=" min-w-full rounded-xl">
             <thead>
                 <tr class="bg-gray-50 select-none">
-                    <th scope="col" class="p-5 text-left text-sm leading-6 font-semibold text-gray-900 capitalize rounded-t-xl cursor-pointer" on:click=on_header_click>{FILTER::PROCESS.to_string()}</th>
-                    <th scope="col" class="p-5 text-left text-sm leading-6 font-semibold text-gray-900 capitalize cursor-pointer" on:click=on_header_click>{FILTER::PORT.to_string()}</th>
-                    <th scope="col" class="p-5 text-left text-sm leading-6 font-semibold text-gray-900 capitalize cursor-pointer" on:click=on_header_click>{FILTER::PID.to_string()}</th>
+                    <th scope="col" class="pl-5 rounded-t-xl">
+                        <input type="checkbox" title="Select all filtered rows" prop:checked=all_selected on:change=toggle_all/>
+                    </th>
+                    {[
+                        SortField::PROCESS,
+                        SortField::PORT,
+                        SortField::PID,
+                        SortField::PROTOCOL,
+                        SortField::ADDRESS,
+                        SortField::STATE,
+                        SortField::USER,
+                        SortField::STARTED,
+                        SortField::CONNECTIONS,
+                        SortField::CPU,
+                        SortField::MEMORY,
+                    ]
+                        .into_iter()
+                        .map(sort_header)
+                        .collect_view()}
                     <th scope="col" class="p-5 text-left text-sm leading-6 font-semibold text-gray-900 capitalize"> Kill </th>
                 </tr>
             </thead>
             <tbody class="divide-y divide-gray-300 ">
             <For
-                each=move || sort_and_filter_ports.get()
-                key=|prop| format!("{}_{}", prop.get_pid(), prop.get_port())
-                children=move |port_info: PortInfo| {
+                each=move || visible_groups.get()
+                // 이름, 사용자, 컨테이너가 나중에 채워지거나 소유 프로세스가 바뀌면 행을 다시 그리도록 키에 포함
+                key=|group| {
+                    group
+                        .owners
+                        .iter()
+                        .map(|prop| format!(
+                            "{}_{}_{:?}_{:?}_{:?}_{:?}_{:?}_{:?}",
+                            prop.key(),
+                            prop.get_process_name(),
+                            prop.get_user(),
+                            prop.get_container().map(|container| container.id),
+                            prop.get_cgroup(),
+                            prop.kill_denied_reason(),
+                            prop.get_started_at(),
+                            prop.get_connections()
+                        ))
+                        .collect::<Vec<String>>()
+                        .join("|")
+                }
+                children=move |group: SocketGroup| {
                         let delete_cb = delete_cb.clone();
+                        let stop_container_cb = stop_container_cb.clone();
+                        let stop_unit_cb = stop_unit_cb.clone();
+                        let release_cb = release_cb.clone();
+                        let detail_cb = detail_cb.clone();
+                        let port_info = group.primary().clone();
+                        let owners = group.owners.clone();
+                        let owner_count = owners.len();
+                        let socket_key = group.socket_key();
+                        let probe = {
+                            let socket_key = socket_key.clone();
+                            move || probes.get().get(&socket_key).cloned()
+                        };
+                        let fingerprint = {
+                            let socket_key = socket_key.clone();
+                            move || {
+                                fingerprints
+                                    .get()
+                                    .get(&socket_key)
+                                    .and_then(|result| result.fingerprint.clone())
+                            }
+                        };
+                        let reserved = port_info.is_reserved();
+                        let container = port_info.get_container();
+                        let cgroup = port_info.get_cgroup();
+                        let row_keys: Vec<String> = owners.iter().map(|owner| owner.key()).collect();
+                        let row_class = move || {
+                            let highlights = highlights.get();
+                            match row_keys.iter().find_map(|key| highlights.get(key)) {
+                                Some(RowHighlight::Added) => "bg-green-100 transition-all duration-500",
+                                Some(RowHighlight::Removed) => "bg-red-100 opacity-50 transition-all duration-500",
+                                None => "bg-white transition-all duration-500 hover:bg-gray-100 cursor-pointer",
+                            }
+                        };
+                        let is_expanded = {
+                            let socket_key = socket_key.clone();
+                            move || expanded.get().contains(&socket_key)
+                        };
+                        let is_selected = {
+                            let socket_key = socket_key.clone();
+                            move || selected.get().contains(&socket_key)
+                        };
+                        let on_select = {
+                            let socket_key = socket_key.clone();
+                            move |e: MouseEvent| toggle_select(socket_key.clone(), e.shift_key())
+                        };
+
+                        // 버튼, 체크박스, 링크를 누른 경우는 제외한다.
+                        let on_row_click = {
+                            let pid = port_info.get_pid();
+                            move |e: MouseEvent| {
+                                let on_control = event_target::<web_sys::Element>(&e)
+                                    .closest("button, input, a")
+                                    .ok()
+                                    .flatten()
+                                    .is_some();
+                                if !on_control {
+                                    detail_cb(pid.clone());
+                                }
+                            }
+                        };
+
+                        let toggle_expanded = move |_e| {
+                            let socket_key = socket_key.clone();
+                            set_expanded.update(move |expanded| {
+                                if !expanded.remove(&socket_key) {
+                                    expanded.insert(socket_key);
+                                }
+                            });
+                        };
 
                         view!
                         {
-                            <tr class="bg-white transition-all duration-500 hover:bg-gray-100">
-                                <td class="p-5 whitespace-nowrap text-sm leading-6 font-medium text-gray-900 ">{port_info.get_process_name()}</td>
-                                <td class="p-5 whitespace-nowrap text-sm leading-6 font-medium text-gray-900">{port_info.get_port()}</td>
-                                <td class="p-5 whitespace-nowrap text-sm leading-6 font-medium text-gray-900">{port_info.get_pid()}</td>
+                            <tr class=row_class on:click=on_row_click>
+                                <td class="pl-5">
+                                    <input type="checkbox" prop:checked=is_selected on:click=on_select/>
+                                </td>
+                                <td class="p-5 whitespace-nowrap text-sm leading-6 font-medium text-gray-900 ">
+                                    <div>{port_info.get_process_name()}</div>
+                                    {reserved.then(|| view! {
+                                        <div class="text-xs text-indigo-600">"reserved by open_port_check"</div>
+                                    })}
+                                    {container.clone().map(|container| view! {
+                                        <div class="text-xs text-gray-500">
+                                            {format!("{} · {}", container.name, container.image)}
+                                            {container.compose_project.map(|project| format!(" · {}", project))}
+                                        </div>
+                                    })}
+                                    {cgroup.clone().filter(|_| container.is_none()).map(|cgroup| view! {
+                                        <div class="text-xs text-gray-500">{cgroup.label()}</div>
+                                    })}
+                                </td>
+                                <td class="p-5 whitespace-nowrap text-sm leading-6 font-medium text-gray-900">
+                                    <div class="flex items-center space-x-2">
+                                        {move || probe().map(|probe| view! {
+                                            <span class=format!("inline-block w-2 h-2 rounded-full {}", probe.color()) title=probe.describe()></span>
+                                        })}
+                                        <span>{port_info.get_port()}</span>
+                                        {
+                                            let fingerprint = fingerprint.clone();
+                                            move || fingerprint().and_then(|fingerprint| fingerprint.url()).map(|url| view! {
+                                                <button class="text-xs font-bold text-indigo-600 hover:underline" title=format!("Open {}", url) on:click=move |_e| open_in_browser(url.clone())>
+                                                    "Open"
+                                                </button>
+                                            })
+                                        }
+                                    </div>
+                                    {move || fingerprint().map(|fingerprint| view! {
+                                        <div class="text-xs text-indigo-600">{fingerprint.summary()}</div>
+                                    })}
+                                    {port_info.get_service().map(|service| view! {
+                                        <div class="text-xs text-gray-500">{service}</div>
+                                    })}
+                                </td>
+                                <td class="p-5 whitespace-nowrap text-sm leading-6 font-medium text-gray-900">
+                                    {if owner_count > 1 {
+                                        let is_expanded = is_expanded.clone();
+                                        view! {
+                                            <button class="px-2 py-1 rounded-lg text-xs font-bold border-2 text-gray-800 hover:bg-gray-100" title="Show all owners" on:click=toggle_expanded>
+                                                {move || format!("{} {} owners", if is_expanded() { "▾" } else { "▸" }, owner_count)}
+                                            </button>
+                                        }.into_view()
+                                    } else {
+                                        port_info.get_pid().into_view()
+                                    }}
+                                </td>
+                                <td class="p-5 whitespace-nowrap text-sm leading-6 font-medium text-gray-500">{port_info.get_protocol()}</td>
+                                <td class="p-5 whitespace-nowrap text-sm leading-6 font-medium text-gray-500">{port_info.get_address()}</td>
+                                <td class="p-5 whitespace-nowrap text-sm leading-6 font-medium text-gray-500">{port_info.get_state()}</td>
+                                <td class="p-5 whitespace-nowrap text-sm leading-6 font-medium text-gray-500">{port_info.get_user().unwrap_or_default()}</td>
+                                <td class="p-5 whitespace-nowrap text-sm leading-6 font-medium text-gray-500">{port_info.uptime_label().unwrap_or_default()}</td>
+                                <td class="p-5 whitespace-nowrap text-sm leading-6 font-medium text-gray-500">
+                                    {port_info.get_connections().map(|count| count.to_string()).unwrap_or_default()}
+                                </td>
+                                {usage_cells(port_info.get_pid(), "p-5 whitespace-nowrap text-sm leading-6 font-medium text-gray-500")}
                                 <td class=" p-5 ">
-                                    <button class="p-2 rounded-full  group transition-all duration-500  flex item-center hover:bg-gray-600" on:click= move |_e| {
-                                        delete_cb(port_info.get_pid());
-                                    }>
-                                        <img src="/public/trash.svg" width="20" height="20" alt="Icon" />
-                                    </button>
+                                    {match (container, cgroup) {
+                                        _ if reserved => view! {
+                                            <button class="px-3 py-1 rounded-lg text-xs font-bold border-2 text-gray-800 hover:bg-gray-100" title="Release reservation" on:click= move |_e| {
+                                                release_cb(port_info.get_port());
+                                            }>
+                                                "Release"
+                                            </button>
+                                        }.into_view(),
+                                        (Some(container), _) => view! {
+                                            <button class="px-3 py-1 rounded-lg text-xs font-bold border-2 text-gray-800 hover:bg-gray-100" title="Stop container" on:click= move |_e| {
+                                                stop_container_cb(container.id.clone());
+                                            }>
+                                                "Stop container"
+                                            </button>
+                                        }.into_view(),
+                                        (None, Some(CgroupInfo::SystemdUnit { unit, user, main: true })) => view! {
+                                            <button class="px-3 py-1 rounded-lg text-xs font-bold border-2 text-gray-800 hover:bg-gray-100" title=format!("Stop {}", unit) on:click= move |_e| {
+                                                stop_unit_cb(unit.clone(), user);
+                                            }>
+                                                "Stop unit"
+                                            </button>
+                                        }.into_view(),
+                                        // 여러 프로세스가 가진 소켓은 모두 종료해야 포트가 닫힌다.
+                                        _ if owner_count > 1 => {
+                                            let delete_cb = delete_cb.clone();
+                                            let pids: Vec<String> = owners.iter().map(|owner| owner.get_pid()).collect();
+                                            // 하나라도 종료할 수 없으면 포트가 닫히지 않는다.
+                                            let denied = owners
+                                                .iter()
+                                                .find_map(|owner| owner.kill_denied_reason().map(|reason| format!("pid {}: {}", owner.get_pid(), reason)));
+                                            view! {
+                                                <button
+                                                    class=if denied.is_some() {
+                                                        "px-3 py-1 rounded-lg text-xs font-bold border-2 border-gray-300 text-gray-400 cursor-not-allowed"
+                                                    } else {
+                                                        "px-3 py-1 rounded-lg text-xs font-bold border-2 border-red-500 text-red-600 hover:bg-red-50"
+                                                    }
+                                                    title=denied.clone().unwrap_or("Kill all owners".to_string())
+                                                    disabled=denied.is_some()
+                                                    on:click= move |_e| {
+                                                        pids.iter().for_each(|pid| delete_cb(pid.clone()));
+                                                    }
+                                                >
+                                                    "Kill all"
+                                                </button>
+                                            }.into_view()
+                                        }
+                                        _ => {
+                                            let delete_cb = delete_cb.clone();
+                                            let denied = port_info.kill_denied_reason();
+                                            let note = port_info.kill_note();
+                                            view! {
+                                                <button
+                                                    class=if denied.is_some() {
+                                                        "p-2 rounded-full flex item-center opacity-30 cursor-not-allowed"
+                                                    } else {
+                                                        "p-2 rounded-full  group transition-all duration-500  flex item-center hover:bg-gray-600"
+                                                    }
+                                                    title=note.unwrap_or("Kill process".to_string())
+                                                    disabled=denied.is_some()
+                                                    on:click= move |_e| {
+                                                        delete_cb(port_info.get_pid());
+                                                    }
+                                                >
+                                                    <img src="/public/trash.svg" width="20" height="20" alt="Icon" />
+                                                </button>
+                                            }.into_view()
+                                        }
+                                    }}
                                 </td>
                             </tr>
+                            // 펼치면 소유 프로세스마다 한 줄씩, 하나만 골라 종료할 수 있다.
+                            <Show when=move || { owner_count > 1 && is_expanded() }>
+                                {owners
+                                    .iter()
+                                    .map(|owner| {
+                                        let delete_cb = delete_cb.clone();
+                                        let pid = owner.get_pid();
+                                        let denied = owner.kill_denied_reason();
+                                        let note = owner.kill_note();
+                                        view! {
+                                            <tr class="bg-gray-50">
+                                                <td></td>
+                                                <td class="pl-10 pr-5 py-2 whitespace-nowrap text-sm text-gray-700">{owner.get_process_name()}</td>
+                                                <td class="px-5 py-2"></td>
+                                                <td class="px-5 py-2 whitespace-nowrap text-sm text-gray-700">{owner.get_pid()}</td>
+                                                <td class="px-5 py-2" colspan="3"></td>
+                                                <td class="px-5 py-2 whitespace-nowrap text-sm text-gray-500">{owner.get_user().unwrap_or_default()}</td>
+                                                <td class="px-5 py-2 whitespace-nowrap text-sm text-gray-500">{owner.uptime_label().unwrap_or_default()}</td>
+                                                <td class="px-5 py-2"></td>
+                                                {usage_cells(owner.get_pid(), "px-5 py-2 whitespace-nowrap text-sm text-gray-500")}
+                                                <td class="px-5 py-2">
+                                                    <button
+                                                        class=if denied.is_some() {
+                                                            "p-2 rounded-full flex item-center opacity-30 cursor-not-allowed"
+                                                        } else {
+                                                            "p-2 rounded-full  group transition-all duration-500  flex item-center hover:bg-gray-600"
+                                                        }
+                                                        title=note.unwrap_or("Kill this owner".to_string())
+                                                        disabled=denied.is_some()
+                                                        on:click= move |_e| {
+                                                            delete_cb(pid.clone());
+                                                        }
+                                                    >
+                                                        <img src="/public/trash.svg" width="16" height="16" alt="Icon" />
+                                                    </button>
+                                                </td>
+                                            </tr>
+                                        }
+                                    })
+                                    .collect_view()}
+                            </Show>
                         }
                     }
                 />
@@ -0,0 +1,201 @@
+use std::collections::BTreeMap;
+
+use js_sys::Date;
+use leptos::*;
+use wasm_bindgen::JsValue;
+
+use crate::interfaces::history::{HistoryQuery, PortLifetime, QueryHistoryArgs};
+use crate::utils::tauri::invoke_command;
+
+const HOUR_MS: u64 = 60 * 60 * 1000;
+
+// (표시 이름, 구간 ms), 한 시간부터 일주일까지 확대/축소
+const RANGES: [(&str, u64); 5] = [
+    ("1h", HOUR_MS),
+    ("6h", 6 * HOUR_MS),
+    ("24h", 24 * HOUR_MS),
+    ("3d", 3 * 24 * HOUR_MS),
+    ("7d", 7 * 24 * HOUR_MS),
+];
+
+// 프로세스 이름마다 같은 색을 사용
+const COLORS: [&str; 8] = [
+    "bg-pink-500",
+    "bg-indigo-500",
+    "bg-green-500",
+    "bg-yellow-500",
+    "bg-blue-500",
+    "bg-purple-500",
+    "bg-red-500",
+    "bg-teal-500",
+];
+
+fn color_of(process_name: &str) -> &'static str {
+    let hash = process_name
+        .bytes()
+        .fold(0_usize, |acc, byte| acc.wrapping_mul(31).wrapping_add(byte as usize));
+    COLORS[hash % COLORS.len()]
+}
+
+fn format_time(ms: u64) -> String {
+    Date::new(&JsValue::from_f64(ms as f64))
+        .to_locale_string("default", &JsValue::UNDEFINED)
+        .into()
+}
+
+/**
+ * 포트마다 한 줄(lane)로 묶고, 포트 번호 순으로 정렬한다.
+ */
+fn group_lanes(lifetimes: Vec<PortLifetime>) -> Vec<(String, Vec<PortLifetime>)> {
+    let mut lanes: BTreeMap<(usize, String), Vec<PortLifetime>> = BTreeMap::new();
+
+    for lifetime in lifetimes {
+        let port = lifetime.port.get_port();
+        let order = port.parse::<usize>().unwrap_or(usize::MAX);
+        lanes.entry((order, port)).or_default().push(lifetime);
+    }
+
+    lanes
+        .into_iter()
+        .map(|((_, port), lifetimes)| (port, lifetimes))
+        .collect()
+}
+
+#[component]
+pub fn Timeline() -> impl IntoView {
+    let (range_ms, set_range_ms) = create_signal(HOUR_MS);
+    let (now, set_now) = create_signal(Date::now() as u64);
+    let (selected, set_selected) = create_signal(None::<PortLifetime>);
+
+    let lifetimes = create_resource(
+        move || range_ms.get(),
+        move |range| async move {
+            let current = Date::now() as u64;
+            set_now.set(current);
+
+            let args = QueryHistoryArgs {
+                query: HistoryQuery {
+                    from: Some(current.saturating_sub(range)),
+                    to: Some(current),
+                    ..HistoryQuery::default()
+                },
+            };
+
+            invoke_command::<Vec<PortLifetime>>("query_history", &args)
+                .await
+                .unwrap_or_default()
+        },
+    );
+
+    let lanes = move || group_lanes(lifetimes.get().unwrap_or_default());
+
+    view! {
+        <div class="p-4 space-y-4">
+            <div class="flex items-center space-x-2">
+                {RANGES
+                    .iter()
+                    .map(|(label, ms)| {
+                        let ms = *ms;
+                        view! {
+                            <button
+                                class=move || if range_ms.get() == ms {
+                                    "rounded-lg px-3 py-1 text-sm font-bold bg-indigo-600 text-white"
+                                } else {
+                                    "rounded-lg px-3 py-1 text-sm font-bold border-2 text-gray-800"
+                                }
+                                on:click=move |_e| set_range_ms.set(ms)
+                            >
+                                {*label}
+                            </button>
+                        }
+                    })
+                    .collect_view()}
+                <button
+                    class="bg-indigo-600 text-white rounded-lg px-3 py-1 hover:bg-indigo-500"
+                    on:click=move |_e| lifetimes.refetch()
+                >
+                    <img src="/public/refresh.svg" width="16" height="16" alt="Icon" />
+                </button>
+            </div>
+
+            <div class="flex justify-between text-xs text-gray-500 pl-20">
+                <span>{move || format_time(now.get().saturating_sub(range_ms.get()))}</span>
+                <span>{move || format_time(now.get())}</span>
+            </div>
+
+            <Show
+                when=move || !lanes().is_empty()
+                fallback=|| view! {
+                    <div class="text-sm text-gray-500">"No history in this range. Turn on REC to record port events."</div>
+                }
+            >
+                <div class="space-y-1">
+                    {move || {
+                        let from = now.get().saturating_sub(range_ms.get());
+                        let to = now.get();
+                        let range = (to - from).max(1) as f64;
+
+                        lanes()
+                            .into_iter()
+                            .map(|(port, lifetimes)| {
+                                view! {
+                                    <div class="flex items-center">
+                                        <div class="w-20 shrink-0 text-sm font-medium text-gray-900">{port}</div>
+                                        <div class="relative flex-grow h-6 bg-gray-100 rounded">
+                                            {lifetimes
+                                                .into_iter()
+                                                .map(|lifetime| {
+                                                    let start = lifetime.opened_at.unwrap_or(from).max(from);
+                                                    let end = lifetime.closed_at.unwrap_or(to).min(to);
+                                                    let left = (start - from) as f64 / range * 100.0;
+                                                    let width = (end.saturating_sub(start) as f64 / range * 100.0).max(0.5);
+                                                    let class = format!(
+                                                        "absolute top-0 h-6 rounded cursor-pointer opacity-80 hover:opacity-100 {}",
+                                                        color_of(&lifetime.port.get_process_name())
+                                                    );
+                                                    let title = format!(
+                                                        "{} ({})",
+                                                        lifetime.port.get_process_name(),
+                                                        lifetime.port.get_pid()
+                                                    );
+
+                                                    view! {
+                                                        <div
+                                                            class=class
+                                                            style=format!("left: {:.3}%; width: {:.3}%;", left, width)
+                                                            title=title
+                                                            on:click=move |_e| set_selected.set(Some(lifetime.clone()))
+                                                        ></div>
+                                                    }
+                                                })
+                                                .collect_view()}
+                                        </div>
+                                    </div>
+                                }
+                            })
+                            .collect_view()
+                    }}
+                </div>
+            </Show>
+
+            {move || selected.get().map(|lifetime| {
+                let port = lifetime.port.clone();
+                view! {
+                    <div class="rounded-lg shadow-gray-200 shadow-lg p-4 text-sm text-gray-900 space-y-1">
+                        <div class="font-bold">{format!("{} ({})", port.get_process_name(), port.get_pid())}</div>
+                        <div>{format!("{} {}:{}", port.get_protocol(), port.get_address(), port.get_port())}</div>
+                        <div>{format!("User: {}", port.get_user().unwrap_or_default())}</div>
+                        <div>{format!(
+                            "Opened: {}",
+                            lifetime.opened_at.map(format_time).unwrap_or("before recording".to_string())
+                        )}</div>
+                        <div>{format!(
+                            "Closed: {}",
+                            lifetime.closed_at.map(format_time).unwrap_or("still open".to_string())
+                        )}</div>
+                    </div>
+                }
+            })}
+        </div>
+    }
+}
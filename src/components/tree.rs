@@ -0,0 +1,249 @@
+use std::collections::HashSet;
+use std::str::FromStr;
+
+use ev::MouseEvent;
+use leptos::*;
+
+use crate::interfaces::group::{GroupBy, RowGroup};
+use crate::interfaces::port::PortInfo;
+use crate::interfaces::view_model::PortViewModel;
+
+#[component]
+pub fn GroupByControl(view_model: PortViewModel) -> impl IntoView {
+    view! {
+        <select
+            title="Group rows"
+            class="text-sm font-bold text-gray-800 outline-none border-2 px-2 py-1 rounded-lg"
+            prop:value=move || view_model.group_by.get().to_string()
+            on:change=move |e| {
+                if let Ok(group_by) = GroupBy::from_str(&event_target_value(&e)) {
+                    view_model.group_by.set(group_by);
+                }
+            }
+        >
+            {GroupBy::ALL
+                .into_iter()
+                .map(|item| view! { <option value=item.to_string()>{item.label()}</option> })
+                .collect_view()}
+        </select>
+    }
+}
+
+// 그룹 안의 행 중 하나라도 종료할 수 없다면 그 이유
+fn denied_reason(rows: &[PortInfo]) -> Option<String> {
+    rows.iter().find_map(|row| {
+        row.kill_denied_reason()
+            .map(|reason| format!("pid {}: {}", row.get_pid(), reason))
+    })
+}
+
+// 테이블과 같이 예약한 행과 컨테이너의 docker-proxy는 프로세스를 종료하지 않는다.
+fn excluded_reason(row: &PortInfo) -> Option<&'static str> {
+    if row.is_reserved() {
+        Some("Reserved by open_port_check. Release it from the table")
+    } else if row.get_container().is_some() {
+        Some("Owned by a container. Stop it from the table")
+    } else {
+        None
+    }
+}
+
+// 부모 행에서 종료할 pid, 예약한 행의 pid는 이 앱이므로 함께 뺀다.
+fn killable_pids(rows: &[PortInfo], pids: &[String]) -> Vec<String> {
+    let excluded: HashSet<String> = rows
+        .iter()
+        .filter(|row| excluded_reason(row).is_some())
+        .map(|row| row.get_pid())
+        .collect();
+
+    pids.iter()
+        .filter(|pid| !excluded.contains(*pid))
+        .cloned()
+        .collect()
+}
+
+/**
+ * 프로세스, 포트, 사용자로 묶은 트리
+ * 프로세스로 묶었다면 부모 행에서 프로세스를, 포트로 묶었다면 포트를 가진 모든 프로세스를 종료한다.
+ */
+#[component]
+pub fn PortTree(
+    view_model: PortViewModel,
+    delete_cb: impl Fn(String) + 'static + Clone,
+    // 행을 클릭하면 그 프로세스(pid)의 상세 패널을 연다.
+    detail_cb: impl Fn(String) + 'static + Clone,
+) -> impl IntoView {
+    // 접은 그룹 (RowGroup::key), 처음에는 모두 펼쳐져 있다.
+    let (collapsed, set_collapsed) = create_signal(HashSet::<String>::new());
+    let group_by = view_model.group_by;
+
+    view! {
+        <table class="min-w-full rounded-xl">
+            <thead>
+                <tr class="bg-gray-50 select-none">
+                    {["Group", "Port", "Address", "PID", "Process", "User", "Kill"]
+                        .into_iter()
+                        .map(|title| view! {
+                            <th scope="col" class="p-5 text-left text-sm leading-6 font-semibold text-gray-900 capitalize">{title}</th>
+                        })
+                        .collect_view()}
+                </tr>
+            </thead>
+            <tbody class="divide-y divide-gray-300">
+            <For
+                each=move || view_model.groups.get()
+                // 이름, 사용자, 종료 권한이 나중에 채워지면 다시 그린다.
+                key=|group| group
+                    .rows
+                    .iter()
+                    .map(|row| format!("{}_{}_{:?}_{:?}", row.key(), row.get_process_name(), row.get_user(), row.kill_denied_reason()))
+                    .fold(group.key.clone(), |key, row| format!("{}|{}", key, row))
+                children=move |group: RowGroup| {
+                    let delete_cb = delete_cb.clone();
+                    let detail_cb = detail_cb.clone();
+                    let grouping = group_by.get_untracked();
+                    let label = group.label(grouping);
+                    let pids = group.pids();
+                    let port_count = group.port_count();
+                    let killable = killable_pids(&group.rows, &pids);
+                    let denied = if killable.is_empty() {
+                        group.rows.iter().find_map(excluded_reason).map(String::from)
+                    } else {
+                        let rows: Vec<PortInfo> = group
+                            .rows
+                            .iter()
+                            .filter(|row| killable.contains(&row.get_pid()))
+                            .cloned()
+                            .collect();
+                        denied_reason(&rows)
+                    };
+                    let is_collapsed = {
+                        let key = group.key.clone();
+                        move || collapsed.get().contains(&key)
+                    };
+                    let toggle = {
+                        let key = group.key.clone();
+                        move |_e| {
+                            let key = key.clone();
+                            set_collapsed.update(move |collapsed| {
+                                if !collapsed.remove(&key) {
+                                    collapsed.insert(key);
+                                }
+                            });
+                        }
+                    };
+                    let pid_label = match pids.as_slice() {
+                        [pid] => pid.clone(),
+                        pids => format!("{} processes", pids.len()),
+                    };
+                    let user_label = match grouping {
+                        GroupBy::PROCESS => group.rows[0].get_user().unwrap_or_default(),
+                        _ => String::new(),
+                    };
+                    let kill_parent = {
+                        let delete_cb = delete_cb.clone();
+                        let pids = killable;
+                        // 사용자로 묶은 그룹은 관계없는 프로세스가 섞여 있어 한 번에 종료하지 않는다.
+                        (grouping != GroupBy::USER).then(move || {
+                            let title = match grouping {
+                                GroupBy::PROCESS => "Kill process",
+                                _ => "Kill all owners",
+                            };
+                            view! {
+                                <button
+                                    class=if denied.is_some() {
+                                        "px-3 py-1 rounded-lg text-xs font-bold border-2 border-gray-300 text-gray-400 cursor-not-allowed"
+                                    } else {
+                                        "px-3 py-1 rounded-lg text-xs font-bold border-2 border-red-500 text-red-600 hover:bg-red-50"
+                                    }
+                                    title=denied.clone().unwrap_or(title.to_string())
+                                    disabled=denied.is_some()
+                                    on:click=move |_e| pids.iter().for_each(|pid| delete_cb(pid.clone()))
+                                >
+                                    {if grouping == GroupBy::PROCESS { "Kill" } else { "Kill all" }}
+                                </button>
+                            }
+                        })
+                    };
+                    let rows = group.rows.clone();
+
+                    view! {
+                        <tr class="bg-gray-50 hover:bg-gray-100">
+                            <td class="p-5 whitespace-nowrap text-sm leading-6 font-semibold text-gray-900">
+                                <button class="mr-2 text-gray-500" title="Collapse or expand" on:click=toggle>
+                                    {
+                                        let is_collapsed = is_collapsed.clone();
+                                        move || if is_collapsed() { "▸" } else { "▾" }
+                                    }
+                                </button>
+                                {label}
+                            </td>
+                            <td class="p-5 whitespace-nowrap text-sm leading-6 text-gray-700">
+                                {if port_count == 1 { "1 port".to_string() } else { format!("{} ports", port_count) }}
+                            </td>
+                            <td class="p-5"></td>
+                            <td class="p-5 whitespace-nowrap text-sm leading-6 text-gray-700">{pid_label}</td>
+                            <td class="p-5"></td>
+                            <td class="p-5 whitespace-nowrap text-sm leading-6 text-gray-500">{user_label}</td>
+                            <td class="p-5">{kill_parent}</td>
+                        </tr>
+                        <Show when=move || !is_collapsed()>
+                            {rows
+                                .iter()
+                                .map(|row| {
+                                    let delete_cb = delete_cb.clone();
+                                    let pid = row.get_pid();
+                                    let denied = excluded_reason(row)
+                                        .map(String::from)
+                                        .or_else(|| row.kill_denied_reason());
+                                    // 버튼을 누른 경우는 제외한다.
+                                    let on_row_click = {
+                                        let detail_cb = detail_cb.clone();
+                                        let pid = pid.clone();
+                                        move |e: MouseEvent| {
+                                            let on_control = event_target::<web_sys::Element>(&e)
+                                                .closest("button, input, a")
+                                                .ok()
+                                                .flatten()
+                                                .is_some();
+                                            if !on_control {
+                                                detail_cb(pid.clone());
+                                            }
+                                        }
+                                    };
+                                    view! {
+                                        <tr class="bg-white hover:bg-gray-100 cursor-pointer" on:click=on_row_click>
+                                            <td class="pl-12 pr-5 py-2"></td>
+                                            <td class="px-5 py-2 whitespace-nowrap text-sm text-gray-900">{row.get_port()}</td>
+                                            <td class="px-5 py-2 whitespace-nowrap text-sm text-gray-500">
+                                                {format!("{} {} {}", row.get_protocol(), row.get_address(), row.get_state())}
+                                            </td>
+                                            <td class="px-5 py-2 whitespace-nowrap text-sm text-gray-700">{row.get_pid()}</td>
+                                            <td class="px-5 py-2 whitespace-nowrap text-sm text-gray-700">{row.get_process_name()}</td>
+                                            <td class="px-5 py-2 whitespace-nowrap text-sm text-gray-500">{row.get_user().unwrap_or_default()}</td>
+                                            <td class="px-5 py-2">
+                                                <button
+                                                    class=if denied.is_some() {
+                                                        "p-2 rounded-full flex item-center opacity-30 cursor-not-allowed"
+                                                    } else {
+                                                        "p-2 rounded-full  group transition-all duration-500  flex item-center hover:bg-gray-600"
+                                                    }
+                                                    title=denied.clone().unwrap_or("Kill process".to_string())
+                                                    disabled=denied.is_some()
+                                                    on:click=move |_e| delete_cb(pid.clone())
+                                                >
+                                                    <img src="/public/trash.svg" width="16" height="16" alt="Icon" />
+                                                </button>
+                                            </td>
+                                        </tr>
+                                    }
+                                })
+                                .collect_view()}
+                        </Show>
+                    }
+                }
+            />
+            </tbody>
+        </table>
+    }
+}
@@ -0,0 +1,81 @@
+use leptos::*;
+
+use crate::interfaces::watch::{WatchIntervalArgs, WatchStatus};
+use crate::utils::tauri::invoke_command;
+
+// (표시 이름, 간격 ms)
+const INTERVALS: [(&str, u64); 5] = [
+    ("1s", 1_000),
+    ("3s", 3_000),
+    ("5s", 5_000),
+    ("10s", 10_000),
+    ("30s", 30_000),
+];
+
+#[component]
+pub fn WatchControl(
+    // 감시를 재개할 때 기준 스냅샷을 맞추기 위해 전체 목록을 다시 불러온다.
+    on_resume: impl Fn() + 'static + Clone,
+) -> impl IntoView {
+    let (status, set_status) = create_signal(WatchStatus::default());
+
+    spawn_local(async move {
+        if let Ok(current) = invoke_command::<WatchStatus>("get_watch_status", &()).await {
+            set_status.set(current);
+        }
+    });
+
+    let on_toggle = move |_e| {
+        let on_resume = on_resume.clone();
+        let paused = status.get_untracked().paused;
+
+        spawn_local(async move {
+            let cmd = if paused { "resume_watch" } else { "pause_watch" };
+            if let Ok(current) = invoke_command::<WatchStatus>(cmd, &()).await {
+                set_status.set(current);
+                if paused {
+                    on_resume();
+                }
+            }
+        });
+    };
+
+    let on_interval_change = move |e| {
+        let Ok(interval_ms) = event_target_value(&e).parse::<u64>() else {
+            return;
+        };
+
+        spawn_local(async move {
+            let args = WatchIntervalArgs { interval_ms };
+            if let Ok(current) = invoke_command::<WatchStatus>("set_watch_interval", &args).await {
+                set_status.set(current);
+            }
+        });
+    };
+
+    view! {
+        <div class="flex flex-nowrap items-center px-2 space-x-2">
+            <button
+                class=move || if status.get().paused {
+                    "rounded-lg px-3 py-2 text-sm font-bold border-2 text-gray-800"
+                } else {
+                    "rounded-lg px-3 py-2 text-sm font-bold border-2 border-green-500 text-green-600"
+                }
+                on:click=on_toggle
+            >
+                {move || if status.get().paused { "LIVE OFF" } else { "LIVE ON" }}
+            </button>
+            <select
+                title="interval"
+                class="text-sm font-bold text-gray-800 outline-none border-2 px-2 py-2 rounded-lg"
+                prop:value=move || status.get().interval_ms.to_string()
+                on:change=on_interval_change
+            >
+                {INTERVALS
+                    .iter()
+                    .map(|(label, ms)| view! { <option value=ms.to_string()>{*label}</option> })
+                    .collect_view()}
+            </select>
+        </div>
+    }
+}
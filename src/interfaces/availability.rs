@@ -0,0 +1,75 @@
+use serde::{Deserialize, Serialize};
+
+// 백엔드의 UnavailableReason과 같은 형태
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum UnavailableReason {
+    #[serde(rename_all = "camelCase")]
+    InUse {
+        pid: Option<String>,
+        process_name: Option<String>,
+    },
+    PermissionDenied,
+    #[serde(rename_all = "camelCase")]
+    Excluded {
+        start: u16,
+        end: u16,
+    },
+    AddressNotAvailable,
+    #[serde(rename_all = "camelCase")]
+    Other {
+        message: String,
+    },
+}
+
+impl ToString for UnavailableReason {
+    fn to_string(&self) -> String {
+        match self {
+            UnavailableReason::InUse {
+                pid: Some(pid),
+                process_name,
+            } => format!(
+                "in use by {} ({})",
+                process_name.clone().unwrap_or("unknown".to_string()),
+                pid
+            ),
+            UnavailableReason::InUse { pid: None, .. } => "in use".to_string(),
+            UnavailableReason::PermissionDenied => {
+                "permission denied (ports below 1024 need admin)".to_string()
+            }
+            UnavailableReason::Excluded { start, end } => {
+                format!("excluded by the OS ({}-{})", start, end)
+            }
+            UnavailableReason::AddressNotAvailable => "address not available".to_string(),
+            UnavailableReason::Other { message } => message.clone(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressAvailability {
+    pub address: String,
+    pub reason: Option<UnavailableReason>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct PortAvailability {
+    pub port: u16,
+    pub available: bool,
+    pub addresses: Vec<AddressAvailability>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct CheckPortArgs {
+    pub port: u16,
+    pub addresses: Option<Vec<String>>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct FindFreePortArgs {
+    pub start: u16,
+    pub end: u16,
+    pub addresses: Option<Vec<String>>,
+}
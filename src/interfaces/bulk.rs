@@ -0,0 +1,50 @@
+use serde::{Deserialize, Serialize};
+
+use crate::interfaces::kill::KillSignal;
+use crate::interfaces::port::PortInfo;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum BulkAction {
+    Kill { signal: KillSignal },
+    Export,
+    Copy,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct BulkResult {
+    pub pid: String,
+    pub success: bool,
+    pub message: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct BulkOutcome {
+    pub results: Vec<BulkResult>,
+    pub output: Option<String>,
+}
+
+impl BulkOutcome {
+    // 실패한 pid와 이유 (ex. "1 (system process), 999 (Failed to send signal)")
+    pub fn failures(&self) -> Option<String> {
+        let failures: Vec<String> = self
+            .results
+            .iter()
+            .filter(|result| !result.success)
+            .map(|result| match &result.message {
+                Some(message) => format!("{} ({})", result.pid, message),
+                None => result.pid.clone(),
+            })
+            .collect();
+
+        (!failures.is_empty()).then(|| failures.join(", "))
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct BulkActionArgs {
+    pub action: BulkAction,
+    pub ports: Vec<PortInfo>,
+}
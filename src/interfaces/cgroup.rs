@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum CgroupInfo {
+    #[serde(rename_all = "camelCase")]
+    SystemdUnit {
+        unit: String,
+        user: bool,
+        #[serde(default)]
+        main: bool,
+    },
+    #[serde(rename_all = "camelCase")]
+    Container { runtime: String, id: String },
+    #[serde(rename_all = "camelCase")]
+    UserSession { slice: String },
+    #[serde(rename_all = "camelCase")]
+    Other { path: String },
+}
+
+impl CgroupInfo {
+    // 프로세스 이름 아래에 보여줄 한 줄 설명
+    pub fn label(&self) -> String {
+        match self {
+            CgroupInfo::SystemdUnit {
+                unit, user: true, ..
+            } => format!("{} (user)", unit),
+            CgroupInfo::SystemdUnit {
+                unit, user: false, ..
+            } => unit.clone(),
+            CgroupInfo::Container { runtime, id } => {
+                format!("{} · {}", runtime, id.chars().take(12).collect::<String>())
+            }
+            CgroupInfo::UserSession { slice } => slice.clone(),
+            CgroupInfo::Other { path } => path.clone(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct StopUnitArgs<'a> {
+    pub unit: &'a str,
+    pub user: bool,
+}
@@ -0,0 +1,50 @@
+use serde::{Deserialize, Serialize};
+
+use crate::interfaces::port::PortInfo;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ScanCoverage {
+    pub elevated: bool,
+    pub unknown_owners: usize,
+    pub unreadable_processes: usize,
+    pub warnings: Vec<String>,
+}
+
+impl ScanCoverage {
+    pub fn is_partial(&self) -> bool {
+        self.unknown_owners > 0 || self.unreadable_processes > 0 || !self.warnings.is_empty()
+    }
+
+    // ex. "Partial view — 3 sockets with unknown owner"
+    pub fn summary(&self) -> String {
+        let mut details = Vec::new();
+
+        if self.unknown_owners > 0 {
+            details.push(format!(
+                "{} socket{} with unknown owner",
+                self.unknown_owners,
+                if self.unknown_owners == 1 { "" } else { "s" }
+            ));
+        }
+        if self.unreadable_processes > 0 {
+            details.push(format!(
+                "{} process{} not readable",
+                self.unreadable_processes,
+                if self.unreadable_processes == 1 { "" } else { "es" }
+            ));
+        }
+        if details.is_empty() {
+            details.push("other users' sockets may be hidden".to_string());
+        }
+
+        format!("Partial view — {}", details.join(", "))
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ElevatedScan {
+    pub rows: Vec<PortInfo>,
+    pub coverage: ScanCoverage,
+}
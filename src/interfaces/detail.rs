@@ -0,0 +1,92 @@
+use js_sys::Date;
+use serde::{Deserialize, Serialize};
+
+use crate::interfaces::kill::ChildProcess;
+use crate::interfaces::usage::format_bytes;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SocketEntry {
+    pub protocol: String,
+    pub local_address: String,
+    pub remote_address: Option<String>,
+    pub state: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct EnvVar {
+    pub name: String,
+    pub value: String,
+}
+
+impl EnvVar {
+    // 길이를 드러내지 않도록 고정된 길이로 가린다.
+    pub fn masked(&self) -> &'static str {
+        if self.value.is_empty() {
+            ""
+        } else {
+            "••••••••"
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ProcessDetail {
+    pub pid: String,
+    pub process_name: Option<String>,
+    pub command_line: Option<String>,
+    pub executable: Option<String>,
+    pub cwd: Option<String>,
+    pub environment: Option<Vec<EnvVar>>,
+    pub ancestors: Vec<ChildProcess>,
+    pub sockets: Vec<SocketEntry>,
+    pub cpu_percent: Option<f64>,
+    pub memory_bytes: Option<u64>,
+    pub started_at: Option<u64>,
+}
+
+impl ProcessDetail {
+    // ex) "3.2%"
+    pub fn cpu_label(&self) -> Option<String> {
+        self.cpu_percent.map(|percent| format!("{:.1}%", percent))
+    }
+
+    pub fn memory_label(&self) -> Option<String> {
+        self.memory_bytes.map(format_bytes)
+    }
+
+    // 로컬 시각 (ex. "2024. 5. 1. 오후 3:00:00")
+    pub fn started_label(&self) -> Option<String> {
+        self.started_at.map(|started_at| {
+            Date::new(&((started_at as f64) * 1000.0).into())
+                .to_locale_string("default", &js_sys::Object::new().into())
+                .into()
+        })
+    }
+
+    // 부모부터 최상위까지 (ex. "npm (100) ← bash (50) ← init (1)")
+    pub fn ancestry_label(&self) -> String {
+        self.ancestors
+            .iter()
+            .map(|parent| format!("{} ({})", parent.process_name, parent.pid))
+            .collect::<Vec<String>>()
+            .join(" ← ")
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ProcessDetailArgs<'a> {
+    pub pid: &'a str,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct CopyTextArgs {
+    pub text: String,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct RevealPathArgs {
+    pub path: String,
+}
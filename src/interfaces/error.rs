@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+
+// 백엔드의 CommandError와 같은 형태
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "kind", content = "message")]
+pub enum CommandError {
+    Timeout(u64),
+    Cancelled,
+    UnsupportedOS,
+    Failed(String),
+}
+
+impl ToString for CommandError {
+    fn to_string(&self) -> String {
+        match self {
+            CommandError::Timeout(ms) => format!("Timed out after {}s", ms / 1000),
+            CommandError::Cancelled => "Cancelled".to_string(),
+            CommandError::UnsupportedOS => "Unsupported OS".to_string(),
+            CommandError::Failed(message) => message.clone(),
+        }
+    }
+}
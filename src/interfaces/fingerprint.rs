@@ -0,0 +1,140 @@
+use js_sys::Date;
+use serde::{Deserialize, Serialize};
+
+use crate::interfaces::port::PortInfo;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct HttpFingerprint {
+    pub url: String,
+    pub status: u16,
+    pub server: Option<String>,
+    pub title: Option<String>,
+    pub framework: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct TlsFingerprint {
+    pub subject: Option<String>,
+    pub issuer: Option<String>,
+    pub not_after: Option<u64>,
+    pub self_signed: bool,
+}
+
+const DAY_MS: f64 = 24.0 * 60.0 * 60.0 * 1000.0;
+
+impl TlsFingerprint {
+    // ex. "TLS (self-signed, expires in 3 days, localhost)"
+    pub fn summary(&self) -> String {
+        let expiry = self.not_after.map(|not_after| {
+            let days = ((not_after as f64 - Date::now()) / DAY_MS).floor() as i64;
+            match days {
+                ..=-1 => "expired".to_string(),
+                0 => "expires today".to_string(),
+                1 => "expires in 1 day".to_string(),
+                _ => format!("expires in {} days", days),
+            }
+        });
+
+        let details: Vec<String> = [
+            self.self_signed.then(|| "self-signed".to_string()),
+            expiry,
+            self.subject.clone(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+
+        if details.is_empty() {
+            "TLS".to_string()
+        } else {
+            format!("TLS ({})", details.join(", "))
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(tag = "kind", content = "data", rename_all = "camelCase")]
+pub enum Fingerprint {
+    Http(HttpFingerprint),
+    #[serde(rename_all = "camelCase")]
+    Redis {
+        version: Option<String>,
+        auth_required: bool,
+    },
+    #[serde(rename_all = "camelCase")]
+    Postgres {
+        ssl: bool,
+    },
+    #[serde(rename_all = "camelCase")]
+    Mysql {
+        version: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    Ssh {
+        banner: String,
+    },
+    Tls(TlsFingerprint),
+}
+
+impl Fingerprint {
+    // 테이블에 보여줄 한 줄 요약 (ex. "HTTP 200 · Vite · My App")
+    pub fn summary(&self) -> String {
+        match self {
+            Fingerprint::Http(http) => [
+                Some(format!("HTTP {}", http.status)),
+                http.framework.clone(),
+                http.server.clone().filter(|_| http.framework.is_none()),
+                http.title.clone(),
+            ]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<String>>()
+            .join(" · "),
+            Fingerprint::Redis {
+                version,
+                auth_required,
+            } => match (version, auth_required) {
+                (Some(version), _) => format!("Redis {}", version),
+                (None, true) => "Redis (auth required)".to_string(),
+                (None, false) => "Redis".to_string(),
+            },
+            Fingerprint::Postgres { ssl: true } => "PostgreSQL (SSL)".to_string(),
+            Fingerprint::Postgres { ssl: false } => "PostgreSQL".to_string(),
+            // MariaDB는 버전 문자열에 이름이 들어 있다. (ex. 5.5.5-10.11.6-MariaDB)
+            Fingerprint::Mysql { version } if version.contains("MariaDB") => version.clone(),
+            Fingerprint::Mysql { version } => format!("MySQL {}", version),
+            Fingerprint::Ssh { banner } => banner.clone(),
+            Fingerprint::Tls(tls) => tls.summary(),
+        }
+    }
+
+    // 브라우저로 열 수 있는 주소
+    pub fn url(&self) -> Option<String> {
+        match self {
+            Fingerprint::Http(http) => Some(http.url.clone()),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct FingerprintResult {
+    pub socket_key: String,
+    pub fingerprint: Option<Fingerprint>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FingerprintPortsArgs {
+    pub ports: Vec<PortInfo>,
+    pub timeout_ms: Option<u64>,
+}
+
+// tauri-plugin-shell의 open 명령어 인자
+#[derive(Serialize, Deserialize)]
+pub struct OpenArgs {
+    pub path: String,
+}
@@ -0,0 +1,114 @@
+use std::collections::HashMap;
+
+use crate::interfaces::port::PortInfo;
+
+// 목록을 묶는 기준, NONE이면 소켓마다 한 줄인 기존 테이블
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum GroupBy {
+    NONE,
+    PROCESS,
+    PORT,
+    USER,
+}
+
+impl GroupBy {
+    pub const ALL: [GroupBy; 4] = [GroupBy::NONE, GroupBy::PROCESS, GroupBy::PORT, GroupBy::USER];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            GroupBy::NONE => "No grouping",
+            GroupBy::PROCESS => "Group by process",
+            GroupBy::PORT => "Group by port",
+            GroupBy::USER => "Group by user",
+        }
+    }
+}
+
+impl ToString for GroupBy {
+    fn to_string(&self) -> String {
+        match self {
+            GroupBy::NONE => "NONE".to_string(),
+            GroupBy::PROCESS => "PROCESS".to_string(),
+            GroupBy::PORT => "PORT".to_string(),
+            GroupBy::USER => "USER".to_string(),
+        }
+    }
+}
+
+impl std::str::FromStr for GroupBy {
+    type Err = ();
+    fn from_str(value: &str) -> Result<GroupBy, Self::Err> {
+        GroupBy::ALL
+            .into_iter()
+            .find(|group_by| group_by.to_string() == value)
+            .ok_or(())
+    }
+}
+
+// 트리의 부모 행 하나와 그 아래 행들
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RowGroup {
+    pub key: String,
+    pub rows: Vec<PortInfo>,
+}
+
+impl RowGroup {
+    // 부모 행에 보여줄 이름 (ex. "chrome", ":8080", "root")
+    pub fn label(&self, group_by: GroupBy) -> String {
+        let first = &self.rows[0];
+        match group_by {
+            GroupBy::PROCESS | GroupBy::NONE => first.get_process_name(),
+            GroupBy::PORT => format!(":{}", first.get_port()),
+            GroupBy::USER => first.get_user().unwrap_or("Unknown user".to_string()),
+        }
+    }
+
+    // 중복 없는 pid, 처음 나온 순서
+    pub fn pids(&self) -> Vec<String> {
+        let mut pids: Vec<String> = Vec::new();
+        for row in self.rows.iter() {
+            if !pids.contains(&row.get_pid()) {
+                pids.push(row.get_pid());
+            }
+        }
+        pids
+    }
+
+    // 중복 없는 포트 수 (IPv4, IPv6로 같은 포트를 열어도 하나)
+    pub fn port_count(&self) -> usize {
+        let mut ports: Vec<String> = self.rows.iter().map(|row| row.get_port()).collect();
+        ports.sort();
+        ports.dedup();
+        ports.len()
+    }
+}
+
+// 정렬된 순서를 유지하며, 그룹은 처음 나온 순서대로 놓는다.
+pub fn group_rows(rows: Vec<PortInfo>, group_by: GroupBy) -> Vec<RowGroup> {
+    let mut groups: Vec<RowGroup> = Vec::new();
+    let mut index: HashMap<String, usize> = HashMap::new();
+
+    for row in rows {
+        // 기준이 바뀌어도 키가 겹치지 않도록 기준을 앞에 붙인다. (pid 8080과 포트 8080)
+        let value = match group_by {
+            GroupBy::PROCESS => row.get_pid(),
+            GroupBy::PORT => row.get_port(),
+            GroupBy::USER => row.get_user().unwrap_or_default(),
+            GroupBy::NONE => row.socket_key(),
+        };
+        let key = format!("{}:{}", group_by.to_string(), value);
+
+        match index.get(&key) {
+            Some(position) => groups[*position].rows.push(row),
+            None => {
+                index.insert(key.clone(), groups.len());
+                groups.push(RowGroup {
+                    key,
+                    rows: vec![row],
+                });
+            }
+        }
+    }
+
+    groups
+}
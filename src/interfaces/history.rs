@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+
+use crate::interfaces::port::PortInfo;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct HistoryQuery {
+    pub port: Option<String>,
+    pub process_name: Option<String>,
+    pub from: Option<u64>,
+    pub to: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct QueryHistoryArgs {
+    pub query: HistoryQuery,
+}
+
+// 한 프로세스가 한 소켓을 점유한 기간
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PortLifetime {
+    pub port: PortInfo,
+    pub opened_at: Option<u64>,
+    pub closed_at: Option<u64>,
+}
@@ -1,6 +1,117 @@
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct KillArgs<'a> {
     pub pid: &'a str,
-}
\ No newline at end of file
+    pub signal: Option<KillSignal>,
+    pub timeout_ms: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum KillSignal {
+    TERM,
+    #[default]
+    KILL,
+    INT,
+    HUP,
+}
+
+impl KillSignal {
+    pub const ALL: [KillSignal; 4] = [
+        KillSignal::TERM,
+        KillSignal::KILL,
+        KillSignal::INT,
+        KillSignal::HUP,
+    ];
+
+    // 선택 목록에 보여줄 설명 (윈도우는 KILL만 강제 종료)
+    pub fn label(&self) -> &'static str {
+        match self {
+            KillSignal::TERM => "SIGTERM — ask to exit",
+            KillSignal::KILL => "SIGKILL — force kill",
+            KillSignal::INT => "SIGINT — interrupt (Ctrl+C)",
+            KillSignal::HUP => "SIGHUP — hang up / reload",
+        }
+    }
+}
+
+impl ToString for KillSignal {
+    fn to_string(&self) -> String {
+        match self {
+            KillSignal::TERM => "TERM",
+            KillSignal::KILL => "KILL",
+            KillSignal::INT => "INT",
+            KillSignal::HUP => "HUP",
+        }
+        .to_string()
+    }
+}
+
+impl std::str::FromStr for KillSignal {
+    type Err = ();
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        KillSignal::ALL
+            .into_iter()
+            .find(|signal| signal.to_string() == value)
+            .ok_or(())
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ChildProcess {
+    pub pid: String,
+    pub process_name: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ProcessContext {
+    pub pid: String,
+    pub process_name: Option<String>,
+    pub command_line: Option<String>,
+    pub children: Vec<ChildProcess>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ProcessContextArgs<'a> {
+    pub pid: &'a str,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum KillPermission {
+    Allowed,
+    #[serde(rename_all = "camelCase")]
+    Denied {
+        reason: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    Unknown {
+        reason: String,
+    },
+}
+
+impl KillPermission {
+    // 종료할 수 없다면 그 이유
+    pub fn denied_reason(&self) -> Option<String> {
+        match self {
+            KillPermission::Allowed => None,
+            KillPermission::Denied { reason } => Some(reason.clone()),
+            KillPermission::Unknown { .. } => None,
+        }
+    }
+
+    // 버튼에 보여줄 설명 (막혔거나 확실하지 않은 이유)
+    pub fn reason(&self) -> Option<String> {
+        match self {
+            KillPermission::Allowed => None,
+            KillPermission::Denied { reason } | KillPermission::Unknown { reason } => {
+                Some(reason.clone())
+            }
+        }
+    }
+}
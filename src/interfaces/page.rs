@@ -0,0 +1,14 @@
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum PAGE {
+    TABLE,
+    TIMELINE,
+}
+
+impl ToString for PAGE {
+    fn to_string(&self) -> String {
+        match self {
+            PAGE::TABLE => "TABLE".to_string(),
+            PAGE::TIMELINE => "TIMELINE".to_string(),
+        }
+    }
+}
@@ -1,26 +1,262 @@
+use std::collections::HashMap;
+
+use js_sys::Date;
 use serde::{Deserialize, Serialize};
 
+use crate::interfaces::{
+    cgroup::CgroupInfo,
+    container::{ContainerPatch, ContainerSummary},
+    coverage::ScanCoverage,
+    kill::KillPermission,
+};
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
-pub struct PortInfo(String, String, String);
+#[serde(rename_all = "camelCase")]
+pub struct PortInfo {
+    process_name: String,
+    port: String,
+    pid: String,
+    protocol: String,
+    address: String,
+    user: Option<String>,
+    #[serde(default)]
+    container: Option<ContainerSummary>,
+    #[serde(default)]
+    cgroup: Option<CgroupInfo>,
+    #[serde(default)]
+    service: Option<String>,
+    #[serde(default)]
+    reserved: bool,
+    #[serde(default)]
+    kill_permission: Option<KillPermission>,
+    #[serde(default)]
+    started_at: Option<u64>,
+    #[serde(default)]
+    connections: Option<usize>,
+}
 
 impl PortInfo {
     pub fn get_process_name(&self) -> String {
-        self.0.clone()
+        self.process_name.clone()
     }
 
     pub fn get_port(&self) -> String {
-        self.1.clone()
+        self.port.clone()
     }
 
     pub fn get_pid(&self) -> String {
-        self.2.clone()
+        self.pid.clone()
+    }
+
+    pub fn get_protocol(&self) -> String {
+        self.protocol.clone()
+    }
+
+    pub fn get_address(&self) -> String {
+        self.address.clone()
+    }
+
+    pub fn get_user(&self) -> Option<String> {
+        self.user.clone()
+    }
+
+    pub fn get_container(&self) -> Option<ContainerSummary> {
+        self.container.clone()
+    }
+
+    pub fn get_cgroup(&self) -> Option<CgroupInfo> {
+        self.cgroup.clone()
+    }
+
+    pub fn get_service(&self) -> Option<String> {
+        self.service.clone()
     }
 
-    pub fn get_port_as_usize(&self) -> usize {
-        self.1.parse::<usize>().unwrap()
+    // 조회 결과에는 대기 중인 소켓만 있으므로 TCP는 LISTEN, 연결이 없는 UDP는 UNCONN
+    pub fn get_state(&self) -> &'static str {
+        if self.protocol.eq_ignore_ascii_case("UDP") {
+            "UNCONN"
+        } else {
+            "LISTEN"
+        }
     }
 
-    pub fn get_pid_as_usize(&self) -> usize {
-        self.2.parse::<usize>().unwrap()
+    // 이 앱이 예약해 둔 포트
+    pub fn is_reserved(&self) -> bool {
+        self.reserved
     }
+
+    // 종료할 수 없는 이유, 권한 확인 전이거나 종료할 수 있다면 None
+    pub fn kill_denied_reason(&self) -> Option<String> {
+        self.kill_permission
+            .as_ref()
+            .and_then(|permission| permission.denied_reason())
+    }
+
+    // 종료 버튼의 설명
+    pub fn kill_note(&self) -> Option<String> {
+        self.kill_permission
+            .as_ref()
+            .and_then(|permission| permission.reason())
+    }
+
+    // 프로세스 시작 시각 (unix 초)
+    pub fn get_started_at(&self) -> Option<u64> {
+        self.started_at
+    }
+
+    // 실행된 시간 (ex. "45s", "12m", "3h", "2d")
+    pub fn uptime_label(&self) -> Option<String> {
+        self.started_at.map(|started_at| {
+            let seconds = (Date::now() / 1000.0) as u64;
+            match seconds.saturating_sub(started_at) {
+                elapsed @ ..=59 => format!("{}s", elapsed),
+                elapsed @ ..=3_599 => format!("{}m", elapsed / 60),
+                elapsed @ ..=86_399 => format!("{}h", elapsed / 3_600),
+                elapsed => format!("{}d", elapsed / 86_400),
+            }
+        })
+    }
+
+    // 이 포트로 맺어진 TCP 연결 수
+    pub fn get_connections(&self) -> Option<usize> {
+        self.connections
+    }
+
+    // 백엔드의 PortInfo::key와 같은 형태
+    pub fn key(&self) -> String {
+        format!(
+            "{}_{}_{}_{}",
+            self.protocol, self.address, self.port, self.pid
+        )
+    }
+
+    // 같은 소켓을 가진 프로세스들을 묶는 키 (protocol, address, port)
+    pub fn socket_key(&self) -> String {
+        format!("{}_{}_{}", self.protocol, self.address, self.port)
+    }
+
+    pub fn apply_patch(&mut self, patch: &ProcessPatch) {
+        if let Some(process_name) = &patch.process_name {
+            self.process_name = process_name.clone();
+        }
+        if let Some(user) = &patch.user {
+            self.user = Some(user.clone());
+        }
+        if let Some(cgroup) = &patch.cgroup {
+            self.cgroup = Some(cgroup.clone());
+        }
+        if let Some(kill_permission) = &patch.kill_permission {
+            self.kill_permission = Some(kill_permission.clone());
+        }
+        if let Some(started_at) = patch.started_at {
+            self.started_at = Some(started_at);
+        }
+    }
+
+    // 백엔드의 apply_connections와 같다. 연결이 없는 TCP 포트는 0, UDP는 None
+    pub fn apply_connections(&mut self, counts: &[ConnectionCount]) {
+        if !self.protocol.eq_ignore_ascii_case("TCP") {
+            return;
+        }
+        if let Ok(port) = self.port.parse::<u16>() {
+            self.connections = Some(
+                counts
+                    .iter()
+                    .filter(|connection| {
+                        connection.port == port
+                            && is_connection_of(&self.address, &connection.address)
+                    })
+                    .map(|connection| connection.count)
+                    .sum(),
+            );
+        }
+    }
+
+    pub fn apply_container_patch(&mut self, patch: &ContainerPatch) {
+        if self.key() == patch.key {
+            self.container = Some(patch.container.clone());
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ProcessPatch {
+    pub pid: String,
+    pub process_name: Option<String>,
+    pub user: Option<String>,
+    #[serde(default)]
+    pub cgroup: Option<CgroupInfo>,
+    #[serde(default)]
+    pub kill_permission: Option<KillPermission>,
+    #[serde(default)]
+    pub started_at: Option<u64>,
+}
+
+// 백엔드의 is_connection_of와 같다. 모든 주소에서 기다리는 행은 그 포트의 모든 연결을 받는다.
+fn is_connection_of(row_address: &str, address: &str) -> bool {
+    matches!(row_address, "" | "*" | "0.0.0.0" | "::") || row_address.eq_ignore_ascii_case(address)
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ConnectionCount {
+    pub address: String,
+    pub port: u16,
+    pub count: usize,
+}
+
+// 소켓 하나와 그 소켓을 가진 모든 프로세스
+// SO_REUSEPORT, pre-fork 서버처럼 여러 pid가 같은 소켓을 가질 수 있다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SocketGroup {
+    pub owners: Vec<PortInfo>,
+}
+
+impl SocketGroup {
+    // 행에 대표로 보여줄 첫 번째 프로세스
+    pub fn primary(&self) -> &PortInfo {
+        &self.owners[0]
+    }
+
+    pub fn socket_key(&self) -> String {
+        self.primary().socket_key()
+    }
+}
+
+// 정렬된 순서를 유지하며 같은 소켓의 행을 하나로 묶는다.
+pub fn group_by_socket(rows: Vec<PortInfo>) -> Vec<SocketGroup> {
+    let mut groups: Vec<SocketGroup> = Vec::new();
+    let mut index: HashMap<String, usize> = HashMap::new();
+
+    for row in rows {
+        match index.get(&row.socket_key()) {
+            Some(position) => groups[*position].owners.push(row),
+            None => {
+                index.insert(row.socket_key(), groups.len());
+                groups.push(SocketGroup { owners: vec![row] });
+            }
+        }
+    }
+
+    groups
+}
+
+// stream_open_ports 채널로 전달되는 이벤트
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "event", content = "data", rename_all = "camelCase")]
+pub enum ScanEvent {
+    Rows(Vec<PortInfo>),
+    Patch(Vec<ProcessPatch>),
+    Containers(Vec<ContainerPatch>),
+    Connections(Vec<ConnectionCount>),
+    Coverage(ScanCoverage),
+    Finished,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetPortsArgs {
+    pub timeout_ms: Option<u64>,
 }
@@ -0,0 +1,55 @@
+use serde::{Deserialize, Serialize};
+
+use crate::interfaces::port::PortInfo;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum ProbeStatus {
+    #[serde(rename_all = "camelCase")]
+    Healthy {
+        latency_ms: u64,
+    },
+    #[serde(rename_all = "camelCase")]
+    Failed {
+        message: String,
+    },
+    Skipped,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ProbeResult {
+    pub socket_key: String,
+    pub target: Option<String>,
+    pub status: ProbeStatus,
+    pub checked_at: u64,
+}
+
+impl ProbeResult {
+    // 상태 점의 색
+    pub fn color(&self) -> &'static str {
+        match self.status {
+            ProbeStatus::Healthy { .. } => "bg-green-500",
+            ProbeStatus::Failed { .. } => "bg-red-500",
+            ProbeStatus::Skipped => "bg-gray-300",
+        }
+    }
+
+    pub fn describe(&self) -> String {
+        let target = self.target.clone().unwrap_or_default();
+        match &self.status {
+            ProbeStatus::Healthy { latency_ms } => {
+                format!("{} accepted in {}ms", target, latency_ms)
+            }
+            ProbeStatus::Failed { message } => format!("{} failed: {}", target, message),
+            ProbeStatus::Skipped => "Not probed (not TCP)".to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProbePortsArgs {
+    pub ports: Vec<PortInfo>,
+    pub timeout_ms: Option<u64>,
+}
@@ -0,0 +1,684 @@
+use js_sys::{Array, Reflect, RegExp};
+use wasm_bindgen::{JsCast, JsValue};
+
+use crate::interfaces::filter::FILTER;
+use crate::interfaces::port::PortInfo;
+
+/**
+ * 검색어로 고를 수 있는 항목
+ * FILTER(PROCESS/PORT/PID)는 접두어 없이 입력한 단어를 어느 항목에서 찾을지 정하는 약칭이다.
+ */
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum QueryField {
+    Process,
+    Port,
+    Pid,
+    User,
+    State,
+    Address,
+    Protocol,
+    Service,
+}
+
+impl QueryField {
+    pub const NAMES: &'static str = "proc, port, pid, user, state, addr, proto, service";
+
+    fn parse(name: &str) -> Option<QueryField> {
+        match name.to_lowercase().as_str() {
+            "proc" | "process" => Some(QueryField::Process),
+            "port" => Some(QueryField::Port),
+            "pid" => Some(QueryField::Pid),
+            "user" => Some(QueryField::User),
+            "state" => Some(QueryField::State),
+            "addr" | "address" => Some(QueryField::Address),
+            "proto" | "protocol" => Some(QueryField::Protocol),
+            "service" => Some(QueryField::Service),
+            _ => None,
+        }
+    }
+
+    // 범위(3000-3999)를 쓸 수 있는 숫자 항목
+    fn is_numeric(&self) -> bool {
+        matches!(self, QueryField::Port | QueryField::Pid)
+    }
+
+    fn values(&self, port: &PortInfo) -> Vec<String> {
+        match self {
+            QueryField::Process => vec![port.get_process_name()],
+            QueryField::Port => vec![port.get_port()],
+            QueryField::Pid => vec![port.get_pid()],
+            QueryField::User => port.get_user().into_iter().collect(),
+            QueryField::State => vec![port.get_state().to_string()],
+            QueryField::Address => vec![port.get_address()],
+            QueryField::Protocol => vec![port.get_protocol()],
+            QueryField::Service => port.get_service().into_iter().collect(),
+        }
+    }
+}
+
+impl FILTER {
+    // 드롭다운에서 고른 항목 = 접두어 없는 단어를 찾을 항목
+    pub fn field(&self) -> QueryField {
+        match self {
+            FILTER::PROCESS => QueryField::Process,
+            FILTER::PORT => QueryField::Port,
+            FILTER::PID => QueryField::Pid,
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum Matcher {
+    // 대소문자를 구분하지 않는 부분 일치
+    Contains(String),
+    // 양 끝을 포함하는 숫자 범위, port:80 은 80-80
+    Range(u64, u64),
+    Regex(RegExp),
+}
+
+impl Matcher {
+    fn matches(&self, value: &str) -> bool {
+        match self {
+            Matcher::Contains(text) => value.to_lowercase().contains(text),
+            Matcher::Range(start, end) => value
+                .parse::<u64>()
+                .map(|value| *start <= value && value <= *end)
+                .unwrap_or(false),
+            Matcher::Regex(regex) => regex.test(value),
+        }
+    }
+}
+
+/**
+ * 항목 하나에 대한 조건 (ex. port:3000-3999)
+ * field가 None이면 드롭다운에서 고른 항목(FILTER)으로 찾는다.
+ */
+#[derive(Clone, PartialEq, Debug)]
+pub struct QueryTerm {
+    pub field: Option<QueryField>,
+    pub matcher: Matcher,
+}
+
+impl QueryTerm {
+    fn matches(&self, port: &PortInfo, shorthand: &FILTER) -> bool {
+        match self.field {
+            Some(field) => field
+                .values(port)
+                .iter()
+                .any(|value| self.matcher.matches(value)),
+            // 기존 PORT 검색은 서비스 이름(ex. "vite", "redis")도 함께 찾는다.
+            None => {
+                let field = shorthand.field();
+                let mut values = field.values(port);
+                if field == QueryField::Port {
+                    values.extend(port.get_service());
+                }
+                values.iter().any(|value| self.matcher.matches(value))
+            }
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum Query {
+    // 빈 검색어, 모든 행과 일치
+    All,
+    Term(QueryTerm),
+    Not(Box<Query>),
+    And(Vec<Query>),
+    Or(Vec<Query>),
+}
+
+impl Query {
+    pub fn matches(&self, port: &PortInfo, shorthand: &FILTER) -> bool {
+        match self {
+            Query::All => true,
+            Query::Term(term) => term.matches(port, shorthand),
+            Query::Not(query) => !query.matches(port, shorthand),
+            Query::And(queries) => queries.iter().all(|query| query.matches(port, shorthand)),
+            Query::Or(queries) => queries.iter().any(|query| query.matches(port, shorthand)),
+        }
+    }
+}
+
+/**
+ * 검색어의 잘못된 부분, start..end는 입력 문자열의 바이트 위치
+ */
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct QueryError {
+    pub message: String,
+    pub start: usize,
+    pub end: usize,
+}
+
+impl QueryError {
+    fn new(message: impl Into<String>, start: usize, end: usize) -> QueryError {
+        QueryError {
+            message: message.into(),
+            start,
+            end,
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+enum Token {
+    LParen,
+    RParen,
+    Not,
+    And,
+    Or,
+    // 접두어(field:)와 값, field_end는 ':' 위치
+    Word {
+        field: Option<String>,
+        field_end: usize,
+        value: String,
+        value_start: usize,
+    },
+}
+
+/**
+ * 검색어를 토큰으로 나눈다.
+ * 따옴표("...")와 정규식(/.../)은 공백과 괄호를 포함할 수 있다.
+ */
+fn tokenize(input: &str) -> Result<Vec<(Token, usize, usize)>, QueryError> {
+    let mut tokens = Vec::new();
+    let mut chars = input.char_indices().peekable();
+
+    while let Some(&(start, c)) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+            continue;
+        }
+
+        let single = match c {
+            '(' => Some(Token::LParen),
+            ')' => Some(Token::RParen),
+            '!' => Some(Token::Not),
+            _ => None,
+        };
+        if let Some(token) = single {
+            chars.next();
+            tokens.push((token, start, start + 1));
+            continue;
+        }
+
+        if input[start..].starts_with("&&") || input[start..].starts_with("||") {
+            let token = if c == '&' { Token::And } else { Token::Or };
+            chars.next();
+            chars.next();
+            tokens.push((token, start, start + 2));
+            continue;
+        }
+
+        // 접두어: 영문자 뒤에 바로 ':'
+        let mut field = None;
+        let mut field_end = start;
+        let name_len = input[start..]
+            .find(|c: char| !c.is_ascii_alphabetic())
+            .unwrap_or(input.len() - start);
+        if name_len > 0 && input[start + name_len..].starts_with(':') {
+            field = Some(input[start..start + name_len].to_string());
+            field_end = start + name_len;
+            for _ in 0..=name_len {
+                chars.next();
+            }
+        }
+
+        let value_start = chars.peek().map(|(index, _)| *index).unwrap_or(input.len());
+        let mut value = String::new();
+        match chars.peek().map(|(_, c)| *c) {
+            Some(quote @ ('"' | '/')) => {
+                chars.next();
+                let mut closed = false;
+                while let Some((_, c)) = chars.next() {
+                    if c == '\\' && quote == '/' {
+                        // 정규식의 이스케이프는 그대로 둔다. (ex. \d, \/)
+                        value.push(c);
+                        if let Some((_, next)) = chars.next() {
+                            value.push(next);
+                        }
+                    } else if c == quote {
+                        closed = true;
+                        break;
+                    } else {
+                        value.push(c);
+                    }
+                }
+                if !closed {
+                    let name = if quote == '"' { "quote" } else { "regex" };
+                    return Err(QueryError::new(
+                        format!("Unclosed {}", name),
+                        value_start,
+                        input.len(),
+                    ));
+                }
+                if quote == '/' {
+                    // 플래그 (ex. /node/i)
+                    let mut flags = String::new();
+                    while let Some(&(_, c)) = chars.peek() {
+                        if !c.is_ascii_alphabetic() {
+                            break;
+                        }
+                        flags.push(c);
+                        chars.next();
+                    }
+                    value = format!("/{}/{}", value, flags);
+                } else {
+                    value = format!("\"{}\"", value);
+                }
+            }
+            _ => {
+                while let Some(&(_, c)) = chars.peek() {
+                    if c.is_whitespace() || c == '(' || c == ')' {
+                        break;
+                    }
+                    value.push(c);
+                    chars.next();
+                }
+            }
+        }
+
+        let end = chars.peek().map(|(index, _)| *index).unwrap_or(input.len());
+        let token = match (&field, value.as_str()) {
+            (None, "AND") => Token::And,
+            (None, "OR") => Token::Or,
+            (None, "NOT") => Token::Not,
+            _ => Token::Word {
+                field,
+                field_end,
+                value,
+                value_start,
+            },
+        };
+        tokens.push((token, start, end));
+    }
+
+    Ok(tokens)
+}
+
+/**
+ * 잘못된 정규식은 예외를 던지므로 생성자를 Reflect로 호출해 오류로 받는다.
+ * 'g', 'y' 플래그는 test()가 위치를 기억하게 만들어 결과가 달라지므로 뺀다.
+ */
+fn compile_regex(pattern: &str, flags: &str) -> Result<RegExp, String> {
+    let flags: String = flags.chars().filter(|c| *c != 'g' && *c != 'y').collect();
+    let args = Array::of2(&JsValue::from_str(pattern), &JsValue::from_str(&flags));
+
+    Reflect::construct(&RegExp::new("", "").constructor(), &args)
+        .map(|regex| regex.unchecked_into::<RegExp>())
+        .map_err(|e| {
+            e.dyn_ref::<js_sys::Error>()
+                .map(|e| String::from(e.message()))
+                .unwrap_or("Invalid regex".to_string())
+        })
+}
+
+fn parse_range(value: &str) -> Option<(u64, u64)> {
+    match value.split_once('-') {
+        Some((start, end)) => Some((start.parse().ok()?, end.parse().ok()?)),
+        None => value.parse().ok().map(|value| (value, value)),
+    }
+}
+
+fn parse_term(
+    field: &Option<String>,
+    field_start: usize,
+    field_end: usize,
+    value: &str,
+    value_start: usize,
+) -> Result<QueryTerm, QueryError> {
+    let value_end = value_start + value.len();
+
+    let field = match field {
+        Some(name) => Some(QueryField::parse(name).ok_or_else(|| {
+            QueryError::new(
+                format!("Unknown field '{}' (use {})", name, QueryField::NAMES),
+                field_start,
+                field_end,
+            )
+        })?),
+        None => None,
+    };
+
+    if value.is_empty() {
+        return Err(QueryError::new(
+            "Missing value after ':'",
+            field_start,
+            field_end + 1,
+        ));
+    }
+
+    if let Some(regex) = value.strip_prefix('/') {
+        let (pattern, flags) = regex.rsplit_once('/').unwrap_or((regex, ""));
+        let regex = compile_regex(pattern, flags)
+            .map_err(|message| QueryError::new(message, value_start, value_end))?;
+        return Ok(QueryTerm {
+            field,
+            matcher: Matcher::Regex(regex),
+        });
+    }
+
+    if let Some(text) = value.strip_prefix('"').and_then(|value| value.strip_suffix('"')) {
+        return Ok(QueryTerm {
+            field,
+            matcher: Matcher::Contains(text.to_lowercase()),
+        });
+    }
+
+    // port:80 은 8080과 일치하지 않도록 정확히 비교한다. 접두어 없는 단어는 기존처럼 부분 일치
+    match field {
+        Some(field) if field.is_numeric() => {
+            let (start, end) = parse_range(value).ok_or_else(|| {
+                QueryError::new(
+                    "Expected a number or a range (ex. 3000-3999)",
+                    value_start,
+                    value_end,
+                )
+            })?;
+            if start > end {
+                return Err(QueryError::new(
+                    format!("Range start {} is greater than end {}", start, end),
+                    value_start,
+                    value_end,
+                ));
+            }
+            Ok(QueryTerm {
+                field: Some(field),
+                matcher: Matcher::Range(start, end),
+            })
+        }
+        _ => Ok(QueryTerm {
+            field,
+            matcher: Matcher::Contains(value.to_lowercase()),
+        }),
+    }
+}
+
+/**
+ * 재귀 하강 파서
+ *
+ * or    := and (("OR" | "||") and)*
+ * and   := unary (("AND" | "&&")? unary)*     공백으로 나열해도 AND
+ * unary := ("!" | "NOT") unary | "(" or ")" | term
+ */
+struct Parser<'a> {
+    input: &'a str,
+    tokens: Vec<(Token, usize, usize)>,
+    position: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<&(Token, usize, usize)> {
+        self.tokens.get(self.position)
+    }
+
+    fn end_error(&self, message: &str) -> QueryError {
+        QueryError::new(message, self.input.len(), self.input.len())
+    }
+
+    fn parse_or(&mut self) -> Result<Query, QueryError> {
+        let mut queries = vec![self.parse_and()?];
+        while let Some((Token::Or, _, _)) = self.peek() {
+            self.position += 1;
+            queries.push(self.parse_and()?);
+        }
+
+        Ok(if queries.len() == 1 {
+            queries.remove(0)
+        } else {
+            Query::Or(queries)
+        })
+    }
+
+    fn parse_and(&mut self) -> Result<Query, QueryError> {
+        let mut queries = vec![self.parse_unary()?];
+        loop {
+            match self.peek() {
+                Some((Token::And, _, _)) => {
+                    self.position += 1;
+                    queries.push(self.parse_unary()?);
+                }
+                Some((Token::Or | Token::RParen, _, _)) | None => break,
+                Some(_) => queries.push(self.parse_unary()?),
+            }
+        }
+
+        Ok(if queries.len() == 1 {
+            queries.remove(0)
+        } else {
+            Query::And(queries)
+        })
+    }
+
+    fn parse_unary(&mut self) -> Result<Query, QueryError> {
+        let Some((token, start, end)) = self.peek().cloned() else {
+            return Err(self.end_error("Expected a search term"));
+        };
+        self.position += 1;
+
+        match token {
+            Token::Not => Ok(Query::Not(Box::new(self.parse_unary()?))),
+            Token::LParen => {
+                let query = self.parse_or()?;
+                match self.peek() {
+                    Some((Token::RParen, _, _)) => {
+                        self.position += 1;
+                        Ok(query)
+                    }
+                    _ => Err(QueryError::new("Unclosed '('", start, end)),
+                }
+            }
+            Token::RParen => Err(QueryError::new("Unexpected ')'", start, end)),
+            Token::And | Token::Or => Err(QueryError::new(
+                "Expected a search term before the operator",
+                start,
+                end,
+            )),
+            Token::Word {
+                field,
+                field_end,
+                value,
+                value_start,
+            } => parse_term(&field, start, field_end, &value, value_start).map(Query::Term),
+        }
+    }
+}
+
+/**
+ * 검색어를 해석한다.
+ *
+ * ex) port:3000-3999 proc:node !user:root state:listen addr:0.0.0.0
+ *     proc:/^python\d/ OR (port:5432 || service:redis)
+ */
+pub fn parse_query(input: &str) -> Result<Query, QueryError> {
+    let tokens = tokenize(input)?;
+    if tokens.is_empty() {
+        return Ok(Query::All);
+    }
+
+    let mut parser = Parser {
+        input,
+        tokens,
+        position: 0,
+    };
+    let query = parser.parse_or()?;
+
+    match parser.peek() {
+        None => Ok(query),
+        Some((_, start, end)) => Err(QueryError::new("Unexpected ')'", *start, *end)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn word(text: &str) -> Query {
+        Query::Term(QueryTerm {
+            field: None,
+            matcher: Matcher::Contains(text.to_string()),
+        })
+    }
+
+    fn port(start: u64, end: u64) -> Query {
+        Query::Term(QueryTerm {
+            field: Some(QueryField::Port),
+            matcher: Matcher::Range(start, end),
+        })
+    }
+
+    fn error(input: &str) -> (String, usize, usize) {
+        let error = parse_query(input).unwrap_err();
+        (error.message, error.start, error.end)
+    }
+
+    #[test]
+    fn test_tokenize_quoted() {
+        let tokens = tokenize(r#"proc:"my app" (x)"#).unwrap();
+
+        assert_eq!(
+            tokens[0],
+            (
+                Token::Word {
+                    field: Some("proc".to_string()),
+                    field_end: 4,
+                    value: "\"my app\"".to_string(),
+                    value_start: 5,
+                },
+                0,
+                13
+            )
+        );
+        assert_eq!(tokens[1], (Token::LParen, 14, 15));
+        assert_eq!(tokens[3], (Token::RParen, 16, 17));
+    }
+
+    #[test]
+    fn test_tokenize_operators() {
+        let tokens: Vec<Token> = tokenize("a && b || !c AND NOT d OR e")
+            .unwrap()
+            .into_iter()
+            .map(|(token, _, _)| token)
+            .filter(|token| !matches!(token, Token::Word { .. }))
+            .collect();
+
+        assert_eq!(
+            tokens,
+            vec![
+                Token::And,
+                Token::Or,
+                Token::Not,
+                Token::And,
+                Token::Not,
+                Token::Or
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_empty() {
+        assert_eq!(parse_query("   "), Ok(Query::All));
+    }
+
+    #[test]
+    fn test_parse_precedence() {
+        // 공백과 AND는 OR보다 먼저 묶인다.
+        assert_eq!(
+            parse_query("a b OR c"),
+            Ok(Query::Or(vec![
+                Query::And(vec![word("a"), word("b")]),
+                word("c")
+            ]))
+        );
+        assert_eq!(
+            parse_query("a || b && c"),
+            Ok(Query::Or(vec![
+                word("a"),
+                Query::And(vec![word("b"), word("c")])
+            ]))
+        );
+        assert_eq!(
+            parse_query("(a OR b) c"),
+            Ok(Query::And(vec![
+                Query::Or(vec![word("a"), word("b")]),
+                word("c")
+            ]))
+        );
+    }
+
+    #[test]
+    fn test_parse_negation() {
+        // !는 바로 뒤의 항목에만 붙는다.
+        assert_eq!(
+            parse_query("!a b"),
+            Ok(Query::And(vec![Query::Not(Box::new(word("a"))), word("b")]))
+        );
+        assert_eq!(
+            parse_query("NOT port:22"),
+            Ok(Query::Not(Box::new(port(22, 22))))
+        );
+        assert_eq!(
+            parse_query("!(a OR b)"),
+            Ok(Query::Not(Box::new(Query::Or(vec![word("a"), word("b")]))))
+        );
+    }
+
+    #[test]
+    fn test_parse_terms() {
+        assert_eq!(parse_query("port:3000-3999"), Ok(port(3000, 3999)));
+        // 따옴표 안의 공백과 연산자는 값이다.
+        assert_eq!(parse_query(r#""A OR B""#), Ok(word("a or b")));
+        assert_eq!(
+            parse_query("user:Root"),
+            Ok(Query::Term(QueryTerm {
+                field: Some(QueryField::User),
+                matcher: Matcher::Contains("root".to_string()),
+            }))
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            error(r#"proc:"node"#),
+            ("Unclosed quote".to_string(), 5, 10)
+        );
+        assert_eq!(error("proc:/node"), ("Unclosed regex".to_string(), 5, 10));
+        assert_eq!(
+            error("a foo:1"),
+            (
+                format!("Unknown field 'foo' (use {})", QueryField::NAMES),
+                2,
+                5
+            )
+        );
+        assert_eq!(
+            error("port:"),
+            ("Missing value after ':'".to_string(), 0, 5)
+        );
+        assert_eq!(
+            error("port:http"),
+            (
+                "Expected a number or a range (ex. 3000-3999)".to_string(),
+                5,
+                9
+            )
+        );
+        assert_eq!(
+            error("port:9-1"),
+            ("Range start 9 is greater than end 1".to_string(), 5, 8)
+        );
+        assert_eq!(error("(a b"), ("Unclosed '('".to_string(), 0, 1));
+        assert_eq!(error("a )"), ("Unexpected ')'".to_string(), 2, 3));
+        assert_eq!(
+            error("OR a"),
+            (
+                "Expected a search term before the operator".to_string(),
+                0,
+                2
+            )
+        );
+        assert_eq!(error("a AND"), ("Expected a search term".to_string(), 5, 5));
+    }
+}
@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Reservation {
+    pub port: u16,
+    pub address: String,
+    pub reserved_at: u64,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ReservePortArgs {
+    pub port: u16,
+    pub addresses: Option<Vec<String>>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ReleasePortArgs {
+    pub port: u16,
+}
@@ -0,0 +1,30 @@
+use serde::{Deserialize, Serialize};
+
+use crate::interfaces::kill::KillSignal;
+
+// 백엔드의 Settings와 같은 형태
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Settings {
+    pub history_enabled: bool,
+    pub history_retention_days: u32,
+    // 종료 확인 창을 건너뛸 프로세스 이름
+    pub skip_kill_confirm: Vec<String>,
+    pub kill_signal: KillSignal,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Settings {
+            history_enabled: false,
+            history_retention_days: 7,
+            skip_kill_confirm: Vec::new(),
+            kill_signal: KillSignal::default(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct SettingsArgs {
+    pub settings: Settings,
+}
@@ -1,6 +1,58 @@
-#[derive(Clone, Debug)]
+#[derive(Clone, Copy, PartialEq, Debug)]
 pub enum SORT {
-    NONE,
     ASC,
     DESC
-}
\ No newline at end of file
+}
+
+impl SORT {
+    pub fn reverse(self) -> SORT {
+        match self {
+            SORT::ASC => SORT::DESC,
+            SORT::DESC => SORT::ASC,
+        }
+    }
+}
+
+// 테이블에서 정렬할 수 있는 열
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SortField {
+    PROCESS,
+    PORT,
+    PID,
+    PROTOCOL,
+    ADDRESS,
+    STATE,
+    USER,
+    STARTED,
+    CONNECTIONS,
+    CPU,
+    MEMORY,
+}
+
+impl SortField {
+    // 처음 클릭했을 때의 방향, 사용량은 큰 값부터 보여준다.
+    pub fn first_order(&self) -> SORT {
+        match self {
+            SortField::CPU | SortField::MEMORY => SORT::DESC,
+            _ => SORT::ASC,
+        }
+    }
+}
+
+impl ToString for SortField {
+    fn to_string(&self) -> String {
+        match self {
+            SortField::PROCESS => "PROCESS".to_string(),
+            SortField::PORT => "PORT".to_string(),
+            SortField::PID => "PID".to_string(),
+            SortField::PROTOCOL => "PROTOCOL".to_string(),
+            SortField::ADDRESS => "ADDRESS".to_string(),
+            SortField::STATE => "STATE".to_string(),
+            SortField::USER => "USER".to_string(),
+            SortField::STARTED => "STARTED".to_string(),
+            SortField::CONNECTIONS => "CONNS".to_string(),
+            SortField::CPU => "CPU".to_string(),
+            SortField::MEMORY => "MEMORY".to_string(),
+        }
+    }
+}
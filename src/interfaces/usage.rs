@@ -0,0 +1,51 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+// 스파크라인에 남길 측정 수 (감시 간격마다 하나)
+pub const HISTORY_LEN: usize = 30;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ProcessUsage {
+    pub pid: String,
+    pub cpu_percent: Option<f64>,
+    pub memory_bytes: Option<u64>,
+    pub sampled_at: u64,
+}
+
+impl ProcessUsage {
+    // ex) "3.2%"
+    pub fn cpu_label(&self) -> Option<String> {
+        self.cpu_percent.map(|percent| format!("{:.1}%", percent))
+    }
+
+    pub fn memory_label(&self) -> Option<String> {
+        self.memory_bytes.map(format_bytes)
+    }
+}
+
+// ex) "512 KB", "48.3 MB", "1.2 GB"
+pub fn format_bytes(bytes: u64) -> String {
+    match bytes {
+        ..=1_048_575 => format!("{} KB", bytes / 1024),
+        1_048_576..=1_073_741_823 => format!("{:.1} MB", bytes as f64 / 1_048_576.0),
+        _ => format!("{:.1} GB", bytes as f64 / 1_073_741_824.0),
+    }
+}
+
+/**
+ * pid마다 최근 측정값, 오래된 값부터 HISTORY_LEN개까지
+ * 이번 측정에 없는 pid(종료된 프로세스)는 지운다.
+ */
+pub fn push_samples(history: &mut HashMap<String, Vec<ProcessUsage>>, samples: Vec<ProcessUsage>) {
+    history.retain(|pid, _| samples.iter().any(|sample| sample.pid == *pid));
+
+    for sample in samples {
+        let samples = history.entry(sample.pid.clone()).or_default();
+        samples.push(sample);
+        if samples.len() > HISTORY_LEN {
+            samples.remove(0);
+        }
+    }
+}
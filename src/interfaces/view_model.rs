@@ -0,0 +1,279 @@
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+use std::net::IpAddr;
+
+use leptos::*;
+
+use crate::interfaces::filter::FILTER;
+use crate::interfaces::group::{group_rows, GroupBy, RowGroup};
+use crate::interfaces::port::PortInfo;
+use crate::interfaces::query::{parse_query, Query, QueryError};
+use crate::interfaces::sort::{SortField, SORT};
+use crate::interfaces::usage::ProcessUsage;
+
+/**
+ * 정렬 기준 한 개 (항목, 방향)
+ */
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct SortKey {
+    pub field: SortField,
+    pub order: SORT,
+}
+
+// 숫자가 아닌 값(ex. "*")은 뒤로 보내고 문자열로 비교한다. (백엔드의 sort_rows와 같은 규칙)
+fn numeric(value: &str) -> (u64, &str) {
+    (value.parse::<u64>().unwrap_or(u64::MAX), value)
+}
+
+// 주소는 IP로 비교하고, IP가 아닌 값(ex. "*")은 뒤로 보낸다.
+fn address(value: &str) -> (bool, Option<IpAddr>, &str) {
+    let ip = value
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .parse::<IpAddr>()
+        .ok();
+    (ip.is_none(), ip, value)
+}
+
+// 아직 채워지지 않은 값은 방향과 관계없이 항상 뒤로 보낸다.
+fn present_first_by<T>(
+    a: Option<T>,
+    b: Option<T>,
+    order: SORT,
+    cmp: impl Fn(&T, &T) -> Ordering,
+) -> Ordering {
+    match (a, b) {
+        (Some(a), Some(b)) => match order {
+            SORT::ASC => cmp(&a, &b),
+            SORT::DESC => cmp(&b, &a),
+        },
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    }
+}
+
+fn present_first<T: Ord>(a: Option<T>, b: Option<T>, order: SORT) -> Ordering {
+    present_first_by(a, b, order, T::cmp)
+}
+
+// pid의 가장 최근 측정값
+fn latest<'a>(
+    usage: &'a HashMap<String, Vec<ProcessUsage>>,
+    port: &PortInfo,
+) -> Option<&'a ProcessUsage> {
+    usage
+        .get(&port.get_pid())
+        .and_then(|samples| samples.last())
+}
+
+/**
+ * 기준 하나로 두 행을 비교한다. 모든 값에 대해 순서가 정해지므로 잘못된 값이 있어도 멈추지 않는다.
+ */
+fn compare(
+    key: &SortKey,
+    a: &PortInfo,
+    b: &PortInfo,
+    usage: &HashMap<String, Vec<ProcessUsage>>,
+) -> Ordering {
+    let ordering = match key.field {
+        SortField::PROCESS => a
+            .get_process_name()
+            .to_lowercase()
+            .cmp(&b.get_process_name().to_lowercase()),
+        SortField::PORT => numeric(&a.get_port()).cmp(&numeric(&b.get_port())),
+        SortField::PID => numeric(&a.get_pid()).cmp(&numeric(&b.get_pid())),
+        SortField::PROTOCOL => a.get_protocol().cmp(&b.get_protocol()),
+        SortField::ADDRESS => address(&a.get_address()).cmp(&address(&b.get_address())),
+        SortField::STATE => a.get_state().cmp(b.get_state()),
+        SortField::USER => return present_first(a.get_user(), b.get_user(), key.order),
+        SortField::STARTED => {
+            return present_first(a.get_started_at(), b.get_started_at(), key.order)
+        }
+        SortField::CONNECTIONS => {
+            return present_first(a.get_connections(), b.get_connections(), key.order)
+        }
+        // f64는 total_cmp로 비교해 NaN이 있어도 순서가 정해진다.
+        SortField::CPU => {
+            return present_first_by(
+                latest(usage, a).and_then(|usage| usage.cpu_percent),
+                latest(usage, b).and_then(|usage| usage.cpu_percent),
+                key.order,
+                f64::total_cmp,
+            )
+        }
+        SortField::MEMORY => {
+            return present_first(
+                latest(usage, a).and_then(|usage| usage.memory_bytes),
+                latest(usage, b).and_then(|usage| usage.memory_bytes),
+                key.order,
+            )
+        }
+    };
+
+    match key.order {
+        SORT::ASC => ordering,
+        SORT::DESC => ordering.reverse(),
+    }
+}
+
+// 안정 정렬이므로 기준이 같은 행은 조회된 순서를 유지한다.
+fn sort_ports(
+    ports: &mut [PortInfo],
+    keys: &[SortKey],
+    usage: &HashMap<String, Vec<ProcessUsage>>,
+) {
+    if keys.is_empty() {
+        return;
+    }
+
+    ports.sort_by(|a, b| {
+        keys.iter()
+            .fold(Ordering::Equal, |ordering, key| ordering.then_with(|| compare(key, a, b, usage)))
+    });
+}
+
+/**
+ * 검색창과 테이블이 함께 쓰는 상태
+ * 원본(source)은 조회와 감시 이벤트로만 바뀌고, 보이는 목록(visible)은 항상 최신 원본에서 계산한다.
+ */
+#[derive(Clone, Copy)]
+pub struct PortViewModel {
+    // 마지막 조회 결과에 패치와 감시 이벤트를 반영한 전체 목록
+    pub source: RwSignal<Vec<PortInfo>>,
+    pub query: RwSignal<String>,
+    // 접두어 없는 단어를 찾을 항목 (검색창 드롭다운)
+    pub shorthand: RwSignal<FILTER>,
+    // 앞쪽 기준이 우선한다.
+    pub sort_keys: RwSignal<Vec<SortKey>>,
+    // 선택한 소켓 (socket_key), 새로고침해도 유지된다.
+    pub selected: RwSignal<HashSet<String>>,
+    // NONE이 아니라면 트리로 묶어서 보여준다.
+    pub group_by: RwSignal<GroupBy>,
+    // pid마다 최근 CPU, 메모리 측정값 (감시 이벤트)
+    pub usage: RwSignal<HashMap<String, Vec<ProcessUsage>>>,
+    pub query_error: Memo<Option<QueryError>>,
+    pub visible: Memo<Vec<PortInfo>>,
+    pub groups: Memo<Vec<RowGroup>>,
+}
+
+impl PortViewModel {
+    pub fn new() -> PortViewModel {
+        let source = create_rw_signal(Vec::<PortInfo>::new());
+        let query = create_rw_signal(String::new());
+        let shorthand = create_rw_signal(FILTER::PROCESS);
+        let sort_keys = create_rw_signal(Vec::<SortKey>::new());
+        let selected = create_rw_signal(HashSet::<String>::new());
+        let group_by = create_rw_signal(GroupBy::NONE);
+        let usage = create_rw_signal(HashMap::<String, Vec<ProcessUsage>>::new());
+
+        let parsed = create_memo(move |_| query.with(|query| parse_query(query)));
+        let query_error = create_memo(move |_| parsed.get().err());
+        // 입력 중인 검색어가 잘못되었다면 마지막으로 올바른 검색어로 계속 거른다.
+        let active_query = create_memo(move |previous: Option<&Query>| match parsed.get() {
+            Ok(query) => query,
+            Err(_) => previous.cloned().unwrap_or(Query::All),
+        });
+
+        let visible = create_memo(move |_| {
+            let query = active_query.get();
+            let shorthand = shorthand.get();
+            let mut ports: Vec<PortInfo> = source.with(|ports| {
+                ports
+                    .iter()
+                    .filter(|port| query.matches(port, &shorthand))
+                    .cloned()
+                    .collect()
+            });
+            sort_keys.with(|keys| {
+                // 사용량으로 정렬할 때만 측정마다 다시 정렬한다.
+                if keys
+                    .iter()
+                    .any(|key| matches!(key.field, SortField::CPU | SortField::MEMORY))
+                {
+                    usage.with(|usage| sort_ports(&mut ports, keys, usage));
+                } else {
+                    sort_ports(&mut ports, keys, &HashMap::new());
+                }
+            });
+            ports
+        });
+        // 테이블(NONE)은 소켓 단위로 따로 묶으므로 계산하지 않는다.
+        let groups = create_memo(move |_| match group_by.get() {
+            GroupBy::NONE => Vec::new(),
+            group_by => group_rows(visible.get(), group_by),
+        });
+
+        PortViewModel {
+            source,
+            query,
+            shorthand,
+            sort_keys,
+            selected,
+            group_by,
+            usage,
+            query_error,
+            visible,
+            groups,
+        }
+    }
+
+    /**
+     * 헤더 클릭, 같은 항목이면 처음 방향 => 반대 방향 => 해제 순서로 바꾼다. (대부분 ASC부터)
+     * shift 클릭(additive)이면 다른 기준을 유지한 채 뒤에 추가하거나 바꾼다.
+     */
+    pub fn toggle_sort(&self, field: SortField, additive: bool) {
+        self.sort_keys.update(|keys| {
+            let position = keys.iter().position(|key| key.field == field);
+            let first = field.first_order();
+
+            if !additive {
+                let order = match position.map(|position| keys[position].order) {
+                    Some(order) if keys.len() == 1 && order == first => Some(first.reverse()),
+                    Some(_) if keys.len() == 1 => None,
+                    _ => Some(first),
+                };
+                *keys = order
+                    .map(|order| vec![SortKey { field, order }])
+                    .unwrap_or_default();
+                return;
+            }
+
+            match position {
+                Some(position) if keys[position].order == first => {
+                    keys[position].order = first.reverse();
+                }
+                Some(position) => {
+                    keys.remove(position);
+                }
+                None => keys.push(SortKey {
+                    field,
+                    order: first,
+                }),
+            }
+        });
+    }
+
+    // 헤더에 표시할 방향과 우선순위 (1부터), 기준이 여러 개일 때만 순위를 보여준다.
+    pub fn sort_indicator(&self, field: SortField) -> Option<String> {
+        self.sort_keys.with(|keys| {
+            let position = keys.iter().position(|key| key.field == field)?;
+            let arrow = match keys[position].order {
+                SORT::ASC => "▲",
+                SORT::DESC => "▼",
+            };
+
+            Some(if keys.len() > 1 {
+                format!("{}{}", arrow, position + 1)
+            } else {
+                arrow.to_string()
+            })
+        })
+    }
+}
+
+impl Default for PortViewModel {
+    fn default() -> Self {
+        Self::new()
+    }
+}
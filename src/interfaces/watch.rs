@@ -0,0 +1,30 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct WatchStatus {
+    pub paused: bool,
+    pub interval_ms: u64,
+}
+
+impl Default for WatchStatus {
+    fn default() -> Self {
+        WatchStatus {
+            paused: true,
+            interval_ms: 3_000,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WatchIntervalArgs {
+    pub interval_ms: u64,
+}
+
+// 감시 이벤트로 바뀐 행을 잠시 강조할 때 사용
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum RowHighlight {
+    Added,
+    Removed,
+}
@@ -7,6 +7,16 @@ pub mod components {
     pub mod table;
     pub mod search;
     pub mod loading;
+    pub mod watch;
+    pub mod settings;
+    pub mod timeline;
+    pub mod availability;
+    pub mod probe;
+    pub mod coverage;
+    pub mod kill_dialog;
+    pub mod tree;
+    pub mod detail_panel;
+    pub mod sparkline;
 }
 
 pub mod interfaces {
@@ -14,6 +24,28 @@ pub mod interfaces {
     pub mod filter;
     pub mod kill;
     pub mod sort;
+    pub mod error;
+    pub mod watch;
+    pub mod settings;
+    pub mod history;
+    pub mod page;
+    pub mod container;
+    pub mod cgroup;
+    pub mod availability;
+    pub mod reservation;
+    pub mod probe;
+    pub mod fingerprint;
+    pub mod coverage;
+    pub mod bulk;
+    pub mod query;
+    pub mod view_model;
+    pub mod group;
+    pub mod detail;
+    pub mod usage;
+}
+
+pub mod utils {
+    pub mod tauri;
 }
 
 fn main() {
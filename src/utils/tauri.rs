@@ -0,0 +1,94 @@
+use js_sys::{Function, Reflect};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_wasm_bindgen::{from_value, to_value};
+use wasm_bindgen::prelude::*;
+
+use crate::interfaces::error::CommandError;
+
+#[wasm_bindgen]
+extern "C" {
+    // Result를 돌려주는 명령어는 Err일 때 Promise가 reject 되므로 catch로 받는다.
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "core"], js_name = invoke, catch)]
+    async fn try_invoke(cmd: &str, args: JsValue) -> Result<JsValue, JsValue>;
+
+    // 백엔드에서 흘려보내는 메시지를 받는 채널
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "core"])]
+    type Channel;
+
+    #[wasm_bindgen(constructor, js_namespace = ["window", "__TAURI__", "core"])]
+    fn new() -> Channel;
+
+    #[wasm_bindgen(method, setter)]
+    fn set_onmessage(this: &Channel, callback: &Function);
+
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "event"], js_name = listen)]
+    async fn tauri_listen(event: &str, handler: &Function) -> JsValue;
+}
+
+fn to_command_error(e: JsValue) -> CommandError {
+    from_value::<CommandError>(e).unwrap_or_else(|e| CommandError::Failed(e.to_string()))
+}
+
+/**
+ * 명령어를 호출하고 결과 혹은 CommandError로 변환한다.
+ */
+pub async fn invoke_command<T: DeserializeOwned>(
+    cmd: &str,
+    args: &impl Serialize,
+) -> Result<T, CommandError> {
+    let args = to_value(args).map_err(|e| CommandError::Failed(e.to_string()))?;
+
+    match try_invoke(cmd, args).await {
+        Ok(value) => from_value::<T>(value).map_err(|e| CommandError::Failed(e.to_string())),
+        Err(e) => Err(to_command_error(e)),
+    }
+}
+
+/**
+ * 채널 인자를 가진 명령어를 호출한다.
+ * 명령어가 끝나기 전까지 백엔드가 보낸 메시지는 on_event로 전달된다.
+ */
+pub async fn invoke_streaming<E: DeserializeOwned + 'static>(
+    cmd: &str,
+    args: &impl Serialize,
+    channel_arg: &str,
+    mut on_event: impl FnMut(E) + 'static,
+) -> Result<(), CommandError> {
+    let args = to_value(args).map_err(|e| CommandError::Failed(e.to_string()))?;
+
+    let channel = Channel::new();
+    let callback = Closure::<dyn FnMut(JsValue)>::new(move |message: JsValue| {
+        if let Ok(event) = from_value::<E>(message) {
+            on_event(event);
+        }
+    });
+    channel.set_onmessage(callback.as_ref().unchecked_ref());
+    Reflect::set(&args, &JsValue::from_str(channel_arg), &channel)
+        .map_err(|_| CommandError::Failed("Failed to attach channel".to_string()))?;
+
+    let result = try_invoke(cmd, args).await;
+
+    // 명령어가 끝난 뒤에도 마지막 메시지가 늦게 도착할 수 있으므로 콜백은 해제하지 않는다.
+    callback.forget();
+
+    result.map(|_| ()).map_err(to_command_error)
+}
+
+/**
+ * 백엔드에서 emit 하는 이벤트를 구독한다.
+ * 앱 수명 동안 유지되는 구독에만 사용한다.
+ */
+pub fn listen<T: DeserializeOwned + 'static>(event: &str, mut handler: impl FnMut(T) + 'static) {
+    let callback = Closure::<dyn FnMut(JsValue)>::new(move |event: JsValue| {
+        let payload = Reflect::get(&event, &JsValue::from_str("payload")).unwrap_or(JsValue::NULL);
+        if let Ok(payload) = from_value::<T>(payload) {
+            handler(payload);
+        }
+    });
+
+    let event = event.to_string();
+    wasm_bindgen_futures::spawn_local(async move {
+        tauri_listen(&event, callback.as_ref().unchecked_ref()).await;
+        callback.forget();
+    });
+}